@@ -0,0 +1,104 @@
+#[cfg(feature = "simd")]
+use std::simd::prelude::*;
+
+const LANES: usize = 16;
+
+/// Squared Euclidean distance between two vectors of the same length.
+pub fn squared_euclidean_f32(a: &[f32], b: &[f32]) -> f32 {
+    assert_eq!(a.len(), b.len());
+    let a_chunks = a.chunks_exact(LANES);
+    let b_chunks = b.chunks_exact(LANES);
+    let tail = a_chunks
+        .remainder()
+        .iter()
+        .zip(b_chunks.remainder())
+        .map(|(x, y)| (x - y) * (x - y))
+        .sum::<f32>();
+    squared_euclidean_chunks(a_chunks, b_chunks) + tail
+}
+
+/// Dot product of two vectors of the same length.
+pub fn dot_f32(a: &[f32], b: &[f32]) -> f32 {
+    assert_eq!(a.len(), b.len());
+    let a_chunks = a.chunks_exact(LANES);
+    let b_chunks = b.chunks_exact(LANES);
+    let tail = a_chunks
+        .remainder()
+        .iter()
+        .zip(b_chunks.remainder())
+        .map(|(x, y)| x * y)
+        .sum::<f32>();
+    dot_chunks(a_chunks, b_chunks) + tail
+}
+
+#[cfg(feature = "simd")]
+fn squared_euclidean_chunks(
+    a: std::slice::ChunksExact<'_, f32>,
+    b: std::slice::ChunksExact<'_, f32>,
+) -> f32 {
+    let mut acc = Simd::<f32, LANES>::splat(0.0);
+    for (x, y) in a.zip(b) {
+        let d = Simd::<f32, LANES>::from_slice(x) - Simd::<f32, LANES>::from_slice(y);
+        acc += d * d;
+    }
+    acc.reduce_sum()
+}
+
+#[cfg(not(feature = "simd"))]
+fn squared_euclidean_chunks(
+    a: std::slice::ChunksExact<'_, f32>,
+    b: std::slice::ChunksExact<'_, f32>,
+) -> f32 {
+    // Independent accumulators per lane, so the loop gets auto-vectorized.
+    let mut acc = [0.0f32; LANES];
+    for (x, y) in a.zip(b) {
+        for i in 0..LANES {
+            let d = x[i] - y[i];
+            acc[i] += d * d;
+        }
+    }
+    acc.iter().sum()
+}
+
+#[cfg(feature = "simd")]
+fn dot_chunks(a: std::slice::ChunksExact<'_, f32>, b: std::slice::ChunksExact<'_, f32>) -> f32 {
+    let mut acc = Simd::<f32, LANES>::splat(0.0);
+    for (x, y) in a.zip(b) {
+        acc += Simd::<f32, LANES>::from_slice(x) * Simd::<f32, LANES>::from_slice(y);
+    }
+    acc.reduce_sum()
+}
+
+#[cfg(not(feature = "simd"))]
+fn dot_chunks(a: std::slice::ChunksExact<'_, f32>, b: std::slice::ChunksExact<'_, f32>) -> f32 {
+    let mut acc = [0.0f32; LANES];
+    for (x, y) in a.zip(b) {
+        for i in 0..LANES {
+            acc[i] += x[i] * y[i];
+        }
+    }
+    acc.iter().sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_squared_euclidean_f32() {
+        let a = (0..37).map(|i| i as f32).collect::<Vec<_>>();
+        let b = (0..37).map(|i| (2 * i) as f32).collect::<Vec<_>>();
+        let expected = (0..37).map(|i| (i * i) as f32).sum::<f32>();
+        assert_eq!(squared_euclidean_f32(&a, &b), expected);
+        assert_eq!(squared_euclidean_f32(&[], &[]), 0.0);
+    }
+
+    #[test]
+    fn test_dot_f32() {
+        let a = (0..37).map(|i| i as f32).collect::<Vec<_>>();
+        let b = vec![2.0f32; 37];
+        let expected = (0..37).map(|i| (2 * i) as f32).sum::<f32>();
+        assert_eq!(dot_f32(&a, &b), expected);
+        assert_eq!(dot_f32(&[], &[]), 0.0);
+    }
+}
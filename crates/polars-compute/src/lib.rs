@@ -14,6 +14,7 @@ pub mod cast;
 pub mod comparisons;
 #[cfg(feature = "dtype-decimal")]
 pub mod decimal;
+pub mod distance;
 pub mod ewm;
 pub mod filter;
 #[cfg(feature = "cast")]
@@ -15,6 +15,42 @@ where
         options.multithreaded,
     );
 }
+
+// Move the first `limit` values in sort order to the front and return them, unsorted.
+//
+// The selection itself is unstable. With `maintain_order` we break ties on the index, so the
+// first of the equal values at the boundary are kept, and restore the original order of the
+// selection, so the stable sort after it keeps equal values in order.
+fn select_limit<T>(
+    vals: &mut [(IdxSize, T)],
+    limit: usize,
+    options: SortOptions,
+) -> &mut [(IdxSize, T)]
+where
+    T: TotalOrd,
+{
+    if limit >= vals.len() {
+        return vals;
+    }
+
+    let (lower, _el, _upper) = vals.select_nth_unstable_by(limit, |a, b| {
+        let ord = if options.descending {
+            b.1.tot_cmp(&a.1)
+        } else {
+            a.1.tot_cmp(&b.1)
+        };
+        if options.maintain_order {
+            ord.then(a.0.cmp(&b.0))
+        } else {
+            ord
+        }
+    });
+    if options.maintain_order {
+        lower.sort_unstable_by_key(|v| v.0);
+    }
+    lower
+}
+
 // Compute the indexes after reversing a sorted array, maintaining
 // the order of equal elements, in linear time. Faster than sort_impl
 //  as we avoid allocating extra memory.
@@ -139,18 +175,7 @@ where
         let limit = limit as usize;
         // Overwrite output len.
         len = limit;
-        let out = if limit >= vals.len() {
-            vals.as_mut_slice()
-        } else {
-            let (lower, _el, _upper) = if options.descending {
-                vals.as_mut_slice()
-                    .select_nth_unstable_by(limit, |a, b| b.1.tot_cmp(&a.1))
-            } else {
-                vals.as_mut_slice()
-                    .select_nth_unstable_by(limit, |a, b| a.1.tot_cmp(&b.1))
-            };
-            lower
-        };
+        let out = select_limit(vals.as_mut_slice(), limit, options);
 
         sort_impl(out, options);
         out
@@ -235,19 +260,7 @@ where
     }
 
     let vals = if let Some(limit) = options.limit {
-        let limit = limit as usize;
-        let out = if limit >= vals.len() {
-            vals.as_mut_slice()
-        } else {
-            let (lower, _el, _upper) = if options.descending {
-                vals.as_mut_slice()
-                    .select_nth_unstable_by(limit, |a, b| b.1.tot_cmp(&a.1))
-            } else {
-                vals.as_mut_slice()
-                    .select_nth_unstable_by(limit, |a, b| a.1.tot_cmp(&b.1))
-            };
-            lower
-        };
+        let out = select_limit(vals.as_mut_slice(), limit as usize, options);
         sort_impl(out, options);
         out
     } else {
@@ -382,4 +395,44 @@ mod test {
         let idx: Vec<IdxSize> = r.into_no_null_iter().collect();
         assert_eq!(idx, vec![2, 0, 5]);
     }
+
+    #[test]
+    fn test_arg_sort_maintain_order_with_limit() {
+        let a =
+            Int32Chunked::from_iter_values(PlSmallStr::from_static("a"), (0..100).map(|i| i % 3));
+        let o = SortOptions {
+            maintain_order: true,
+            limit: Some(50),
+            ..Default::default()
+        };
+        let idx: Vec<IdxSize> = a.arg_sort(o).into_no_null_iter().collect();
+        let expected: Vec<IdxSize> = (0..100)
+            .filter(|i| i % 3 == 0)
+            .chain((0..100).filter(|i| i % 3 == 1))
+            .take(50)
+            .collect();
+        assert_eq!(idx, expected);
+
+        let a = Int32Chunked::new(
+            PlSmallStr::from_static("a"),
+            &[Some(3), None, Some(1), Some(3), Some(1), Some(3)],
+        );
+        let o = SortOptions {
+            descending: true,
+            nulls_last: true,
+            maintain_order: true,
+            limit: Some(2),
+            ..Default::default()
+        };
+        let idx: Vec<IdxSize> = a.arg_sort(o).into_no_null_iter().collect();
+        assert_eq!(idx, vec![0, 3]);
+
+        let o = o.with_order_descending(false).with_nulls_last(false);
+        let o = SortOptions {
+            limit: Some(3),
+            ..o
+        };
+        let idx: Vec<IdxSize> = a.arg_sort(o).into_no_null_iter().collect();
+        assert_eq!(idx, vec![1, 2, 4]);
+    }
 }
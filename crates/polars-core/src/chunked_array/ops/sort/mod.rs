@@ -1083,6 +1083,27 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_arg_sort_columns_mixed_order() -> PolarsResult<()> {
+        let columns = [
+            Column::new("a".into(), [1, 1, 2, 2, 1, 2]),
+            Column::new("b".into(), [1, 2, 1, 1, 2, 2]),
+            Column::new("c".into(), [3, 2, 1, 2, 1, 0]),
+        ];
+        let options = SortMultipleOptions::default()
+            .with_order_descending_multi([false, true, false])
+            .with_maintain_order(true);
+
+        // The permutation is returned without materializing a sorted DataFrame.
+        let idx = super::arg_sort(&columns, options.clone())?;
+        assert_eq!(idx.cont_slice()?, &[4, 1, 0, 5, 2, 3]);
+
+        // Row-encoded path.
+        let idx = super::arg_sort(&columns, options.with_nulls_last(true))?;
+        assert_eq!(idx.cont_slice()?, &[4, 1, 0, 5, 2, 3]);
+        Ok(())
+    }
+
     #[test]
     fn test_sort_string() {
         let ca = StringChunked::new(
@@ -41,6 +41,9 @@ pub struct SortOptions {
     pub multithreaded: bool,
     /// If true maintain the order of equal elements.
     /// Default `false`.
+    ///
+    /// Without it, some sorts use an unstable algorithm, which is faster and needs no extra
+    /// memory. Combined with a `limit` it costs a tie-break on the row index of every comparison.
     pub maintain_order: bool,
     /// Limit a sort output, this is for optimization purposes and might be ignored.
     pub limit: Option<IdxSize>,
@@ -217,6 +217,26 @@ impl DataFrame {
         Ok(unsafe { self.take_unchecked(&idx) })
     }
 
+    /// Draw `n` row indices uniformly with replacement, as used by
+    /// [`DataFrame::sample_bootstrap`].
+    ///
+    /// The indices can be reused to take the same bootstrap sample from other frames of the
+    /// same height.
+    pub fn sample_bootstrap_indices(&self, n: usize, seed: Option<u64>) -> PolarsResult<IdxCa> {
+        polars_ensure!(
+            n == 0 || self.height() > 0,
+            ShapeMismatch: "cannot take a bootstrap sample from an empty DataFrame"
+        );
+        Ok(create_rand_index_with_replacement(n, self.height(), seed))
+    }
+
+    /// Take a bootstrap sample: `n` rows drawn uniformly with replacement.
+    pub fn sample_bootstrap(&self, n: usize, seed: Option<u64>) -> PolarsResult<Self> {
+        let idx = self.sample_bootstrap_indices(n, seed)?;
+        // SAFETY: the indices are within bounds.
+        Ok(unsafe { self.take_unchecked(&idx) })
+    }
+
     /// Sample a fraction between 0.0-1.0 of this [`DataFrame`].
     pub fn sample_frac(
         &self,
@@ -394,4 +414,22 @@ mod test {
             .is_ok()
         );
     }
+
+    #[test]
+    fn test_sample_bootstrap() -> PolarsResult<()> {
+        let df = df!["foo" => &[1, 2, 3, 4, 5]]?;
+
+        let idx = df.sample_bootstrap_indices(12, Some(0))?;
+        assert_eq!(idx.len(), 12);
+        assert!(idx.into_no_null_iter().all(|i| (i as usize) < df.height()));
+
+        // The same seed gives the same sample.
+        let out = df.sample_bootstrap(12, Some(0))?;
+        assert_eq!(out.height(), 12);
+        assert!(out.equals(&df.take(&idx)?));
+
+        assert_eq!(df.clear().sample_bootstrap(0, None)?.height(), 0);
+        assert!(df.clear().sample_bootstrap(1, None).is_err());
+        Ok(())
+    }
 }
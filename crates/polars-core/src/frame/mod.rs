@@ -3037,4 +3037,39 @@ mod test {
         df.apply("x", |f| f.cast(&DataType::Int8).unwrap()).unwrap();
         assert_ne!(&schema_before, df.schema());
     }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_unnest_keeps_field_order() {
+        let names = ["e", "b", "d", "a", "c"];
+        let fields = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| Column::new((*name).into(), [i as i32]))
+            .collect::<Vec<_>>();
+        let s = StructChunked::from_columns("s".into(), 1, &fields)
+            .unwrap()
+            .into_column();
+        let df = DataFrame::new(
+            1,
+            vec![
+                Column::new("before".into(), [0]),
+                s,
+                Column::new("after".into(), [0]),
+            ],
+        )
+        .unwrap();
+
+        let out = df.unnest(["s"], None).unwrap();
+        assert_eq!(
+            out.get_column_names(),
+            &["before", "e", "b", "d", "a", "c", "after"]
+        );
+
+        let out = df.unnest(["s"], Some("_")).unwrap();
+        assert_eq!(
+            out.get_column_names(),
+            &["before", "s_e", "s_b", "s_d", "s_a", "s_c", "after"]
+        );
+    }
 }
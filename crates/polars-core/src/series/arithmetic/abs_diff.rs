@@ -0,0 +1,101 @@
+use polars_utils::float16::pf16;
+
+use super::borrowed::check_lengths;
+use super::*;
+use crate::chunked_array::ops::arity::broadcast_binary_elementwise_values;
+
+/// Absolute difference between two native values that never wraps around.
+trait SaturatingAbsDiff: Copy {
+    fn saturating_abs_diff(self, other: Self) -> Self;
+}
+
+macro_rules! impl_ordered_abs_diff {
+    ($($T:ty),*) => {
+        $(
+            impl SaturatingAbsDiff for $T {
+                #[inline]
+                fn saturating_abs_diff(self, other: Self) -> Self {
+                    if self > other {
+                        self - other
+                    } else {
+                        other - self
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_signed_abs_diff {
+    ($($T:ty),*) => {
+        $(
+            impl SaturatingAbsDiff for $T {
+                #[inline]
+                fn saturating_abs_diff(self, other: Self) -> Self {
+                    self.saturating_sub(other).saturating_abs()
+                }
+            }
+        )*
+    };
+}
+
+impl_ordered_abs_diff!(u8, u16, u32, u64, u128, pf16, f32, f64);
+impl_signed_abs_diff!(i8, i16, i32, i64, i128);
+
+fn abs_diff_ca<T>(lhs: &ChunkedArray<T>, rhs: &ChunkedArray<T>) -> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: SaturatingAbsDiff,
+{
+    broadcast_binary_elementwise_values(lhs, rhs, |a: T::Native, b: T::Native| {
+        a.saturating_abs_diff(b)
+    })
+}
+
+impl Series {
+    /// Compute `|self - other|` element-wise in a single pass.
+    ///
+    /// Integer results saturate at the bounds of the supertype instead of wrapping around,
+    /// so `abs_diff([5u8], [10u8])` is `[5u8]`.
+    pub fn abs_diff(&self, other: &Series) -> PolarsResult<Series> {
+        check_lengths(self, other)?;
+        let (lhs, rhs) = coerce_lhs_rhs(self, other)?;
+        let dtype = lhs.dtype();
+        polars_ensure!(
+            dtype.is_primitive_numeric(),
+            opq = abs_diff,
+            self.dtype(),
+            other.dtype()
+        );
+        let out = with_match_physical_numeric_polars_type!(dtype, |$T| {
+            let lhs: &ChunkedArray<$T> = lhs.as_ref().as_ref().as_ref();
+            let rhs: &ChunkedArray<$T> = rhs.as_ref().as_ref().as_ref();
+            abs_diff_ca(lhs, rhs).into_series()
+        });
+        Ok(out.with_name(self.name().clone()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_abs_diff_unsigned_does_not_wrap() -> PolarsResult<()> {
+        let a = Series::new("a".into(), [5u8, 10]);
+        let b = Series::new("b".into(), [10u8, 5]);
+        let out = a.abs_diff(&b)?;
+        assert_eq!(out.dtype(), &DataType::UInt8);
+        assert_eq!(Vec::from(out.u8()?), [Some(5), Some(5)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_abs_diff_signed_saturates() -> PolarsResult<()> {
+        let a = Series::new("a".into(), [i8::MIN, -3, 4]);
+        let b = Series::new("b".into(), [i8::MAX, 3, -4]);
+        let out = a.abs_diff(&b)?;
+        assert_eq!(Vec::from(out.i8()?), [Some(i8::MAX), Some(6), Some(8)]);
+        Ok(())
+    }
+}
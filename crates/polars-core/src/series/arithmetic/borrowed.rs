@@ -484,7 +484,7 @@ pub fn _struct_arithmetic<F: FnMut(&Series, &Series) -> PolarsResult<Series>>(
     }
 }
 
-fn check_lengths(a: &Series, b: &Series) -> PolarsResult<()> {
+pub(super) fn check_lengths(a: &Series, b: &Series) -> PolarsResult<()> {
     match (a.len(), b.len()) {
         // broadcasting
         (1, _) | (_, 1) => Ok(()),
@@ -1,3 +1,4 @@
+mod abs_diff;
 mod bitops;
 mod borrowed;
 mod list;
@@ -296,6 +296,24 @@ impl Series {
         Self::try_from((name, arrays))
     }
 
+    /// Iterate over the arrow arrays backing this Series, without copying them.
+    ///
+    /// These are the arrays as stored, so logical types are represented by their physical
+    /// arrays, e.g. `Int64` for `Datetime`. Use [`Series::into_arrow_chunks`] to export the
+    /// chunks with their logical arrow types.
+    pub fn arrow_chunks(&self) -> impl ExactSizeIterator<Item = &dyn Array> {
+        self.chunks().iter().map(|arr| arr.as_ref())
+    }
+
+    /// Export every chunk of this Series to an arrow array, without rechunking.
+    ///
+    /// See [`Series::to_arrow`] for how the arrow types are chosen.
+    pub fn into_arrow_chunks(self, compat_level: CompatLevel) -> Vec<ArrayRef> {
+        (0..self.n_chunks())
+            .map(|i| self.to_arrow(i, compat_level))
+            .collect()
+    }
+
     pub fn from_arrow(name: PlSmallStr, array: ArrayRef) -> PolarsResult<Series> {
         Self::try_from((name, array))
     }
@@ -802,6 +820,37 @@ impl Series {
         Ok(unsafe { self.take_unchecked(&idx) })
     }
 
+    /// Create a two-column [`DataFrame`] with every combination of the elements of `self`
+    /// and `other`.
+    ///
+    /// Every element of `self` is repeated `other.len()` times while `other` is cycled. The
+    /// gather indices are only collected when the columns are built. Both Series need
+    /// distinct names.
+    pub fn cartesian_product(&self, other: &Series) -> PolarsResult<DataFrame> {
+        let n_right = other.len();
+        let height = self
+            .len()
+            .checked_mul(n_right)
+            .filter(|h| *h <= IdxSize::MAX as usize)
+            .ok_or_else(|| {
+                polars_err!(
+                    ComputeError: "cartesian product of {} and {} rows exceeds the maximum height",
+                    self.len(), n_right
+                )
+            })?;
+
+        let left_idx = (0..height).map(|i| (i / n_right) as IdxSize);
+        let right_idx = (0..height).map(|i| (i % n_right) as IdxSize);
+        // SAFETY: the indices are bounded by the lengths of `self` and `other`.
+        let (left, right) = unsafe {
+            (
+                self.take_unchecked(&left_idx.collect_ca(PlSmallStr::EMPTY)),
+                other.take_unchecked(&right_idx.collect_ca(PlSmallStr::EMPTY)),
+            )
+        };
+        DataFrame::new(height, vec![left.into_column(), right.into_column()])
+    }
+
     #[cfg(feature = "dot_product")]
     pub fn dot(&self, other: &Series) -> PolarsResult<f64> {
         std::ops::Mul::mul(self, other)?.sum::<f64>()
@@ -1161,6 +1210,45 @@ mod test {
         assert!(s2.f32().is_ok());
     }
 
+    #[test]
+    fn cartesian_product() {
+        let a = Series::new("a".into(), [1i32, 2]);
+        let b = Series::new("b".into(), ["a", "b"]);
+        let df = a.cartesian_product(&b).unwrap();
+
+        assert_eq!(df.shape(), (4, 2));
+        let expected = Series::new("a".into(), [1i32, 1, 2, 2]);
+        assert!(
+            df.column("a")
+                .unwrap()
+                .as_materialized_series()
+                .equals(&expected)
+        );
+        let expected = Series::new("b".into(), ["a", "b", "a", "b"]);
+        assert!(
+            df.column("b")
+                .unwrap()
+                .as_materialized_series()
+                .equals(&expected)
+        );
+    }
+
+    #[test]
+    fn arrow_chunks() {
+        let mut s = Series::new("a".into(), [1i32, 2]);
+        s.append(&Series::new("a".into(), [3i32])).unwrap();
+
+        let lens = s.arrow_chunks().map(|arr| arr.len()).collect::<Vec<_>>();
+        assert_eq!(lens, [2, 1]);
+        let chunks = s.clone().into_arrow_chunks(CompatLevel::newest());
+        assert_eq!(chunks.len(), 2);
+        assert!(
+            Series::from_arrow_chunks("a".into(), chunks)
+                .unwrap()
+                .equals(&s)
+        );
+    }
+
     #[test]
     fn new_series() {
         let _ = Series::new("boolean series".into(), &vec![true, false, true]);
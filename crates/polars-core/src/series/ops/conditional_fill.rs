@@ -0,0 +1,61 @@
+use crate::prelude::*;
+
+impl Series {
+    /// Fill null values with a different [`FillNullStrategy`] per segment.
+    ///
+    /// Every null value is filled with the strategy of the first condition that is `true` at
+    /// its position. Null conditions count as `false` and null values that match no condition
+    /// remain null. All strategies are evaluated on `self`, so a forward fill does not
+    /// propagate values filled by an earlier condition.
+    pub fn conditional_fill(
+        &self,
+        conditions: Vec<(BooleanChunked, FillNullStrategy)>,
+    ) -> PolarsResult<Series> {
+        let mut remaining = self.is_null();
+        let mut out = self.clone();
+        for (condition, strategy) in conditions {
+            polars_ensure!(
+                condition.len() == self.len(),
+                length_mismatch = "conditional_fill",
+                self.len(),
+                condition.len()
+            );
+            if !remaining.any() {
+                break;
+            }
+            let mask = &remaining & &condition.fill_null_with_values(false)?;
+            if !mask.any() {
+                continue;
+            }
+            out = self.fill_null(strategy)?.zip_with_same_type(&mask, &out)?;
+            remaining = &remaining & &!&mask;
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_conditional_fill() -> PolarsResult<()> {
+        let s = Series::new(
+            "a".into(),
+            [Some(1), None, Some(3), None, None, Some(6), None],
+        );
+        let sensor = BooleanChunked::new("".into(), [true, true, true, true, false, false, false]);
+        let finance =
+            BooleanChunked::new("".into(), [false, false, false, true, true, true, false]);
+
+        let out = s.conditional_fill(vec![
+            (sensor, FillNullStrategy::Forward(None)),
+            (finance, FillNullStrategy::Zero),
+        ])?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(1), Some(1), Some(3), Some(3), Some(0), Some(6), None]
+        );
+        Ok(())
+    }
+}
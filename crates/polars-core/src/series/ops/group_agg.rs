@@ -0,0 +1,97 @@
+use crate::prelude::*;
+
+/// Aggregation applied to every group by [`Series::group_agg`].
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Debug)]
+pub enum GroupAggMethod {
+    Sum,
+    Mean,
+    Min,
+    Max,
+    /// Number of non-null values.
+    Count,
+    First,
+    Last,
+}
+
+fn check_group_bounds(groups: &GroupsType, len: usize) -> PolarsResult<()> {
+    let len = len as IdxSize;
+    let in_bounds = match groups {
+        GroupsType::Idx(groups) => groups
+            .iter()
+            .all(|(first, all)| first < len && all.iter().all(|&i| i < len)),
+        GroupsType::Slice { groups, .. } => groups
+            .iter()
+            .all(|&[first, n]| first.checked_add(n).is_some_and(|end| end <= len)),
+    };
+    polars_ensure!(
+        in_bounds,
+        OutOfBounds: "group indices are out of bounds for a series of length {}", len
+    );
+    Ok(())
+}
+
+impl Series {
+    /// Aggregate every group in `groups` directly into a [`Series`] with one row per group.
+    ///
+    /// This runs the same kernels as the aggregations of a group-by, without building a
+    /// [`DataFrame`], so custom group-by implementations can aggregate their own groups.
+    /// The groups are checked to be in bounds of `self`.
+    pub fn group_agg(&self, groups: &GroupsType, agg: GroupAggMethod) -> PolarsResult<Series> {
+        check_group_bounds(groups, self.len())?;
+        // SAFETY: the groups are in bounds.
+        let out = unsafe {
+            match agg {
+                GroupAggMethod::Sum => self.agg_sum(groups),
+                GroupAggMethod::Mean => self.agg_mean(groups),
+                GroupAggMethod::Min => self.agg_min(groups),
+                GroupAggMethod::Max => self.agg_max(groups),
+                GroupAggMethod::Count if self.has_nulls() => self.agg_valid_count(groups),
+                GroupAggMethod::Count => groups
+                    .group_count()
+                    .with_name(self.name().clone())
+                    .into_series(),
+                GroupAggMethod::First => self.agg_first(groups),
+                GroupAggMethod::Last => self.agg_last(groups),
+            }
+        };
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_group_agg() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [Some(1i32), None, Some(3), Some(4), Some(5)]);
+        let idx = GroupsType::Idx(GroupsIdx::new(
+            vec![0, 1],
+            vec![vec![0, 2, 4].into(), vec![1, 3].into()],
+            true,
+        ));
+        let slice = GroupsType::new_slice(vec![[0, 3], [3, 2]], false, true);
+
+        let sum = s.group_agg(&idx, GroupAggMethod::Sum)?;
+        assert_eq!(Vec::from(sum.i32()?), &[Some(9), Some(4)]);
+        let count = s.group_agg(&idx, GroupAggMethod::Count)?;
+        assert_eq!(Vec::from(count.idx()?), &[Some(3), Some(1)]);
+        let first = s.group_agg(&idx, GroupAggMethod::First)?;
+        assert_eq!(Vec::from(first.i32()?), &[Some(1), None]);
+
+        let mean = s.group_agg(&slice, GroupAggMethod::Mean)?;
+        assert_eq!(Vec::from(mean.f64()?), &[Some(2.0), Some(4.5)]);
+        let max = s.group_agg(&slice, GroupAggMethod::Max)?;
+        assert_eq!(Vec::from(max.i32()?), &[Some(3), Some(5)]);
+        let last = s.group_agg(&slice, GroupAggMethod::Last)?;
+        assert_eq!(Vec::from(last.i32()?), &[Some(3), Some(5)]);
+
+        let no_nulls = Series::new("b".into(), [1i32, 2, 3, 4, 5]);
+        let count = no_nulls.group_agg(&slice, GroupAggMethod::Count)?;
+        assert_eq!(Vec::from(count.idx()?), &[Some(3), Some(2)]);
+
+        let oob = GroupsType::new_slice(vec![[3, 3]], false, true);
+        assert!(s.group_agg(&oob, GroupAggMethod::Min).is_err());
+        Ok(())
+    }
+}
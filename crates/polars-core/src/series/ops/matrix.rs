@@ -0,0 +1,101 @@
+use crate::prelude::*;
+
+/// Gather indices of diagonal element `i` of every row, null for null rows.
+fn diagonal_idx(ca: &ArrayChunked, n: usize, i: usize) -> IdxCa {
+    let width = n * n;
+    let arr = ca.downcast_as_array();
+    let idx = (0..ca.len()).map(|row| {
+        arr.is_valid(row)
+            .then_some((row * width + i * (n + 1)) as IdxSize)
+    });
+    IdxCa::from_iter_options(PlSmallStr::EMPTY, idx)
+}
+
+fn square_matrices(s: &Series, n: usize, op: &str) -> PolarsResult<ArrayChunked> {
+    let ca = s.array()?;
+    polars_ensure!(
+        n > 0 && ca.width() == n * n,
+        InvalidOperation: "{} expects an Array column of width {} for n = {}, got width {}",
+        op, n * n, n, ca.width()
+    );
+    Ok(ca.rechunk().into_owned())
+}
+
+impl Series {
+    /// Diagonal of every row of an `Array(_, n * n)` column, read as a row-major `n x n` matrix.
+    ///
+    /// Returns an `Array(_, n)` column, null for null rows.
+    pub fn matrix_diagonal(&self, n: usize) -> PolarsResult<Series> {
+        let ca = square_matrices(self, n, "matrix_diagonal")?;
+        let width = n * n;
+        let idx = (0..ca.len())
+            .flat_map(|row| (0..n).map(move |i| (row * width + i * (n + 1)) as IdxSize))
+            .collect_ca(PlSmallStr::EMPTY);
+        // SAFETY: every index is smaller than `len * width`.
+        let values = unsafe { ca.get_inner().take_unchecked(&idx) };
+        let validity = ca.downcast_as_array().validity().cloned();
+        let out = ArrayChunked::from_aligned_values(
+            self.name().clone(),
+            ca.inner_dtype(),
+            n,
+            values.chunks().clone(),
+            ca.len(),
+        );
+        Ok(out.with_validity(validity).into_series())
+    }
+
+    /// Trace of every row of an `Array(_, n * n)` column, read as an `n x n` matrix.
+    ///
+    /// Null rows and matrices with a null on the diagonal have a null trace.
+    pub fn matrix_trace(&self, n: usize) -> PolarsResult<Series> {
+        let ca = square_matrices(self, n, "matrix_trace")?;
+        let inner = ca.get_inner();
+        // SAFETY: every index is smaller than `len * width`.
+        let mut trace = unsafe { inner.take_unchecked(&diagonal_idx(&ca, n, 0)) };
+        for i in 1..n {
+            let element = unsafe { inner.take_unchecked(&diagonal_idx(&ca, n, i)) };
+            trace = (&trace + &element)?;
+        }
+        Ok(trace.with_name(self.name().clone()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn matrices(rows: &[Option<[f64; 9]>]) -> PolarsResult<Series> {
+        let rows = rows
+            .iter()
+            .map(|row| row.map(|row| Series::new("".into(), row)))
+            .collect::<Vec<_>>();
+        Series::new("m".into(), rows).cast(&DataType::Array(Box::new(DataType::Float64), 9))
+    }
+
+    #[test]
+    fn test_matrix_diagonal_and_trace() -> PolarsResult<()> {
+        let identity = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+        let counting = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+        let s = matrices(&[Some(identity), None, Some(counting)])?;
+
+        let diagonal = s.matrix_diagonal(3)?;
+        assert_eq!(
+            diagonal.dtype(),
+            &DataType::Array(Box::new(DataType::Float64), 3)
+        );
+        let diagonal = diagonal.array()?;
+        let row = |i: usize| -> Option<Vec<f64>> {
+            let s = diagonal.get_as_series(i)?;
+            Some(s.f64().unwrap().into_no_null_iter().collect())
+        };
+        assert_eq!(row(0), Some(vec![1.0, 1.0, 1.0]));
+        assert_eq!(row(1), None);
+        assert_eq!(row(2), Some(vec![1.0, 5.0, 9.0]));
+
+        let trace = s.matrix_trace(3)?;
+        assert_eq!(Vec::from(trace.f64()?), &[Some(3.0), None, Some(15.0)]);
+
+        assert!(s.matrix_diagonal(2).is_err());
+        Ok(())
+    }
+}
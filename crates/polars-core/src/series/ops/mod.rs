@@ -1,9 +1,20 @@
+#[cfg(feature = "zip_with")]
+mod conditional_fill;
 mod downcast;
 mod extend;
+#[cfg(feature = "algorithm_group_by")]
+mod group_agg;
+#[cfg(feature = "dtype-array")]
+mod matrix;
 mod null;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 mod reshape;
+mod sample;
+mod shift_by;
+
+#[cfg(feature = "algorithm_group_by")]
+pub use group_agg::GroupAggMethod;
 
 #[derive(Copy, Clone, Hash, Eq, PartialEq, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -356,4 +356,21 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg(feature = "dtype-array")]
+    fn test_reshape_array_zero_copy() -> PolarsResult<()> {
+        let s = Series::new("a".into(), &[1, 2, 3, 4, 5, 6]);
+        let values_ptr = s.i32()?.downcast_as_array().values().as_ptr();
+
+        let dims = [ReshapeDimension::Infer, ReshapeDimension::new(3)];
+        let out = s.reshape_array(&dims)?;
+        assert_eq!(out.dtype(), &DataType::Array(Box::new(DataType::Int32), 3));
+        assert_eq!(out.len(), 2);
+
+        let inner = out.array()?.downcast_as_array().values();
+        let inner = inner.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(inner.values().as_ptr(), values_ptr);
+        Ok(())
+    }
 }
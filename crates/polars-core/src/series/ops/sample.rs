@@ -0,0 +1,50 @@
+use crate::prelude::*;
+
+impl Series {
+    /// Take a systematic sample of `n` elements.
+    ///
+    /// Every `len / n`-th element is taken, starting at `offset`, so the sample is spread evenly
+    /// over the whole [`Series`]. `offset` must be smaller than that interval.
+    pub fn sample_systematic(&self, n: usize, offset: usize) -> PolarsResult<Series> {
+        if n == 0 {
+            return Ok(self.clear());
+        }
+        polars_ensure!(
+            n <= self.len(),
+            ShapeMismatch: "cannot take a systematic sample of {} elements from a series of length {}",
+            n, self.len()
+        );
+        let step = self.len() / n;
+        polars_ensure!(
+            offset < step,
+            OutOfBounds: "offset {} should be smaller than the sampling interval {}", offset, step
+        );
+        Ok(self.gather_every(step, offset)?.head(Some(n)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sample_systematic() -> PolarsResult<()> {
+        let s = Series::new("a".into(), (0..100).collect::<Vec<i32>>());
+        for offset in 0..10 {
+            let out = s.sample_systematic(10, offset)?;
+            let expected = (0..10)
+                .map(|i| Some(10 * i + offset as i32))
+                .collect::<Vec<_>>();
+            assert_eq!(Vec::from(out.i32()?), expected);
+        }
+
+        // The remainder of the division is never sampled.
+        let out = s.sample_systematic(3, 1)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(1), Some(34), Some(67)]);
+
+        assert_eq!(s.sample_systematic(0, 0)?.len(), 0);
+        assert!(s.sample_systematic(101, 0).is_err());
+        assert!(s.sample_systematic(10, 10).is_err());
+        Ok(())
+    }
+}
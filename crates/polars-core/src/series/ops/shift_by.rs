@@ -0,0 +1,50 @@
+use crate::prelude::*;
+
+impl Series {
+    /// Shift every row by its own offset.
+    ///
+    /// Row `i` of the output is row `i - offsets[i]` of `self`, or null if the offset is null
+    /// or that row is out of bounds. Positive offsets look back like [`Series::shift`], negative
+    /// offsets look ahead.
+    pub fn shift_by(&self, offsets: &Series) -> PolarsResult<Series> {
+        polars_ensure!(
+            offsets.len() == self.len(),
+            length_mismatch = "shift_by",
+            self.len(),
+            offsets.len()
+        );
+        polars_ensure!(
+            offsets.dtype().is_integer(),
+            InvalidOperation: "shift_by expects integer offsets, got {}", offsets.dtype()
+        );
+        let offsets = offsets.cast(&DataType::Int64)?;
+        let len = self.len() as i64;
+        let idx = offsets.i64()?.iter().enumerate().map(|(i, offset)| {
+            let j = (i as i64).checked_sub(offset?)?;
+            (0..len).contains(&j).then_some(j as IdxSize)
+        });
+        let idx = IdxCa::from_iter_options(PlSmallStr::EMPTY, idx);
+        self.take(&idx)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_shift_by() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [10, 20, 30, 40]);
+        let offsets = Series::new("offsets".into(), [Some(1), Some(2), Some(1), Some(0)]);
+        let out = s.shift_by(&offsets)?;
+        assert_eq!(out.name().as_str(), "a");
+        assert_eq!(Vec::from(out.i32()?), &[None, None, Some(20), Some(40)]);
+
+        let offsets = Series::new("offsets".into(), [Some(-1), None, Some(i64::MIN), Some(-1)]);
+        let out = s.shift_by(&offsets)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(20), None, None, None]);
+
+        assert!(s.shift_by(&Series::new("".into(), [1, 2])).is_err());
+        Ok(())
+    }
+}
@@ -4,6 +4,7 @@ use std::sync::Arc;
 use polars_core::error::PolarsResult;
 use polars_core::prelude::{BooleanChunked, Column, DataType, IntoColumn, NamedFrom};
 use polars_core::runtime::RAYON;
+use polars_ops::series::SeriesMethods;
 use polars_plan::dsl::{ColumnsUdf, SpecialEq};
 use polars_plan::plans::IRBooleanFunction;
 use polars_utils::pl_str::PlSmallStr;
@@ -17,6 +18,14 @@ pub fn function_expr_to_udf(func: IRBooleanFunction) -> SpecialEq<Arc<dyn Column
         All { ignore_nulls } => map!(all, ignore_nulls),
         IsEmpty { ignore_nulls } => map!(is_empty, ignore_nulls),
         HasNulls => map!(has_nulls),
+        IsMonotonic {
+            descending,
+            ignore_nulls,
+        } => map!(is_monotonic, descending, ignore_nulls),
+        IsMonotonicBy {
+            descending,
+            null_equal,
+        } => map!(is_monotonic_by, descending, null_equal),
         IsNull => map!(is_null),
         IsNotNull => map!(is_not_null),
         IsFinite => map!(is_finite),
@@ -78,6 +87,26 @@ fn has_nulls(s: &Column) -> PolarsResult<Column> {
     Ok(Column::new(s.name().clone(), [s.has_nulls()]))
 }
 
+fn is_monotonic(s: &Column, descending: bool, ignore_nulls: bool) -> PolarsResult<Column> {
+    let s = s.as_materialized_series();
+    let out = if descending {
+        s.is_monotonic_decreasing(!ignore_nulls)?
+    } else {
+        s.is_monotonic_increasing(!ignore_nulls)?
+    };
+    Ok(Column::new(s.name().clone(), [out]))
+}
+
+fn is_monotonic_by(s: &Column, descending: bool, null_equal: bool) -> PolarsResult<Column> {
+    let s = s.as_materialized_series();
+    let out = if descending {
+        s.is_monotonic_decreasing_by(null_equal)?
+    } else {
+        s.is_monotonic_increasing_by(null_equal)?
+    };
+    Ok(Column::new(s.name().clone(), [out]))
+}
+
 fn is_null(s: &Column) -> PolarsResult<Column> {
     Ok(s.is_null().into_column())
 }
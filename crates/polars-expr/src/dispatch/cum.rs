@@ -1,5 +1,6 @@
 use polars_core::error::PolarsResult;
 use polars_core::prelude::Column;
+use polars_ops::prelude::OverflowPolicy;
 
 pub(super) fn cum_count(s: &Column, reverse: bool) -> PolarsResult<Column> {
     // @scalar-opt
@@ -11,17 +12,22 @@ pub(super) fn cum_sum(s: &Column, reverse: bool) -> PolarsResult<Column> {
     polars_ops::prelude::cum_sum(s.as_materialized_series(), reverse).map(Column::from)
 }
 
-pub(super) fn cum_prod(s: &Column, reverse: bool) -> PolarsResult<Column> {
+pub(super) fn cum_prod(
+    s: &Column,
+    reverse: bool,
+    overflow: OverflowPolicy,
+) -> PolarsResult<Column> {
     // @scalar-opt
-    polars_ops::prelude::cum_prod(s.as_materialized_series(), reverse).map(Column::from)
+    polars_ops::prelude::cum_prod_with_overflow(s.as_materialized_series(), reverse, overflow)
+        .map(Column::from)
 }
 
-pub(super) fn cum_min(s: &Column, reverse: bool) -> PolarsResult<Column> {
+pub(super) fn cum_min(s: &Column, reverse: bool, skip_nulls: bool) -> PolarsResult<Column> {
     // @scalar-opt
-    polars_ops::prelude::cum_min(s.as_materialized_series(), reverse).map(Column::from)
+    polars_ops::prelude::cum_min(s.as_materialized_series(), reverse, skip_nulls).map(Column::from)
 }
 
-pub(super) fn cum_max(s: &Column, reverse: bool) -> PolarsResult<Column> {
+pub(super) fn cum_max(s: &Column, reverse: bool, skip_nulls: bool) -> PolarsResult<Column> {
     // @scalar-opt
-    polars_ops::prelude::cum_max(s.as_materialized_series(), reverse).map(Column::from)
+    polars_ops::prelude::cum_max(s.as_materialized_series(), reverse, skip_nulls).map(Column::from)
 }
@@ -52,6 +52,12 @@ pub fn function_expr_to_udf(func: IRListFunction) -> SpecialEq<Arc<dyn ColumnsUd
         ArgMax => map!(arg_max),
         #[cfg(feature = "diff")]
         Diff { n, null_behavior } => map!(diff, n, null_behavior),
+        #[cfg(feature = "cum_agg")]
+        CumSum => map!(cum_sum),
+        #[cfg(feature = "cum_agg")]
+        CumProd => map!(cum_prod),
+        FlattenN(n) => map!(flatten_n, n),
+        ChunkEvery => map_as_slice!(chunk_every),
         Sort(options) => map!(sort, options),
         #[cfg(feature = "list_sets")]
         SetOperation(s) => map_as_slice!(set_operation, s),
@@ -356,6 +362,29 @@ pub(super) fn diff(
     Ok(s.list()?.lst_diff(n, null_behavior)?.into_column())
 }
 
+#[cfg(feature = "cum_agg")]
+pub(super) fn cum_sum(s: &Column) -> PolarsResult<Column> {
+    Ok(s.list()?.lst_cum_sum()?.into_column())
+}
+
+#[cfg(feature = "cum_agg")]
+pub(super) fn cum_prod(s: &Column) -> PolarsResult<Column> {
+    Ok(s.list()?.lst_cum_prod()?.into_column())
+}
+
+pub(super) fn flatten_n(s: &Column, n: usize) -> PolarsResult<Column> {
+    Ok(s.list()?.lst_flatten_n(n).into_column())
+}
+
+pub(super) fn chunk_every(args: &[Column]) -> PolarsResult<Column> {
+    let n = &args[1];
+    polars_ensure!(n.len() == 1, ComputeError: "n should have unit length.");
+    let Some(n) = n.strict_cast(&IDX_DTYPE)?.idx()?.get(0) else {
+        polars_bail!(ComputeError: "n can not be None for list.chunk_every.");
+    };
+    Ok(args[0].list()?.lst_chunk_every(n as usize)?.into_column())
+}
+
 pub(super) fn sort(s: &Column, options: SortOptions) -> PolarsResult<Column> {
     Ok(s.list()?.lst_sort(options)?.into_column())
 }
@@ -25,6 +25,13 @@ pub(super) fn abs(s: &Column) -> PolarsResult<Column> {
     polars_ops::prelude::abs(s.as_materialized_series()).map(Column::from)
 }
 
+#[cfg(feature = "abs")]
+pub(super) fn abs_diff(s: &[Column]) -> PolarsResult<Column> {
+    s[0].as_materialized_series()
+        .abs_diff(s[1].as_materialized_series())
+        .map(Column::from)
+}
+
 pub(super) fn reverse(s: &Column) -> PolarsResult<Column> {
     Ok(s.reverse())
 }
@@ -358,6 +365,33 @@ pub(super) fn gather_every(s: &Column, n: usize, offset: usize) -> PolarsResult<
     s.gather_every(n, offset)
 }
 
+pub(super) fn gather_every_by(s: &[Column]) -> PolarsResult<Column> {
+    let n = &s[1];
+    let offset = &s[2];
+    polars_ensure!(n.len() == 1 && offset.len() == 1, ComputeError: "n and offset should have unit length.");
+    let n = n.strict_cast(&DataType::UInt64)?;
+    let offset = offset.strict_cast(&DataType::UInt64)?;
+    match (n.u64()?.get(0), offset.u64()?.get(0)) {
+        (Some(n), Some(offset)) => s[0].gather_every(n as usize, offset as usize),
+        _ => polars_bail!(ComputeError: "n and offset can not be None for gather_every."),
+    }
+}
+
+pub(super) fn sample_systematic(s: &[Column]) -> PolarsResult<Column> {
+    let n = &s[1];
+    let offset = &s[2];
+    polars_ensure!(n.len() == 1 && offset.len() == 1, ComputeError: "n and offset should have unit length.");
+    let n = n.strict_cast(&DataType::UInt64)?;
+    let offset = offset.strict_cast(&DataType::UInt64)?;
+    match (n.u64()?.get(0), offset.u64()?.get(0)) {
+        (Some(n), Some(offset)) => s[0]
+            .as_materialized_series()
+            .sample_systematic(n as usize, offset as usize)
+            .map(Column::from),
+        _ => polars_bail!(ComputeError: "n and offset can not be None for sample_systematic."),
+    }
+}
+
 #[cfg(feature = "reinterpret")]
 pub(super) fn reinterpret(s: &Column, dtype: &DataType) -> PolarsResult<Column> {
     polars_core::chunked_array::ops::reinterpret(s.as_materialized_series(), dtype)
@@ -655,6 +689,16 @@ pub(super) fn clip(s: &[Column], has_min: bool, has_max: bool) -> PolarsResult<C
     .map(Column::from)
 }
 
+#[cfg(feature = "round_series")]
+pub(super) fn clip_by(s: &[Column]) -> PolarsResult<Column> {
+    polars_ops::series::clip_by(
+        s[0].as_materialized_series(),
+        s[1].as_materialized_series(),
+        s[2].as_materialized_series(),
+    )
+    .map(Column::from)
+}
+
 #[cfg(feature = "dtype-struct")]
 pub fn as_struct(cols: &[Column]) -> PolarsResult<Column> {
     use polars_core::prelude::StructChunked;
@@ -714,6 +758,23 @@ pub(super) fn exp(s: &Column) -> PolarsResult<Column> {
     Ok(s.as_materialized_series().exp().into())
 }
 
+#[cfg(feature = "tdigest")]
+pub(super) fn tdigest(s: &Column, compression: f64) -> PolarsResult<Column> {
+    polars_ops::series::TDigest::build(s.as_materialized_series(), compression)
+        .map(|sketch| sketch.into_series().into())
+}
+
+#[cfg(feature = "tdigest")]
+pub(super) fn tdigest_merge(s: &Column) -> PolarsResult<Column> {
+    polars_ops::series::TDigest::merge(s.as_materialized_series())
+        .map(|sketch| sketch.into_series().into())
+}
+
+#[cfg(feature = "tdigest")]
+pub(super) fn tdigest_quantile(s: &Column, quantile: f64) -> PolarsResult<Column> {
+    polars_ops::series::TDigest::quantile(s.as_materialized_series(), quantile).map(Column::from)
+}
+
 pub(super) fn unique(s: &Column, stable: bool) -> PolarsResult<Column> {
     if stable {
         s.unique_stable()
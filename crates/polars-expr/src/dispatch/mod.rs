@@ -163,6 +163,8 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         F::Business(func) => business::function_expr_to_udf(func),
         #[cfg(feature = "abs")]
         F::Abs => map!(misc::abs),
+        #[cfg(feature = "abs")]
+        F::AbsDiff => map_as_slice!(misc::abs_diff),
         F::Negate => map!(misc::negate),
         F::NullCount => {
             let f = |s: &mut [Column]| {
@@ -283,6 +285,8 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         F::Clip { has_min, has_max } => {
             map_as_slice!(misc::clip, has_min, has_max)
         },
+        #[cfg(feature = "round_series")]
+        F::ClipBy => map_as_slice!(misc::clip_by),
         F::Quantile { method } => map_as_slice!(misc::quantile, method),
         #[cfg(feature = "mode")]
         F::Mode { maintain_order } => map!(misc::mode, maintain_order),
@@ -321,11 +325,17 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         #[cfg(feature = "cum_agg")]
         F::CumSum { reverse } => map!(cum::cum_sum, reverse),
         #[cfg(feature = "cum_agg")]
-        F::CumProd { reverse } => map!(cum::cum_prod, reverse),
+        F::CumProd { reverse, overflow } => map!(cum::cum_prod, reverse, overflow),
         #[cfg(feature = "cum_agg")]
-        F::CumMin { reverse } => map!(cum::cum_min, reverse),
+        F::CumMin {
+            reverse,
+            skip_nulls,
+        } => map!(cum::cum_min, reverse, skip_nulls),
         #[cfg(feature = "cum_agg")]
-        F::CumMax { reverse } => map!(cum::cum_max, reverse),
+        F::CumMax {
+            reverse,
+            skip_nulls,
+        } => map!(cum::cum_max, reverse, skip_nulls),
         #[cfg(feature = "dtype-struct")]
         F::ValueCounts {
             sort,
@@ -359,6 +369,12 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         F::Log1p => map!(misc::log1p),
         #[cfg(feature = "log")]
         F::Exp => map!(misc::exp),
+        #[cfg(feature = "tdigest")]
+        F::TDigest { compression } => map!(misc::tdigest, compression),
+        #[cfg(feature = "tdigest")]
+        F::TDigestMerge => map!(misc::tdigest_merge),
+        #[cfg(feature = "tdigest")]
+        F::TDigestQuantile { quantile } => map!(misc::tdigest_quantile, quantile),
         F::Unique(stable) => map!(misc::unique, stable),
         #[cfg(feature = "round_series")]
         F::Round { decimals, mode } => map!(round::round, decimals, mode),
@@ -519,6 +535,8 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
 
         F::FillNullWithStrategy(strategy) => map!(misc::fill_null_with_strategy, strategy),
         F::GatherEvery { n, offset } => map!(misc::gather_every, n, offset),
+        F::GatherEveryBy => map_as_slice!(misc::gather_every_by),
+        F::SampleSystematic => map_as_slice!(misc::sample_systematic),
         #[cfg(feature = "reinterpret")]
         F::Reinterpret(dtype) => map!(misc::reinterpret, &dtype),
         F::ExtendConstant => map_as_slice!(misc::extend_constant),
@@ -37,6 +37,10 @@ pub fn function_expr_to_udf(func: IRStringFunction) -> SpecialEq<Arc<dyn Columns
         ExtractGroups { pat, dtype } => {
             map!(strings::extract_groups, &pat, &dtype)
         },
+        #[cfg(feature = "extract_groups")]
+        ExtractAllGroups { pat, dtype } => {
+            map!(strings::extract_all_groups, &pat, &dtype)
+        },
         #[cfg(feature = "regex")]
         Find { literal, strict } => map_as_slice!(strings::find, literal, strict),
         LenBytes => map!(strings::len_bytes),
@@ -84,10 +88,17 @@ pub fn function_expr_to_udf(func: IRStringFunction) -> SpecialEq<Arc<dyn Columns
         Normalize { form } => map!(strings::normalize, form.clone()),
         #[cfg(feature = "string_reverse")]
         Reverse => map!(strings::reverse),
+        #[cfg(feature = "string_word_count")]
+        WordCount => map!(strings::word_count),
+        #[cfg(feature = "string_tokenize")]
+        Tokenize { words_only } => map!(strings::tokenize, words_only),
+        TruncateBytes { n, ellipsis } => map!(strings::truncate_bytes, n, ellipsis.as_deref()),
         Uppercase => map!(uppercase),
         Lowercase => map!(lowercase),
         #[cfg(feature = "nightly")]
         Titlecase => map!(strings::titlecase),
+        #[cfg(feature = "string_titlecase")]
+        TitlecaseUnicodeWords => map!(strings::titlecase_unicode_words),
         StripChars => map_as_slice!(strings::strip_chars),
         StripCharsStart => map_as_slice!(strings::strip_chars_start),
         StripCharsEnd => map_as_slice!(strings::strip_chars_end),
@@ -231,6 +242,12 @@ pub(super) fn titlecase(s: &Column) -> PolarsResult<Column> {
     Ok(ca.to_titlecase().into_column())
 }
 
+#[cfg(feature = "string_titlecase")]
+pub(super) fn titlecase_unicode_words(s: &Column) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.to_titlecase_unicode_words().into_column())
+}
+
 pub(super) fn len_chars(s: &Column) -> PolarsResult<Column> {
     let ca = s.str()?;
     Ok(ca.str_len_chars().into_column())
@@ -288,6 +305,12 @@ pub(super) fn extract_groups(s: &Column, pat: &str, dtype: &DataType) -> PolarsR
     ca.extract_groups(pat, dtype).map(Column::from)
 }
 
+#[cfg(feature = "extract_groups")]
+pub(super) fn extract_all_groups(s: &Column, pat: &str, dtype: &DataType) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    ca.extract_all_groups(pat, dtype).map(Column::from)
+}
+
 #[cfg(feature = "string_pad")]
 pub(super) fn pad_start(s: &[Column], fill_char: char) -> PolarsResult<Column> {
     let s1 = s[0].as_materialized_series();
@@ -776,6 +799,23 @@ pub(super) fn reverse(s: &Column) -> PolarsResult<Column> {
     Ok(ca.str_reverse().into_column())
 }
 
+#[cfg(feature = "string_word_count")]
+pub(super) fn word_count(s: &Column) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.str_word_count().into_column())
+}
+
+#[cfg(feature = "string_tokenize")]
+pub(super) fn tokenize(s: &Column, words_only: bool) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.str_tokenize(words_only).into_column())
+}
+
+pub(super) fn truncate_bytes(s: &Column, n: usize, ellipsis: Option<&str>) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.str_truncate_bytes(n, ellipsis).into_column())
+}
+
 #[cfg(feature = "string_to_integer")]
 pub(super) fn to_integer(
     s: &[Column],
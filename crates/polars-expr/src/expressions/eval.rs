@@ -13,15 +13,90 @@ use polars_core::prelude::{
     _set_check_length, ChunkCast, ChunkExplode, ChunkNestingUtils, Column, Field, GroupPositions,
     GroupsType, IdxCa, IntoColumn, ListBuilderTrait, ListChunked,
 };
+#[cfg(feature = "cum_agg")]
+use polars_core::prelude::{DataType, FillNullStrategy, IntoSeries};
 use polars_core::schema::Schema;
 use polars_core::series::Series;
 use polars_plan::dsl::{EvalVariant, Expr};
+#[cfg(feature = "cum_agg")]
+use polars_plan::prelude::{AExpr, Arena, IRAggExpr, Node};
 use polars_utils::IdxSize;
 use polars_utils::pl_str::PlSmallStr;
 
 use super::{AggregationContext, PhysicalExpr};
 use crate::state::ExecutionState;
 
+/// An aggregation of the element that `cumulative_eval` computes with a single running scan,
+/// instead of aggregating every prefix of the input again.
+#[cfg(feature = "cum_agg")]
+#[derive(Clone, Copy)]
+pub(crate) enum CumulativeAgg {
+    Sum,
+    Mean,
+    Min,
+    Max,
+    Count { include_nulls: bool },
+}
+
+#[cfg(feature = "cum_agg")]
+impl CumulativeAgg {
+    pub(crate) fn try_from_evaluation(
+        node: Node,
+        arena: &Arena<AExpr>,
+        element_dtype: &DataType,
+    ) -> Option<Self> {
+        let AExpr::Agg(agg) = arena.get(node) else {
+            return None;
+        };
+        let is_numeric = element_dtype.is_primitive_numeric();
+        // Float min/max have their own NaN semantics, those stay on the slow path.
+        let is_integer = element_dtype.is_integer();
+        let (input, agg) = match agg {
+            IRAggExpr::Sum(input) if is_numeric => (*input, Self::Sum),
+            IRAggExpr::Mean(input) if is_numeric => (*input, Self::Mean),
+            IRAggExpr::Min { input, .. } if is_integer => (*input, Self::Min),
+            IRAggExpr::Max { input, .. } if is_integer => (*input, Self::Max),
+            IRAggExpr::Count {
+                input,
+                include_nulls,
+            } => (
+                *input,
+                Self::Count {
+                    include_nulls: *include_nulls,
+                },
+            ),
+            _ => return None,
+        };
+        matches!(arena.get(input), AExpr::Element).then_some(agg)
+    }
+
+    /// Aggregate every prefix of `s`.
+    fn evaluate(self, s: &Series) -> PolarsResult<Series> {
+        use polars_ops::prelude::{cum_count, cum_max, cum_min, cum_sum};
+
+        let forward_fill = FillNullStrategy::Forward(None);
+        match self {
+            Self::Sum => cum_sum(&s.fill_null(FillNullStrategy::Zero)?, false),
+            Self::Mean => {
+                let sum = cum_sum(&s.cast(&DataType::Float64)?, false)?;
+                let count = cum_count(s, false)?.cast(&DataType::Float64)?;
+                (&sum / &count)?.fill_null(forward_fill)
+            },
+            Self::Min => cum_min(s, false, true)?.fill_null(forward_fill),
+            Self::Max => cum_max(s, false, true)?.fill_null(forward_fill),
+            Self::Count {
+                include_nulls: true,
+            } => {
+                let counts = (1..=s.len() as IdxSize).collect();
+                Ok(IdxCa::from_vec(s.name().clone(), counts).into_series())
+            },
+            Self::Count {
+                include_nulls: false,
+            } => cum_count(s, false),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct EvalExpr {
     input: Arc<dyn PhysicalExpr>,
@@ -33,6 +108,8 @@ pub struct EvalExpr {
     evaluation_is_scalar: bool,
     evaluation_is_elementwise: bool,
     evaluation_is_fallible: bool,
+    #[cfg(feature = "cum_agg")]
+    cumulative_agg: Option<CumulativeAgg>,
 }
 
 impl EvalExpr {
@@ -58,9 +135,17 @@ impl EvalExpr {
             evaluation_is_scalar,
             evaluation_is_elementwise,
             evaluation_is_fallible,
+            #[cfg(feature = "cum_agg")]
+            cumulative_agg: None,
         }
     }
 
+    #[cfg(feature = "cum_agg")]
+    pub(crate) fn with_cumulative_agg(mut self, cumulative_agg: Option<CumulativeAgg>) -> Self {
+        self.cumulative_agg = cumulative_agg;
+        self
+    }
+
     fn evaluate_on_list_chunked(
         &self,
         ca: &ListChunked,
@@ -445,6 +530,11 @@ impl EvalExpr {
             ));
         }
 
+        #[cfg(feature = "cum_agg")]
+        if let Some(agg) = self.cumulative_agg.filter(|_| min_samples > 0) {
+            return self.evaluate_cumulative_agg(agg, input, min_samples);
+        }
+
         let flattened = input.clone().into_column();
         let validity = input.rechunk_validity();
 
@@ -502,6 +592,27 @@ impl EvalExpr {
 
         Ok(out)
     }
+
+    #[cfg(feature = "cum_agg")]
+    fn evaluate_cumulative_agg(
+        &self,
+        agg: CumulativeAgg,
+        input: &Series,
+        min_samples: usize,
+    ) -> PolarsResult<Column> {
+        use polars_core::prelude::ChunkCompareIneq;
+
+        let mut out = agg.evaluate(input)?;
+
+        // Null out the prefixes that don't have enough valid values.
+        if min_samples > 1 || input.has_nulls() {
+            let count = polars_ops::prelude::cum_count(input, false)?;
+            let mask = count.idx()?.gt_eq(min_samples as IdxSize);
+            let nulls = Series::full_null(PlSmallStr::EMPTY, out.len(), out.dtype());
+            out = out.zip_with(&mask, &nulls)?;
+        }
+        Ok(out.cast(self.output_field.dtype())?.into_column())
+    }
 }
 
 impl PhysicalExpr for EvalExpr {
@@ -551,12 +551,12 @@ fn create_physical_expr_inner(
             let element_dtype = variant.element_dtype(&input_field.dtype)?;
             let mut eval_schema = schema.as_ref().clone();
             eval_schema.insert(get_pl_element_name(), element_dtype.clone());
-            let evaluation =
+            let evaluation_phys =
                 create_physical_expr_inner(evaluation, expr_arena, &Arc::new(eval_schema), state)?;
 
-            Ok(Arc::new(EvalExpr::new(
+            let eval_expr = EvalExpr::new(
                 expr,
-                evaluation,
+                evaluation_phys,
                 variant,
                 node_to_expr(expression, expr_arena),
                 output_field,
@@ -564,7 +564,15 @@ fn create_physical_expr_inner(
                 evaluation_is_scalar,
                 evaluation_is_elementwise,
                 evaluation_is_fallible,
-            )))
+            );
+            #[cfg(feature = "cum_agg")]
+            let eval_expr = eval_expr.with_cumulative_agg(match variant {
+                EvalVariant::Cumulative { .. } => {
+                    CumulativeAgg::try_from_evaluation(evaluation, expr_arena, &element_dtype)
+                },
+                _ => None,
+            });
+            Ok(Arc::new(eval_expr))
         },
         #[cfg(feature = "dtype-struct")]
         StructEval { expr, evaluation } => {
@@ -20,6 +20,10 @@ pub struct CsvWriterOptions {
     pub include_header: bool,
     pub batch_size: NonZeroUsize,
     pub serialize_options: Arc<SerializeOptions>,
+    /// Append to an existing file instead of truncating it. The BOM and header are only
+    /// written if the file is empty.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub append: bool,
 }
 
 impl Default for CsvWriterOptions {
@@ -31,6 +35,7 @@ impl Default for CsvWriterOptions {
             include_header: true,
             batch_size: NonZeroUsize::new(1024).unwrap(),
             serialize_options: SerializeOptions::default().into(),
+            append: false,
         }
     }
 }
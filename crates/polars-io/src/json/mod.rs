@@ -80,11 +80,11 @@ use simd_json::BorrowedValue;
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::prelude::*;
 
-/// The format to use to write the DataFrame to JSON: `Json` (a JSON array)
-/// or `JsonLines` (each row output on a separate line).
+/// The format to use to write the DataFrame to JSON: `Json` (a JSON array), `JsonLines` (each
+/// row output on a separate line) or `Values` (a JSON array of row arrays).
 ///
-/// In either case, each row is serialized as a JSON object whose keys are the column names and
-/// whose values are the row's corresponding values.
+/// For `Json` and `JsonLines`, each row is serialized as a JSON object whose keys are the column
+/// names and whose values are the row's corresponding values.
 pub enum JsonFormat {
     /// A single JSON array containing each DataFrame row as an object. The length of the array is the number of rows in
     /// the DataFrame.
@@ -99,6 +99,11 @@ pub enum JsonFormat {
     ///
     /// It is recommended to use the file extension `.jsonl` when saving as JSON Lines.
     JsonLines,
+    /// A single JSON array containing each DataFrame row as an array of its values, in column order. The column
+    /// names are not written.
+    ///
+    /// This format can only be written, not read, as the column names are lost.
+    Values,
 }
 
 /// Writes a DataFrame to JSON.
@@ -160,6 +165,21 @@ where
                 let serializer = polars_json::json::write::Serializer::new(batches, vec![]);
                 polars_json::json::write::write(&mut self.buffer, serializer)?;
             },
+            JsonFormat::Values => {
+                self.buffer.write_all(b"[")?;
+                let mut is_first_row = true;
+                for chunk in df.iter_chunks(CompatLevel::newest(), false) {
+                    let mut rows = polars_json::json::write::ValuesSerializer::new(&chunk, vec![]);
+                    while let Some(row) = rows.next()? {
+                        if !is_first_row {
+                            self.buffer.write_all(b",")?;
+                        }
+                        is_first_row = false;
+                        self.buffer.write_all(row)?;
+                    }
+                }
+                self.buffer.write_all(b"]")?;
+            },
         }
 
         Ok(())
@@ -377,6 +397,9 @@ where
 
                 df
             },
+            JsonFormat::Values => {
+                polars_bail!(InvalidOperation: "reading JSON in the 'Values' format is not supported")
+            },
             JsonFormat::JsonLines => {
                 let mut json_reader = CoreJsonReader::new(
                     rb,
@@ -2,7 +2,83 @@
 
 use std::sync::Arc;
 
+use polars_core::prelude::*;
 pub use polars_parquet::parquet::metadata::FileMetadata;
+use polars_parquet::read::infer_schema;
+use polars_parquet::read::statistics::deserialize_all;
 pub use polars_parquet::read::statistics::{Statistics as ParquetStatistics, deserialize};
 
 pub type FileMetadataRef = Arc<FileMetadata>;
+
+/// Collect the column statistics of every row group into a [`DataFrame`] with the columns
+/// `row_group`, `column`, `min`, `max` and `null_count`.
+///
+/// `min` and `max` are formatted as strings, as their types differ between columns. Columns
+/// without statistics, such as nested columns, get nulls.
+pub fn statistics_to_df(metadata: &FileMetadata) -> PolarsResult<DataFrame> {
+    let schema = infer_schema(metadata)?;
+    let row_groups = metadata.row_groups.as_slice();
+    let height = schema.len() * row_groups.len();
+
+    let mut row_group = Vec::with_capacity(height);
+    let mut column = Vec::with_capacity(height);
+    let mut min = Series::new_empty(PlSmallStr::from_static("min"), &DataType::String);
+    let mut max = Series::new_empty(PlSmallStr::from_static("max"), &DataType::String);
+    let mut null_count = Series::new_empty(PlSmallStr::from_static("null_count"), &IDX_DTYPE);
+
+    for field in schema.iter_values() {
+        row_group.extend(0..row_groups.len() as IdxSize);
+        column.extend(std::iter::repeat_n(field.name.as_str(), row_groups.len()));
+
+        // Statistics of nested columns are spread over multiple leaves; skip those.
+        let statistics = match row_groups
+            .first()
+            .and_then(|rg| rg.columns_idxs_under_root_iter(&field.name))
+        {
+            Some(&[idx]) => deserialize_all(field, row_groups, idx, &metadata.footer_buf)?,
+            _ => None,
+        };
+
+        let Some(statistics) = statistics else {
+            let nulls = Series::full_null(PlSmallStr::EMPTY, row_groups.len(), &DataType::String);
+            min.append(&nulls)?;
+            max.append(&nulls)?;
+            null_count.append(&Series::full_null(
+                PlSmallStr::EMPTY,
+                row_groups.len(),
+                &IDX_DTYPE,
+            ))?;
+            continue;
+        };
+
+        for (out, values) in [
+            (&mut min, statistics.min_value),
+            (&mut max, statistics.max_value),
+        ] {
+            let values = unsafe {
+                Series::_try_from_arrow_unchecked_with_md(
+                    PlSmallStr::EMPTY,
+                    vec![values],
+                    field.dtype(),
+                    field.metadata.as_deref(),
+                )
+            }?;
+            out.append(&values.cast(&DataType::String)?)?;
+        }
+        null_count.append(&Series::from_arrow(
+            PlSmallStr::EMPTY,
+            statistics.null_count.boxed(),
+        )?)?;
+    }
+
+    DataFrame::new(
+        height,
+        vec![
+            Column::new(PlSmallStr::from_static("row_group"), row_group),
+            Column::new(PlSmallStr::from_static("column"), column),
+            min.into_column(),
+            max.into_column(),
+            null_count.into_column(),
+        ],
+    )
+}
@@ -21,6 +21,7 @@ pub struct ParquetObjectStore {
     length: Option<usize>,
     metadata: Option<FileMetadataRef>,
     schema: Option<ArrowSchemaRef>,
+    max_footer_size: Option<usize>,
 }
 
 impl ParquetObjectStore {
@@ -38,9 +39,16 @@ impl ParquetObjectStore {
             length: None,
             metadata,
             schema: None,
+            max_footer_size: None,
         })
     }
 
+    /// Error instead of fetching a footer larger than this many bytes.
+    pub fn with_max_footer_size(mut self, max_footer_size: Option<usize>) -> Self {
+        self.max_footer_size = max_footer_size;
+        self
+    }
+
     /// Initialize the length property of the object, unless it has already been fetched.
     async fn length(&mut self) -> PolarsResult<usize> {
         if self.length.is_none() {
@@ -58,7 +66,7 @@ impl ParquetObjectStore {
     /// Fetch the metadata of the parquet file, do not memoize it.
     async fn fetch_metadata(&mut self) -> PolarsResult<FileMetadata> {
         let length = self.length().await?;
-        fetch_metadata(&self.store, &self.path, length).await
+        fetch_metadata(&self.store, &self.path, length, self.max_footer_size).await
     }
 
     /// Fetch and memoize the metadata of the parquet file.
@@ -112,6 +120,7 @@ async fn fetch_footer_bytes(
     store: &PolarsObjectStore,
     path: &ObjectPath,
     file_byte_length: usize,
+    max_footer_size: Option<usize>,
 ) -> PolarsResult<Buffer<u8>> {
     let out_of_spec = |msg: &str| ParquetError::OutOfSpec(msg.to_string());
 
@@ -145,6 +154,15 @@ async fn fetch_footer_bytes(
             .map_err(|_| out_of_spec("negative footer byte length"))?
     };
 
+    if let Some(max_footer_size) = max_footer_size
+        && footer_byte_length > max_footer_size
+    {
+        polars_bail!(
+            ComputeError: "the footer size of {} bytes exceeds the limit of {} bytes",
+            footer_byte_length, max_footer_size
+        );
+    }
+
     let footer_len = FOOTER_SIZE as usize + footer_byte_length;
     if footer_len <= prefetched.len() {
         // Common case: footer already in the prefetch; zero extra round trips.
@@ -164,13 +182,15 @@ async fn fetch_footer_bytes(
     }
 }
 
-/// Asynchronously reads the files' metadata.
+/// Asynchronously reads the files' metadata, erroring if the footer is larger than
+/// `max_footer_size` bytes.
 pub async fn fetch_metadata(
     store: &PolarsObjectStore,
     path: &ObjectPath,
     file_byte_length: usize,
+    max_footer_size: Option<usize>,
 ) -> PolarsResult<FileMetadata> {
-    let footer = fetch_footer_bytes(store, path, file_byte_length).await?;
+    let footer = fetch_footer_bytes(store, path, file_byte_length, max_footer_size).await?;
     Ok(deserialize_metadata(footer)?)
 }
 
@@ -180,6 +200,6 @@ pub async fn fetch_num_rows(
     path: &ObjectPath,
     file_byte_length: usize,
 ) -> PolarsResult<i64> {
-    let footer = fetch_footer_bytes(store, path, file_byte_length).await?;
+    let footer = fetch_footer_bytes(store, path, file_byte_length, None).await?;
     Ok(deserialize_num_rows(footer)?)
 }
@@ -10,6 +10,9 @@ pub struct ParquetOptions {
     pub parallel: ParallelStrategy,
     pub low_memory: bool,
     pub use_statistics: bool,
+    /// Error instead of reading a footer larger than this many bytes.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_footer_size: Option<usize>,
 }
 
 impl Default for ParquetOptions {
@@ -19,6 +22,7 @@ impl Default for ParquetOptions {
             parallel: ParallelStrategy::default(),
             low_memory: false,
             use_statistics: true,
+            max_footer_size: None,
         }
     }
 }
@@ -28,6 +28,7 @@ pub struct ParquetReader<R: Read + Seek> {
     metadata: Option<FileMetadataRef>,
     hive_partition_columns: Option<Vec<Series>>,
     include_file_path: Option<(PlSmallStr, PlRefStr)>,
+    max_footer_size: Option<usize>,
 }
 
 impl<R: MmapBytesReader> ParquetReader<R> {
@@ -164,13 +165,26 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Error instead of reading a footer larger than this many bytes. This bounds the memory
+    /// spent on the footer of untrusted files.
+    pub fn with_max_footer_size(mut self, max_footer_size: Option<usize>) -> Self {
+        self.max_footer_size = max_footer_size;
+        self
+    }
+
     pub fn set_metadata(&mut self, metadata: FileMetadataRef) {
         self.metadata = Some(metadata);
     }
 
     pub fn get_metadata(&mut self) -> PolarsResult<&FileMetadataRef> {
         if self.metadata.is_none() {
-            self.metadata = Some(Arc::new(read::read_metadata(&mut self.reader)?));
+            let metadata = match self.max_footer_size {
+                Some(max_footer_size) => {
+                    read::read_metadata_with_limit(&mut self.reader, max_footer_size)?
+                },
+                None => read::read_metadata(&mut self.reader)?,
+            };
+            self.metadata = Some(Arc::new(metadata));
         }
         Ok(self.metadata.as_ref().unwrap())
     }
@@ -192,6 +206,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             schema: None,
             hive_partition_columns: None,
             include_file_path: None,
+            max_footer_size: None,
         }
     }
 
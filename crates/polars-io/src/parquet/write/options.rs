@@ -25,6 +25,9 @@ pub struct ParquetWriteOptions {
     pub arrow_schema: Option<ArrowSchemaRef>,
     #[cfg_attr(feature = "serde", serde(default))]
     pub compat_level: Option<CompatLevel>,
+    /// Error instead of writing a footer larger than this many bytes.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_footer_size: Option<usize>,
 }
 
 impl ParquetWriteOptions {
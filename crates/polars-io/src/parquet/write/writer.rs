@@ -6,7 +6,7 @@ use polars_core::frame::chunk_df_for_writing;
 use polars_core::prelude::*;
 use polars_parquet::write::{
     CompressionOptions, Encoding, FileWriter, StatisticsOptions, Version, WriteOptions,
-    get_dtype_encoding, to_parquet_schema,
+    get_field_encoding, to_parquet_schema,
 };
 
 use super::batched_writer::BatchedWriter;
@@ -25,6 +25,7 @@ impl ParquetWriteOptions {
             .with_row_group_size(self.row_group_size)
             .with_data_page_size(self.data_page_size)
             .with_key_value_metadata(self.key_value_metadata.clone())
+            .with_max_footer_size(self.max_footer_size)
     }
 }
 
@@ -46,6 +47,8 @@ pub struct ParquetWriter<W> {
     key_value_metadata: Option<KeyValueMetadata>,
     /// Context info for the Parquet file being written.
     context_info: Option<PlHashMap<String, String>>,
+    /// Error instead of writing a footer larger than this many bytes.
+    max_footer_size: Option<usize>,
 }
 
 impl<W> ParquetWriter<W>
@@ -66,6 +69,7 @@ where
             parallel: true,
             key_value_metadata: None,
             context_info: None,
+            max_footer_size: None,
         }
     }
 
@@ -115,10 +119,18 @@ where
         self
     }
 
+    /// Set the maximum size in bytes of the file metadata in the footer. Very wide schemas or
+    /// many row groups can exceed it, in which case writing errors instead of producing a file
+    /// other readers can't open.
+    pub fn with_max_footer_size(mut self, max_footer_size: Option<usize>) -> Self {
+        self.max_footer_size = max_footer_size;
+        self
+    }
+
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
         let schema = schema_to_arrow_checked(schema, CompatLevel::newest(), "parquet")?;
         let parquet_schema = to_parquet_schema(&schema)?;
-        let encodings = get_encodings(&schema);
+        let encodings = get_encodings(&schema)?;
         let options = self.materialize_options();
         let writer = Mutex::new(FileWriter::try_new(self.writer, schema, options)?);
 
@@ -138,6 +150,7 @@ where
             compression: self.compression,
             version: Version::V1,
             data_page_size: self.data_page_size,
+            max_footer_size: self.max_footer_size,
         }
     }
 
@@ -151,9 +164,8 @@ where
     }
 }
 
-pub fn get_encodings(schema: &ArrowSchema) -> Buffer<Vec<Encoding>> {
-    schema
-        .iter_values()
-        .map(|f| get_dtype_encoding(&f.dtype))
-        .collect()
+/// Returns the encodings of the parquet columns of every field, honoring the encoding set in
+/// the field metadata (see [`polars_parquet::write::ENCODING_METADATA_KEY`]).
+pub fn get_encodings(schema: &ArrowSchema) -> PolarsResult<Buffer<Vec<Encoding>>> {
+    schema.iter_values().map(get_field_encoding).collect()
 }
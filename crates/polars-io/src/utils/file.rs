@@ -6,7 +6,7 @@ use std::sync::Arc;
 
 #[cfg(feature = "cloud")]
 pub use async_writeable::{AsyncDynWriteable, AsyncWriteable};
-use polars_error::{PolarsResult, feature_gated, polars_err};
+use polars_error::{PolarsResult, feature_gated, polars_ensure, polars_err};
 use polars_utils::create_file;
 use polars_utils::file::close_file;
 use polars_utils::mmap::ensure_not_mapped;
@@ -97,6 +97,21 @@ impl Writeable {
         })
     }
 
+    /// Open a local file for appending, creating it if it does not exist.
+    pub fn try_new_append(path: PlRefPath) -> PolarsResult<Self> {
+        polars_ensure!(
+            !path.has_scheme(),
+            InvalidOperation: "appending is only supported for local files, got: {}", path
+        );
+        let path = resolve_homedir(path.as_std_path());
+        let file = std::fs::OpenOptions::new()
+            .append(true)
+            .create(true)
+            .open(&path)?;
+
+        Ok(Self::Local(file))
+    }
+
     /// This returns `Result<>` - if a write was performed before calling this,
     /// `CloudWriter` can be in an Err(_) state.
     #[cfg(feature = "cloud")]
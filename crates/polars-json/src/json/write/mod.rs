@@ -138,6 +138,73 @@ impl FallibleStreamingIterator for RecordSerializer<'_> {
     }
 }
 
+/// [`FallibleStreamingIterator`] that serializes each row of a [`RecordBatchT`] into a JSON array
+/// of its values, without the column names.
+///
+/// # Implementation
+/// Advancing this iterator is CPU-bounded.
+pub struct ValuesSerializer<'a> {
+    index: usize,
+    end: usize,
+    iterators: Vec<Box<dyn StreamingIterator<Item = [u8]> + Send + Sync + 'a>>,
+    buffer: Vec<u8>,
+}
+
+impl<'a> ValuesSerializer<'a> {
+    /// Creates a new [`ValuesSerializer`].
+    pub fn new<A>(chunk: &'a RecordBatchT<A>, buffer: Vec<u8>) -> Self
+    where
+        A: AsRef<dyn Array>,
+    {
+        let end = chunk.len();
+        let iterators = chunk
+            .arrays()
+            .iter()
+            .map(|arr| new_serializer(arr.as_ref(), 0, usize::MAX) as _)
+            .collect();
+
+        Self {
+            index: 0,
+            end,
+            iterators,
+            buffer,
+        }
+    }
+}
+
+impl FallibleStreamingIterator for ValuesSerializer<'_> {
+    type Item = [u8];
+
+    type Error = PolarsError;
+
+    fn advance(&mut self) -> PolarsResult<()> {
+        self.buffer.clear();
+        if self.index == self.end {
+            return Ok(());
+        }
+
+        self.buffer.push(b'[');
+        for (i, it) in self.iterators.iter_mut().enumerate() {
+            if i > 0 {
+                self.buffer.push(b',');
+            }
+            self.buffer.extend_from_slice(it.next().unwrap());
+        }
+        self.buffer.push(b']');
+
+        self.index += 1;
+        Ok(())
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        if !self.buffer.is_empty() {
+            Some(&self.buffer)
+        } else {
+            None
+        }
+    }
+}
+
 /// Writes valid JSON from an iterator of (assumed JSON-encoded) bytes to `writer`
 pub fn write<W, I>(writer: &mut W, mut blocks: I) -> PolarsResult<()>
 where
@@ -108,6 +108,14 @@ impl LazyFrame {
         }
     }
 
+    /// Get an owned copy of the schema of the current `LazyFrame` computation.
+    ///
+    /// Unlike [`LazyFrame::collect_schema`], the result can be mutated without going through
+    /// [`Arc::make_mut`]. Changes to it don't affect the `LazyFrame`.
+    pub fn collect_schema_owned(&mut self) -> PolarsResult<Schema> {
+        self.collect_schema().map(Arc::unwrap_or_clone)
+    }
+
     pub(super) fn get_arenas(&mut self) -> (Arena<IR>, Arena<AExpr>) {
         match self.cached_arena.lock().unwrap().as_mut() {
             Some(arenas) => (arenas.lp_arena.clone(), arenas.expr_arena.clone()),
@@ -0,0 +1,57 @@
+use polars_core::error::to_compute_err;
+use polars_plan::plans::{IRPlanRef, write_ir_non_recursive};
+use polars_utils::arena::Node;
+use recursive::recursive;
+use serde_json::{Map, Value, json};
+
+use super::*;
+
+/// Describe `node` and (recursively) its inputs as a JSON object.
+#[recursive]
+fn ir_to_json(plan: IRPlanRef<'_>, node: Node) -> Value {
+    let ir = plan.lp_arena.get(node);
+    let schema = ir.schema(plan.lp_arena);
+
+    let mut description = String::new();
+    write_ir_non_recursive(&mut description, ir, plan.expr_arena, &schema, 0).unwrap();
+    let fields = schema
+        .iter()
+        .map(|(name, dtype)| (name.to_string(), Value::String(dtype.to_string())))
+        .collect::<Map<_, _>>();
+    let expressions = ir
+        .exprs()
+        .map(|e| Value::String(e.display(plan.expr_arena).to_string()))
+        .collect::<Vec<_>>();
+    let inputs = ir
+        .inputs()
+        .map(|input| ir_to_json(plan, input))
+        .collect::<Vec<_>>();
+
+    json!({
+        "node_type": <&'static str>::from(ir),
+        "description": description,
+        "schema": fields,
+        "expressions": expressions,
+        "inputs": inputs,
+    })
+}
+
+impl LazyFrame {
+    /// Return the logical plan as a JSON document.
+    ///
+    /// Every node is an object with its `node_type`, a short `description` as in
+    /// [`LazyFrame::explain`], its output `schema`, the `expressions` it evaluates and the
+    /// nodes it reads from as `inputs`. Nodes that are shared, such as caches, are repeated
+    /// under every parent.
+    ///
+    /// If `optimized` is `true`, describes the optimized plan, otherwise the naive plan.
+    pub fn explain_json(&self, optimized: bool) -> PolarsResult<String> {
+        let plan = if optimized {
+            self.clone().to_alp_optimized()?
+        } else {
+            self.clone().to_alp()?
+        };
+        let value = ir_to_json(plan.as_ref(), plan.lp_top);
+        serde_json::to_string(&value).map_err(to_compute_err)
+    }
+}
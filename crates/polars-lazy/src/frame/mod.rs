@@ -6,6 +6,11 @@ mod cached_arenas;
 mod err;
 #[cfg(not(target_arch = "wasm32"))]
 mod exitable;
+#[cfg(feature = "serde")]
+mod explain_json;
+#[cfg(feature = "partition_by")]
+mod partition_by;
+mod ttl_cache;
 
 use std::num::NonZeroUsize;
 use std::sync::mpsc::{Receiver, sync_channel};
@@ -1196,6 +1201,10 @@ impl LazyFrame {
     /// flexible join logic, see [`join`](LazyFrame::join) or
     /// [`join_builder`](LazyFrame::join_builder).
     ///
+    /// To join on multiple key columns, pass them to [`join`](LazyFrame::join) with
+    /// [`JoinType::Anti`]. Like `NOT EXISTS` in SQL, a null key never matches, so rows with
+    /// a null key are always kept. Use [`JoinBuilder::join_nulls`] to let nulls match.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -1935,6 +1944,36 @@ impl LazyFrame {
         }
     }
 
+    /// Add a new column at index 0 that counts the rows in increments of `step`.
+    ///
+    /// The rows are numbered `offset, offset + step, offset + 2 * step, ...`. See
+    /// [`with_row_index`](Self::with_row_index) for the meaning of `name` and `offset`.
+    pub fn with_row_index_and_step<S>(
+        self,
+        name: S,
+        offset: Option<IdxSize>,
+        step: IdxSize,
+    ) -> LazyFrame
+    where
+        S: Into<PlSmallStr>,
+    {
+        let name = name.into();
+        if step == 1 {
+            return self.with_row_index(name, offset);
+        }
+
+        // Count from zero so the row index can still be pushed into scans, then scale it in a
+        // wider type. An index that doesn't fit `IdxSize` fails the strict cast back.
+        let offset = offset.unwrap_or(0);
+        #[cfg(not(feature = "bigidx"))]
+        let (wide_dtype, step, offset) = (DataType::UInt64, lit(step as u64), lit(offset as u64));
+        #[cfg(feature = "bigidx")]
+        let (wide_dtype, step, offset) =
+            (DataType::UInt128, lit(step as u128), lit(offset as u128));
+        let scaled = (col(name.clone()).cast(wide_dtype) * step + offset).strict_cast(IDX_DTYPE);
+        self.with_row_index(name, None).with_column(scaled)
+    }
+
     /// Return the number of non-null elements for each column.
     pub fn count(self) -> LazyFrame {
         self.select(vec![col(PlSmallStr::from_static("*")).count()])
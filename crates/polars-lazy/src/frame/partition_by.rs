@@ -0,0 +1,46 @@
+use super::*;
+
+/// Prefix of the temporary columns the partition keys are evaluated into.
+const KEY_PREFIX: &str = "__POLARS_PARTITION_KEY_";
+
+impl LazyFrame {
+    /// Split into a [`LazyFrame`] per distinct value of the `by` expressions.
+    ///
+    /// This collects the query once and partitions the result. Every partition is keyed by the
+    /// values of the `by` expressions in that partition and keeps the columns of the query;
+    /// computed keys are not added as columns. Use this to run a different pipeline on every
+    /// partition.
+    ///
+    /// The keys are owned [`AnyValue`]s, e.g. strings are `AnyValue::StringOwned`.
+    pub fn partition_by<E: AsRef<[Expr]>>(
+        self,
+        by: E,
+    ) -> PolarsResult<PlHashMap<Vec<AnyValue<'static>>, LazyFrame>> {
+        // Evaluate the keys under temporary names, so a computed key can't overwrite a column
+        // with the same name.
+        let keys = by
+            .as_ref()
+            .iter()
+            .map(|e| e.clone().name().prefix(KEY_PREFIX))
+            .collect::<Vec<_>>();
+        let names = self
+            .clone()
+            .select(&keys)
+            .collect_schema()?
+            .iter_names_cloned()
+            .collect::<Vec<_>>();
+        let df = self.with_columns(keys).collect()?;
+        let key_columns = names.iter().cloned().collect::<PlHashSet<_>>();
+
+        df.partition_by_stable(names.iter().cloned(), true)?
+            .into_iter()
+            .map(|part| {
+                let key = names
+                    .iter()
+                    .map(|name| Ok(part.column(name)?.get(0)?.into_static()))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                Ok((key, part.drop_many_amortized(&key_columns).lazy()))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,73 @@
+use std::time::{Duration, Instant};
+
+use super::*;
+
+/// Anonymous scan that collects its input at most once per `ttl`.
+///
+/// The cached result is owned by the scan, so it is shared by every clone of the
+/// resulting [`LazyFrame`] and dropped together with it.
+struct TtlCache {
+    input: LazyFrame,
+    ttl: Duration,
+    cached: Mutex<Option<(Instant, DataFrame)>>,
+}
+
+impl AnonymousScan for TtlCache {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let fresh = |cached: &Option<(Instant, DataFrame)>| match cached {
+            Some((at, df)) if at.elapsed() < self.ttl => Some(df.clone()),
+            _ => None,
+        };
+
+        // Don't hold the lock while collecting: the input may run on the same thread pool as
+        // a concurrent scan of this cache, which would then block on the lock.
+        let cached = fresh(&self.cached.lock().unwrap());
+        let df = match cached {
+            Some(df) => df,
+            None => {
+                let df = self.input.clone().collect()?;
+                let mut cached = self.cached.lock().unwrap();
+                // Another scan may have refreshed the cache in the meantime, prefer its result
+                // so all scans within a ttl see the same data.
+                match fresh(&cached) {
+                    Some(df) => df,
+                    None => {
+                        *cached = Some((Instant::now(), df.clone()));
+                        df
+                    },
+                }
+            },
+        };
+        Ok(match scan_opts.n_rows {
+            Some(n_rows) => df.head(Some(n_rows)),
+            None => df,
+        })
+    }
+}
+
+impl LazyFrame {
+    /// Caches the result of this query across collects for the duration of `ttl`.
+    ///
+    /// Unlike [`LazyFrame::cache`], which only deduplicates work within a single query,
+    /// every collect of the returned LazyFrame (or its clones) reuses the result until it
+    /// is older than `ttl`, after which the input is computed again.
+    pub fn cache_with_ttl(mut self, ttl: Duration) -> PolarsResult<Self> {
+        let opt_state = self.get_opt_state();
+        let schema = self.collect_schema()?;
+        let function = Arc::new(TtlCache {
+            input: self,
+            ttl,
+            cached: Mutex::new(None),
+        });
+        let args = ScanArgsAnonymous {
+            schema: Some(schema),
+            name: "cache_with_ttl",
+            ..Default::default()
+        };
+        Ok(LazyFrame::anonymous_scan(function, args)?.with_optimizations(opt_state))
+    }
+}
@@ -1,7 +1,9 @@
 use polars_buffer::Buffer;
 use polars_core::prelude::*;
+use polars_core::runtime::ASYNC;
 use polars_io::cloud::CloudOptions;
-use polars_io::parquet::read::ParallelStrategy;
+use polars_io::parquet::metadata::statistics_to_df;
+use polars_io::parquet::read::{ParallelStrategy, infer_schema};
 use polars_io::prelude::ParquetOptions;
 use polars_io::{HiveOptions, RowIndex};
 use polars_utils::pl_path::PlRefPath;
@@ -9,6 +11,19 @@ use polars_utils::slice_enum::Slice;
 
 use crate::prelude::*;
 
+/// How the schema of a parquet scan is resolved when its files have different columns.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SchemaEvolution {
+    /// Use the schema of the first file and raise if another file has columns it lacks.
+    #[default]
+    Strict,
+    /// Use the schema of the first file and ignore columns it lacks in the other files.
+    FirstFile,
+    /// Read the footers of all files and use the union of their columns, in order of first
+    /// appearance. Files lacking a column read it as nulls.
+    UnionAllFiles,
+}
+
 #[derive(Clone)]
 pub struct ScanArgsParquet {
     pub n_rows: Option<usize>,
@@ -25,6 +40,13 @@ pub struct ScanArgsParquet {
     pub glob: bool,
     pub include_file_paths: Option<PlSmallStr>,
     pub allow_missing_columns: bool,
+    /// Which casts are allowed when the dtype of a column in a file differs from `schema`.
+    pub cast_columns_policy: CastColumnsPolicy,
+    /// How to resolve the schema when the files have different columns. Not used if `schema`
+    /// is given.
+    pub schema_evolution: SchemaEvolution,
+    /// Error instead of reading a footer larger than this many bytes.
+    pub max_footer_size: Option<usize>,
 }
 
 impl Default for ScanArgsParquet {
@@ -43,6 +65,9 @@ impl Default for ScanArgsParquet {
             glob: true,
             include_file_paths: None,
             allow_missing_columns: false,
+            cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
+            schema_evolution: SchemaEvolution::Strict,
+            max_footer_size: None,
         }
     }
 }
@@ -60,16 +85,81 @@ impl LazyParquetReader {
             sources: ScanSources::default(),
         }
     }
+
+    /// Union of the schemas of all files of the scan, read from their footers.
+    fn union_schema(&self) -> PolarsResult<SchemaRef> {
+        // Let the scan expand the globs, so exactly the files it reads are considered.
+        let args = ScanArgsParquet {
+            n_rows: None,
+            row_index: None,
+            schema_evolution: SchemaEvolution::Strict,
+            ..self.args.clone()
+        };
+        let IRPlan {
+            lp_top, lp_arena, ..
+        } = Self::new(args)
+            .with_sources(self.sources.clone())
+            .finish()?
+            .to_alp()?;
+        let (sources, first_metadata) = lp_arena
+            .iter(lp_top)
+            .find_map(|(_, lp)| match lp {
+                IR::Scan {
+                    sources, scan_type, ..
+                } => match scan_type.as_ref() {
+                    FileScanIR::Parquet { first_metadata, .. } => {
+                        Some((sources.clone(), first_metadata.clone()))
+                    },
+                    _ => None,
+                },
+                _ => None,
+            })
+            .unwrap();
+
+        let mut schema = Schema::default();
+        for (i, source) in sources.iter().enumerate() {
+            let metadata = match first_metadata.as_ref().filter(|_| i == 0) {
+                Some(metadata) => metadata.clone(),
+                None => ASYNC.block_on(read_parquet_metadata(
+                    source,
+                    self.args.cloud_options.as_ref(),
+                    self.args.max_footer_size,
+                ))?,
+            };
+            let file_schema = Schema::from_arrow_schema(&infer_schema(&metadata)?);
+            for (name, dtype) in file_schema.iter() {
+                match schema.get(name) {
+                    None => {
+                        schema.with_column(name.clone(), dtype.clone());
+                    },
+                    Some(first_dtype) => polars_ensure!(
+                        first_dtype == dtype,
+                        SchemaMismatch: "column '{}' has dtype {} in '{}', but {} in an earlier file",
+                        name, dtype, source.to_include_path_name(), first_dtype
+                    ),
+                }
+            }
+        }
+        Ok(Arc::new(schema))
+    }
 }
 
 impl LazyFileListReader for LazyParquetReader {
     /// Get the final [LazyFrame].
-    fn finish(self) -> PolarsResult<LazyFrame> {
+    fn finish(mut self) -> PolarsResult<LazyFrame> {
+        if self.args.schema_evolution == SchemaEvolution::UnionAllFiles
+            && self.args.schema.is_none()
+        {
+            self.args.schema = Some(self.union_schema()?);
+            self.args.allow_missing_columns = true;
+        }
+
         let parquet_options = ParquetOptions {
             schema: self.args.schema,
             parallel: self.args.parallel,
             low_memory: self.args.low_memory,
             use_statistics: self.args.use_statistics,
+            max_footer_size: self.args.max_footer_size,
         };
 
         let unified_scan_args = UnifiedScanArgs {
@@ -89,13 +179,18 @@ impl LazyFileListReader for LazyParquetReader {
                 .args
                 .n_rows
                 .map(|len| Slice::Positive { offset: 0, len }),
-            cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
+            cast_columns_policy: self.args.cast_columns_policy,
             missing_columns_policy: if self.args.allow_missing_columns {
                 MissingColumnsPolicy::Insert
             } else {
                 MissingColumnsPolicy::Raise
             },
-            extra_columns_policy: ExtraColumnsPolicy::Raise,
+            extra_columns_policy: match self.args.schema_evolution {
+                SchemaEvolution::FirstFile => ExtraColumnsPolicy::Ignore,
+                SchemaEvolution::Strict | SchemaEvolution::UnionAllFiles => {
+                    ExtraColumnsPolicy::Raise
+                },
+            },
             include_file_paths: self.args.include_file_paths,
             deletion_files: None,
             table_statistics: None,
@@ -182,4 +277,80 @@ impl LazyFrame {
     ) -> PolarsResult<Self> {
         Self::scan_parquet_sources(ScanSources::Paths(paths), args)
     }
+
+    /// Read the column statistics of every row group from the footers of the files backing a
+    /// parquet scan, without scanning any data.
+    ///
+    /// Returns a [`DataFrame`] with the columns `file`, `row_group`, `column`, `min`, `max`
+    /// and `null_count`. See [`statistics_to_df`] for how the statistics are represented.
+    pub fn parquet_statistics(self) -> PolarsResult<DataFrame> {
+        let IRPlan {
+            lp_top, lp_arena, ..
+        } = self.to_alp()?;
+
+        let Some((sources, first_metadata, cloud_options, max_footer_size)) =
+            lp_arena.iter(lp_top).find_map(|(_, lp)| match lp {
+                IR::Scan {
+                    sources,
+                    scan_type,
+                    unified_scan_args,
+                    ..
+                } => match scan_type.as_ref() {
+                    FileScanIR::Parquet {
+                        options,
+                        first_metadata,
+                        ..
+                    } => Some((
+                        sources.clone(),
+                        first_metadata.clone(),
+                        unified_scan_args.cloud_options.clone(),
+                        options.max_footer_size,
+                    )),
+                    _ => None,
+                },
+                _ => None,
+            })
+        else {
+            polars_bail!(InvalidOperation: "parquet_statistics requires a parquet scan");
+        };
+
+        let mut out: Option<DataFrame> = None;
+        for (i, source) in sources.iter().enumerate() {
+            let metadata = match first_metadata.as_ref().filter(|_| i == 0) {
+                Some(metadata) => metadata.clone(),
+                None => ASYNC.block_on(read_parquet_metadata(
+                    source,
+                    cloud_options.as_ref(),
+                    max_footer_size,
+                ))?,
+            };
+
+            let mut df = statistics_to_df(&metadata)?;
+            let file = Column::new_scalar(
+                PlSmallStr::from_static("file"),
+                Scalar::from(PlSmallStr::from_str(source.to_include_path_name())),
+                df.height(),
+            );
+            df.insert_column(0, file)?;
+
+            match out.as_mut() {
+                Some(out) => {
+                    out.vstack_mut_owned(df)?;
+                },
+                None => out = Some(df),
+            }
+        }
+
+        // A scan without files has no row groups.
+        Ok(out.unwrap_or_else(|| {
+            DataFrame::empty_with_schema(&Schema::from_iter([
+                Field::new(PlSmallStr::from_static("file"), DataType::String),
+                Field::new(PlSmallStr::from_static("row_group"), IDX_DTYPE),
+                Field::new(PlSmallStr::from_static("column"), DataType::String),
+                Field::new(PlSmallStr::from_static("min"), DataType::String),
+                Field::new(PlSmallStr::from_static("max"), DataType::String),
+                Field::new(PlSmallStr::from_static("null_count"), IDX_DTYPE),
+            ]))
+        }))
+    }
 }
@@ -81,3 +81,33 @@ fn test_lazy_ternary() {
             .unwrap()
     );
 }
+
+#[test]
+fn test_pow_broadcast() -> PolarsResult<()> {
+    let df = df! {
+        "a" => [2i64, 3, 4],
+        "e" => [2i64, 1, 0],
+    }?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("a")
+                .pow(lit(Series::new("".into(), [2i64])))
+                .alias("base_broadcast"),
+            lit(Series::new("".into(), [2i64]))
+                .pow(col("e"))
+                .alias("exponent_broadcast"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("base_broadcast")?.i64()?),
+        &[Some(4), Some(9), Some(16)]
+    );
+    assert_eq!(
+        Vec::from(out.column("exponent_broadcast")?.i64()?),
+        &[Some(4), Some(2), Some(1)]
+    );
+    Ok(())
+}
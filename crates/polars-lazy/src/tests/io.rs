@@ -358,6 +358,40 @@ fn test_parquet_statistics() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "parquet")]
+fn test_lazy_parquet_statistics() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let out = scan_foods_parquet(false)
+        .select([col("calories")])
+        .parquet_statistics()?;
+    assert_eq!(
+        out.get_column_names(),
+        &["file", "row_group", "column", "min", "max", "null_count"]
+    );
+    assert_eq!(out.height(), 4);
+
+    let out = out
+        .lazy()
+        .filter(col("column").eq(lit("calories")))
+        .collect()?;
+    assert!(
+        out.column("file")?
+            .str()?
+            .get(0)
+            .unwrap()
+            .ends_with("foods1.parquet")
+    );
+    assert_eq!(out.column("row_group")?.idx()?.get(0), Some(0));
+    assert_eq!(out.column("min")?.str()?.get(0), Some("20"));
+    assert_eq!(out.column("max")?.str()?.get(0), Some("200"));
+    assert_eq!(out.column("null_count")?.idx()?.get(0), Some(0));
+
+    let err = df!["a" => [1]]?.lazy().parquet_statistics();
+    assert!(err.is_err());
+    Ok(())
+}
+
 #[test]
 #[cfg(not(target_os = "windows"))]
 fn test_parquet_globbing() -> PolarsResult<()> {
@@ -738,6 +772,51 @@ fn scan_anonymous_fn_with_options() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn scan_cache_with_ttl() -> PolarsResult<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    struct CountingScan {
+        n_scans: AtomicUsize,
+    }
+
+    impl AnonymousScan for CountingScan {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn scan(&self, _scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+            self.n_scans.fetch_add(1, Ordering::Relaxed);
+            std::thread::sleep(Duration::from_millis(10));
+            Ok(fruits_cars())
+        }
+    }
+
+    let function = Arc::new(CountingScan {
+        n_scans: AtomicUsize::new(0),
+    });
+    let args = || ScanArgsAnonymous {
+        schema: Some(fruits_cars().schema().clone()),
+        ..ScanArgsAnonymous::default()
+    };
+
+    let q = LazyFrame::anonymous_scan(function.clone(), args())?
+        .cache_with_ttl(Duration::from_secs(3600))?;
+    let first = q.clone().collect()?;
+    let second = q.select([col("A").sum()]).collect()?;
+    assert!(first.equals(&fruits_cars()));
+    assert_eq!(second.column("A")?.i32()?.get(0), Some(15));
+    assert_eq!(function.n_scans.load(Ordering::Relaxed), 1);
+
+    // An expired result is computed again.
+    let q = LazyFrame::anonymous_scan(function.clone(), args())?.cache_with_ttl(Duration::ZERO)?;
+    q.clone().collect()?;
+    q.collect()?;
+    assert_eq!(function.n_scans.load(Ordering::Relaxed), 3);
+    Ok(())
+}
+
 #[test]
 fn scan_anonymous_fn_count() -> PolarsResult<()> {
     struct MyScan {}
@@ -786,6 +865,33 @@ fn scan_anonymous_fn_count() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_scan_csv_deferred_schema_inference() -> PolarsResult<()> {
+    let path =
+        std::env::temp_dir().join(format!("polars_deferred_schema_{}.csv", std::process::id()));
+
+    // Building the plan doesn't touch the file, so it doesn't have to exist yet.
+    let lf = LazyCsvReader::new(PlRefPath::try_from_path(&path)?)
+        .with_infer_schema_length(Some(2))
+        .finish()?;
+    std::fs::write(&path, "a,b\n1,x\n2,y\n3.5,z\n")?;
+
+    // Only the sample rows are used for inference.
+    let schema = lf.clone().collect_schema();
+    // The inferred schema is kept with the plan and reused by plans built on top of it.
+    std::fs::remove_file(&path)?;
+    let schema_after_removal = lf.clone().select([col("b")]).collect_schema();
+
+    let schema = schema?;
+    assert_eq!(schema.get("a"), Some(&DataType::Int64));
+    assert_eq!(schema.get("b"), Some(&DataType::String));
+    assert_eq!(
+        schema_after_removal?.as_ref(),
+        &Schema::from_iter([Field::new("b".into(), DataType::String)])
+    );
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "dtype-full")]
 fn scan_small_dtypes() -> PolarsResult<()> {
@@ -810,3 +916,173 @@ fn scan_small_dtypes() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_scan_parquet_cast_columns_policy() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join(format!(
+        "polars_cast_columns_policy_{}.parquet",
+        std::process::id()
+    ));
+    let mut df = df!("a" => [1i32, 2, 3])?;
+    ParquetWriter::new(std::fs::File::create(&path)?).finish(&mut df)?;
+
+    let scan = |cast_columns_policy| {
+        let args = ScanArgsParquet {
+            schema: Some(Arc::new(Schema::from_iter([("a".into(), DataType::Int64)]))),
+            cast_columns_policy,
+            ..Default::default()
+        };
+        LazyFrame::scan_parquet(PlRefPath::try_from_path(&path)?, args)?.collect()
+    };
+
+    let strict = scan(CastColumnsPolicy::ERROR_ON_MISMATCH);
+    let upcast = scan(CastColumnsPolicy {
+        integer_upcast: true,
+        ..CastColumnsPolicy::ERROR_ON_MISMATCH
+    });
+    std::fs::remove_file(&path)?;
+
+    assert!(strict.is_err());
+    let out = upcast?;
+    assert_eq!(out.column("a")?.dtype(), &DataType::Int64);
+    assert_eq!(
+        Vec::from(out.column("a")?.i64()?),
+        &[Some(1), Some(2), Some(3)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_scan_parquet_schema_evolution() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join(format!("polars_schema_evolution_{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    let mut first = df!("a" => [1i64, 2])?;
+    let mut second = df!("a" => [3i64], "b" => ["x"])?;
+    ParquetWriter::new(std::fs::File::create(dir.join("0.parquet"))?).finish(&mut first)?;
+    ParquetWriter::new(std::fs::File::create(dir.join("1.parquet"))?).finish(&mut second)?;
+
+    let glob = PlRefPath::try_from_path(&dir.join("*.parquet"))?;
+    let scan = |schema_evolution| {
+        let args = ScanArgsParquet {
+            schema_evolution,
+            ..Default::default()
+        };
+        LazyFrame::scan_parquet(glob.clone(), args)?.collect()
+    };
+    let strict = scan(SchemaEvolution::Strict);
+    let first_file = scan(SchemaEvolution::FirstFile);
+    let union = scan(SchemaEvolution::UnionAllFiles);
+    std::fs::remove_dir_all(&dir)?;
+
+    assert!(strict.is_err());
+    assert!(first_file?.equals(&df!("a" => [1i64, 2, 3])?));
+    let expected = df!("a" => [1i64, 2, 3], "b" => [None, None, Some("x")])?;
+    assert!(union?.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_scan_parquet_union_schema_dtype_conflict() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join(format!("polars_union_conflict_{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    let mut first = df!("a" => [1i64, 2])?;
+    let mut second = df!("a" => ["x"], "b" => [1i64])?;
+    ParquetWriter::new(std::fs::File::create(dir.join("0.parquet"))?).finish(&mut first)?;
+    ParquetWriter::new(std::fs::File::create(dir.join("1.parquet"))?).finish(&mut second)?;
+
+    let args = ScanArgsParquet {
+        schema_evolution: SchemaEvolution::UnionAllFiles,
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_parquet(PlRefPath::try_from_path(&dir.join("*.parquet"))?, args);
+    std::fs::remove_dir_all(&dir)?;
+
+    let err = out.err().unwrap();
+    assert!(matches!(err, PolarsError::SchemaMismatch(_)), "{err}");
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_parquet_max_footer_size() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join(format!("polars_max_footer_size_{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    let mut df = df!("a" => (0..100i64).collect::<Vec<_>>())?;
+
+    let too_small = ParquetWriter::new(Vec::new())
+        .with_max_footer_size(Some(8))
+        .finish(&mut df);
+    assert!(too_small.is_err());
+
+    // A row group per row makes the footer of the second file much larger.
+    let mut first = Vec::new();
+    ParquetWriter::new(&mut first).finish(&mut df)?;
+    let mut second = Vec::new();
+    ParquetWriter::new(&mut second)
+        .with_row_group_size(Some(1))
+        .finish(&mut df)?;
+    let footer_size = |file: &[u8]| {
+        u32::from_le_bytes(file[file.len() - 8..file.len() - 4].try_into().unwrap()) as usize
+    };
+    let limit = footer_size(&first);
+    assert!(footer_size(&second) > limit);
+    std::fs::write(dir.join("0.parquet"), &first)?;
+    std::fs::write(dir.join("1.parquet"), &second)?;
+
+    let read = |name: &str, max_footer_size| -> PolarsResult<DataFrame> {
+        ParquetReader::new(std::fs::File::open(dir.join(name))?)
+            .with_max_footer_size(max_footer_size)
+            .finish()
+    };
+    let glob = PlRefPath::try_from_path(&dir.join("*.parquet"))?;
+    let scan = |max_footer_size| {
+        let args = ScanArgsParquet {
+            max_footer_size,
+            ..Default::default()
+        };
+        LazyFrame::scan_parquet(glob.clone(), args)?.collect()
+    };
+    let first_read = read("0.parquet", Some(limit));
+    let second_read = read("1.parquet", Some(limit));
+    let limited = scan(Some(limit));
+    let unlimited = scan(None);
+    std::fs::remove_dir_all(&dir)?;
+
+    assert_eq!(first_read?.height(), 100);
+    assert!(second_read.is_err());
+    assert!(limited.is_err());
+    assert_eq!(unlimited?.height(), 200);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "streaming", feature = "async"))]
+fn test_collect_batches_parquet() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join(format!(
+        "polars_collect_batches_{}.parquet",
+        std::process::id()
+    ));
+    let mut df = df!("a" => (0..1_000_000i64).collect::<Vec<_>>())?;
+    ParquetWriter::new(std::fs::File::create(&path)?).finish(&mut df)?;
+
+    let batches =
+        LazyFrame::scan_parquet(PlRefPath::try_from_path(&path)?, ScanArgsParquet::default())?
+            .collect_batches(
+                Engine::Streaming,
+                true,
+                std::num::NonZeroUsize::new(10_000),
+                false,
+            )?
+            .collect::<PolarsResult<Vec<_>>>();
+    std::fs::remove_file(&path)?;
+    let batches = batches?;
+
+    assert_eq!(batches.len(), 100);
+    assert!(batches.iter().all(|df| df.height() == 10_000));
+    let out = accumulate_dataframes_vertical(batches)?;
+    assert!(out.equals(&df));
+    Ok(())
+}
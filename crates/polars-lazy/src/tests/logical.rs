@@ -157,3 +157,27 @@ fn test_lazy_logical_plan_join() {
         let _df = lf.collect().unwrap();
     }
 }
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_explain_json() -> PolarsResult<()> {
+    let lf = df!["a" => [1, 2, 3], "b" => ["x", "y", "z"]]?
+        .lazy()
+        .filter(col("a").gt(lit(1)))
+        .select([col("a"), col("b")]);
+
+    for optimized in [false, true] {
+        let plan: serde_json::Value = serde_json::from_str(&lf.explain_json(optimized)?).unwrap();
+        assert!(plan.get("node_type").is_some());
+        assert_eq!(plan["schema"]["a"], "i32");
+
+        // Walk down to the scan, every node has a type.
+        let mut node = &plan;
+        while let Some(input) = node["inputs"].get(0) {
+            assert!(input["node_type"].is_string());
+            node = input;
+        }
+        assert_eq!(node["node_type"], "DATA_FRAME_SCAN");
+    }
+    Ok(())
+}
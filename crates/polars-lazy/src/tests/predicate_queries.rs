@@ -161,6 +161,57 @@ fn test_strict_cast_predicate_pushdown() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_predicate_pushdown_inner_join_left_only() -> PolarsResult<()> {
+    let left = df![
+        "a" => [1, 6, 7, 8],
+        "b" => [1, 2, 3, 4]
+    ]?;
+    let right = df![
+        "a" => [6, 7, 9],
+        "c" => ["x", "y", "z"]
+    ]?;
+
+    let q = left
+        .lazy()
+        .join(right.lazy(), [col("a")], [col("a")], JoinType::Inner.into())
+        .filter(col("a").gt(lit(5)))
+        .filter(col("b").lt(lit(3)));
+
+    // Both filters refer to left columns only, so they are pushed below the join. The filter on
+    // the join key also applies to the right side.
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let root = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    let (input_left, input_right) = lp_arena
+        .iter(root)
+        .find_map(|(_, lp)| match lp {
+            IR::Join {
+                input_left,
+                input_right,
+                ..
+            } => Some((*input_left, *input_right)),
+            _ => None,
+        })
+        .unwrap();
+    assert!(
+        !lp_arena
+            .iter(root)
+            .take_while(|(_, lp)| !matches!(lp, IR::Join { .. }))
+            .any(|(_, lp)| matches!(lp, IR::Filter { .. }))
+    );
+    assert!(matches!(lp_arena.get(input_left), IR::Filter { .. }));
+    assert!(matches!(lp_arena.get(input_right), IR::Filter { .. }));
+
+    let out = q.collect()?;
+    let expected = df![
+        "a" => [6],
+        "b" => [2],
+        "c" => ["x"]
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
 #[test]
 fn test_filter_nulls_created_by_join() -> PolarsResult<()> {
     // #2602
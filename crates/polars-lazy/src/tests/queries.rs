@@ -67,6 +67,86 @@ fn test_lazy_unpivot() {
     assert_eq!(out.shape(), (7, 3));
 }
 
+#[test]
+#[cfg(feature = "pivot")]
+fn test_lazy_pivot() -> PolarsResult<()> {
+    use polars_core::frame::PivotColumnNaming;
+
+    let df = df![
+        "key" => ["a", "a", "b", "c", "c"],
+        "on" => ["x", "y", "x", "y", "x"],
+        "value" => [1, 2, 3, 4, 50],
+    ]?;
+    let on_columns = Arc::new(df!["on" => ["x", "y"]]?);
+
+    // The pivot is part of the plan, so it can be preceded and followed by other lazy
+    // operations.
+    let out = df
+        .lazy()
+        .filter(col("value").lt(lit(10)))
+        .pivot(
+            cols(["on"]),
+            on_columns,
+            cols(["key"]),
+            cols(["value"]),
+            element().first(),
+            true,
+            "_".into(),
+            PivotColumnNaming::Auto,
+        )
+        .with_column((col("x") + col("y")).alias("total"))
+        .collect()?;
+
+    let expected = df![
+        "key" => ["a", "b", "c"],
+        "x" => [Some(1), Some(3), None],
+        "y" => [Some(2), None, Some(4)],
+        "total" => [Some(3), None, None],
+    ]?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "partition_by")]
+fn test_lazy_partition_by() -> PolarsResult<()> {
+    let df = df![
+        "key" => ["a", "b", "a", "c", "b", "a"],
+        "value" => [1, 2, 3, 4, 5, 6],
+    ]?;
+
+    let parts = df
+        .clone()
+        .lazy()
+        .filter(col("value").neq(lit(4)))
+        .partition_by([col("key")])?;
+    assert_eq!(parts.len(), 2);
+
+    let a = parts[&vec![AnyValue::StringOwned("a".into())]]
+        .clone()
+        .collect()?;
+    assert_eq!(
+        Vec::from(a.column("value")?.i32()?),
+        &[Some(1), Some(3), Some(6)]
+    );
+    let b = parts[&vec![AnyValue::StringOwned("b".into())]]
+        .clone()
+        .select([col("value").sum()])
+        .collect()?;
+    assert_eq!(b.column("value")?.i32()?.get(0), Some(7));
+
+    // Keys can be computed, without overwriting the column they are named after.
+    let parts = df.lazy().partition_by([col("key").eq(lit("a"))])?;
+    assert_eq!(parts.len(), 2);
+    let not_a = parts[&vec![AnyValue::Boolean(false)]].clone().collect()?;
+    assert_eq!(not_a.get_column_names(), &["key", "value"]);
+    assert_eq!(
+        Vec::from(not_a.column("key")?.str()?),
+        &[Some("b"), Some("c"), Some("b")]
+    );
+    Ok(())
+}
+
 #[test]
 fn test_lazy_drop_nulls() {
     let df = df! {
@@ -1221,6 +1301,39 @@ fn test_cross_join() -> PolarsResult<()> {
     Ok(())
 }
 
+#[cfg(feature = "iejoin")]
+#[test]
+fn test_join_where_half_open_interval() -> PolarsResult<()> {
+    let intervals = df![
+        "id" => [1, 2, 3],
+        "start" => [0, 5, 10],
+        "end" => [5, 10, 15]
+    ]?;
+    let events = df![
+        "ts" => [0, 4, 5, 9, 10, 15, -1]
+    ]?;
+
+    // `start <= ts < end`: boundary events belong to the interval they start.
+    let out = intervals
+        .lazy()
+        .join_builder()
+        .with(events.lazy())
+        .join_where(vec![
+            col("ts").gt_eq(col("start")),
+            col("ts").lt(col("end")),
+        ])
+        .sort(["ts"], Default::default())
+        .select([col("id"), col("ts")])
+        .collect()?;
+
+    let expected = df![
+        "id" => [1, 1, 2, 2, 3],
+        "ts" => [0, 4, 5, 9, 10]
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
 #[test]
 fn test_select_empty_df() -> PolarsResult<()> {
     // https://github.com/pola-rs/polars/issues/1056
@@ -1495,6 +1608,22 @@ fn test_when_then_schema() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_collect_schema_owned() -> PolarsResult<()> {
+    let mut lf = fruits_cars().lazy().select([col("A"), col("B")]);
+
+    let mut schema = lf.collect_schema_owned()?;
+    schema.set_dtype("A", DataType::String);
+    schema.insert("C".into(), DataType::Boolean);
+
+    let original = lf.collect_schema()?;
+    assert_eq!(original.len(), 2);
+    assert_eq!(original.get("A"), Some(&DataType::Int32));
+    assert_eq!(lf.collect_schema_owned()?, *original);
+
+    Ok(())
+}
+
 #[test]
 fn test_singleton_broadcast() -> PolarsResult<()> {
     let df = fruits_cars();
@@ -1743,8 +1872,8 @@ fn empty_df() -> PolarsResult<()> {
             col("A").fill_null(lit(1)).alias("4"),
             col("A").cum_count(false).alias("5"),
             col("A").diff(lit(1), NullBehavior::Ignore).alias("6"),
-            col("A").cum_max(false).alias("7"),
-            col("A").cum_min(false).alias("8"),
+            col("A").cum_max(false, true).alias("7"),
+            col("A").cum_min(false, true).alias("8"),
         ])
         .collect()?;
 
@@ -2046,3 +2175,30 @@ fn test_named_udfs() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_with_row_index_and_step() -> PolarsResult<()> {
+    let df = df!["a" => [1, 2, 3, 4]]?;
+    let out = df
+        .lazy()
+        .with_row_index_and_step("idx", Some(5), 10)
+        .collect()?;
+
+    assert_eq!(out.get_column_names(), &["idx", "a"]);
+    assert_eq!(out.column("idx")?.dtype(), &IDX_DTYPE);
+    assert_eq!(
+        out.column("idx")?
+            .idx()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &[5, 15, 25, 35]
+    );
+
+    let err = df!["a" => [1, 2, 3]]?
+        .lazy()
+        .with_row_index_and_step("idx", Some(1), IdxSize::MAX / 2 + 1)
+        .collect()
+        .unwrap_err();
+    assert!(matches!(err, PolarsError::InvalidOperation(_)));
+    Ok(())
+}
@@ -4,6 +4,7 @@ mod v1;
 mod global_alloc;
 mod memory_manager;
 mod spill_context;
+mod spill_file;
 mod spill_frame;
 
 use std::cell::UnsafeCell;
@@ -22,6 +23,7 @@ pub use spill_context::{
     LeastRecentSpillContext, MostRecentSpillContext, ParameterFreeSpillContext, RandomSpillContext,
     SpillContext,
 };
+pub use spill_file::SpillFile;
 pub use spill_frame::SpillFrame;
 
 // SpillTokenInner's state
@@ -0,0 +1,64 @@
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use polars_core::prelude::{DataFrame, PolarsResult};
+use polars_io::SerReader;
+use polars_io::parquet::read::ParquetReader;
+use polars_io::parquet::write::{ParquetCompression, ParquetWriter};
+use polars_io::path_utils::create_dir_owner_only;
+
+static SPILL_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// A DataFrame that was explicitly written to a parquet file in the spill directory.
+///
+/// Unlike a [`SpillFrame`](crate::SpillFrame), which the memory manager may spill
+/// when it sees fit, a `SpillFile` is always on disk. The file is removed when
+/// the `SpillFile` is dropped.
+pub struct SpillFile {
+    path: PathBuf,
+    height: usize,
+}
+
+impl SpillFile {
+    /// Writes the DataFrame to a new file in the spill directory.
+    pub fn write(mut df: DataFrame) -> PolarsResult<Self> {
+        let process_dir = polars_config::config()
+            .ooc_spill_dir()
+            .join(std::process::id().to_string());
+        create_dir_owner_only(&process_dir)?;
+
+        let seq = SPILL_FILE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = process_dir.join(format!("spill_file_{seq}.parquet"));
+        if polars_config::config().verbose() {
+            eprintln!("[ooc] spill_file {} rows={}", path.display(), df.height());
+        }
+        let mut file = std::fs::File::create(&path)?;
+        let slf = Self {
+            path,
+            height: df.height(),
+        };
+        // Spill files are short-lived, so favour write speed over file size.
+        ParquetWriter::new(&mut file)
+            .with_compression(ParquetCompression::Lz4Raw)
+            .finish(&mut df)?;
+        Ok(slf)
+    }
+
+    /// The height of the spilled DataFrame.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Reads the DataFrame back into memory.
+    pub fn read(&self) -> PolarsResult<DataFrame> {
+        let file = std::fs::File::open(&self.path)?;
+        ParquetReader::new(file).finish()
+    }
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        // Best-effort, a leftover file doesn't affect correctness.
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
@@ -259,3 +259,32 @@ pub fn hist_series(
     });
     Ok(out)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_hist_counts_every_value() -> PolarsResult<()> {
+        let s = Series::new(
+            "a".into(),
+            (0..1000).map(|i| i as f64 / 1000.0).collect::<Vec<_>>(),
+        );
+        let counts = |out: Series| -> PolarsResult<Vec<IdxSize>> {
+            let out = out.struct_()?.field_by_name("count")?;
+            Ok(out.idx()?.into_no_null_iter().collect())
+        };
+
+        // Edges derived from the data cover every value.
+        let out = hist_series(&s, Some(10), None, false, true)?;
+        let count = counts(out)?;
+        assert_eq!(count.len(), 10);
+        assert_eq!(count.iter().sum::<IdxSize>(), 1000);
+
+        // The first bin is closed on both sides, the others only on the right.
+        let bins = Series::new("".into(), [0.0, 0.5, 1.0]);
+        let out = hist_series(&s, None, Some(bins), false, true)?;
+        assert_eq!(counts(out)?, [501, 499]);
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+use arrow::array::{Array, ListArray};
+use arrow::offset::OffsetsBuffer;
+
+use super::*;
+
+/// Split every list of `arr` into sublists of `n` values, reusing the values buffer.
+fn chunk_every_array(arr: &ListArray<i64>, n: usize) -> ListArray<i64> {
+    let offsets = arr.offsets().as_slice();
+    let mut inner_offsets = Vec::with_capacity(arr.values().len() / n + arr.len() + 1);
+    let mut outer_offsets = Vec::with_capacity(arr.len() + 1);
+    inner_offsets.push(offsets[0]);
+    outer_offsets.push(0i64);
+    for (i, w) in offsets.windows(2).enumerate() {
+        let (start, end) = (w[0], w[1]);
+        if arr.is_valid(i) {
+            let mut pos = start;
+            while pos < end {
+                pos = (pos + n as i64).min(end);
+                inner_offsets.push(pos);
+            }
+        } else if end > start {
+            // Null lists may still span values, those go into a single hidden sublist so the
+            // inner offsets stay contiguous.
+            inner_offsets.push(end);
+        }
+        outer_offsets.push(inner_offsets.len() as i64 - 1);
+    }
+
+    // SAFETY: both offsets are monotonically increasing and in bounds of their values.
+    let (inner_offsets, outer_offsets) = unsafe {
+        (
+            OffsetsBuffer::new_unchecked(inner_offsets.into()),
+            OffsetsBuffer::new_unchecked(outer_offsets.into()),
+        )
+    };
+    let inner = ListArray::<i64>::new(
+        arr.dtype().clone(),
+        inner_offsets,
+        arr.values().clone(),
+        None,
+    );
+    ListArray::<i64>::new(
+        ListArray::<i64>::default_datatype(inner.dtype().clone()),
+        outer_offsets,
+        inner.boxed(),
+        arr.validity().cloned(),
+    )
+}
+
+/// Split every list into consecutive sublists of length `n`, the last one may be shorter.
+pub(super) fn chunk_every_list(ca: &ListChunked, n: usize) -> PolarsResult<ListChunked> {
+    polars_ensure!(n > 0, InvalidOperation: "`chunk_every` needs n > 0");
+    let chunks = ca
+        .downcast_iter()
+        .map(|arr| chunk_every_array(arr, n).boxed())
+        .collect::<Vec<_>>();
+    // SAFETY: the inner lists keep the values and logical type of `ca`.
+    Ok(unsafe {
+        ListChunked::from_chunks_and_dtype(
+            ca.name().clone(),
+            chunks,
+            DataType::List(Box::new(ca.dtype().clone())),
+        )
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_chunk_every() -> PolarsResult<()> {
+        let rows = [
+            Some(Series::new("".into(), [1i32, 2, 3, 4, 5])),
+            None,
+            Some(Series::new("".into(), [6i32, 7])),
+            Some(Series::new("".into(), [0i32; 0])),
+        ];
+        let ca = Series::new("a".into(), rows).list()?.clone();
+
+        let out = chunk_every_list(&ca, 2)?;
+        assert_eq!(
+            out.dtype(),
+            &DataType::List(Box::new(DataType::List(Box::new(DataType::Int32))))
+        );
+        let row = |i: usize| -> PolarsResult<Option<Vec<Vec<i32>>>> {
+            out.get_as_series(i)
+                .map(|s| {
+                    s.list()?
+                        .into_no_null_iter()
+                        .map(|sub| Ok(sub.i32()?.into_no_null_iter().collect()))
+                        .collect()
+                })
+                .transpose()
+        };
+        assert_eq!(row(0)?, Some(vec![vec![1, 2], vec![3, 4], vec![5]]));
+        assert_eq!(row(1)?, None);
+        assert_eq!(row(2)?, Some(vec![vec![6, 7]]));
+        assert_eq!(row(3)?, Some(vec![]));
+
+        assert!(chunk_every_list(&ca, 0).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+use arrow::array::{Array, ListArray, PrimitiveArray};
+use arrow::types::NativeType;
+use num_traits::{One, Zero};
+use polars_core::chunked_array::ops::ChunkNestingUtils;
+use polars_core::with_match_physical_numeric_polars_type;
+
+use super::*;
+
+/// Scan every sublist of `values` with `op`, restarting from `init` at every offset.
+/// Null values are skipped and remain null in the output.
+fn cum_scan_between_offsets<T: NativeType>(
+    values: &PrimitiveArray<T>,
+    offsets: &[i64],
+    init: T,
+    op: impl Fn(T, T) -> T,
+) -> PrimitiveArray<T> {
+    let mut out = values.values().to_vec();
+    for w in offsets.windows(2) {
+        let mut state = init;
+        for i in w[0] as usize..w[1] as usize {
+            if values.is_valid(i) {
+                state = op(state, out[i]);
+            }
+            out[i] = state;
+        }
+    }
+    PrimitiveArray::from_vec(out).with_validity(values.validity().cloned())
+}
+
+fn cum_scan_list(
+    ca: &ListChunked,
+    out_inner_dtype: DataType,
+    is_sum: bool,
+) -> PolarsResult<Series> {
+    let out_dtype = DataType::List(Box::new(out_inner_dtype.clone()));
+    let ca = ca.cast(&out_dtype)?;
+    let ca = ca.list()?;
+    let ca = ca
+        .trim_lists_to_normalized_offsets()
+        .unwrap_or_else(|| ca.clone());
+
+    let chunks = with_match_physical_numeric_polars_type!(&out_inner_dtype, |$T| {
+        type N = <$T as PolarsNumericType>::Native;
+        ca.downcast_iter()
+            .map(|arr| {
+                let values = arr
+                    .values()
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<N>>()
+                    .unwrap();
+                let offsets = arr.offsets().as_slice();
+                let values = if is_sum {
+                    cum_scan_between_offsets(values, offsets, N::zero(), |a, b| a + b)
+                } else {
+                    cum_scan_between_offsets(values, offsets, N::one(), |a, b| a * b)
+                };
+                Box::new(ListArray::<i64>::new(
+                    arr.dtype().clone(),
+                    arr.offsets().clone(),
+                    values.boxed(),
+                    arr.validity().cloned(),
+                )) as ArrayRef
+            })
+            .collect::<Vec<_>>()
+    });
+
+    // SAFETY: the values have the physical type of `out_dtype`.
+    let out = unsafe { ListChunked::from_chunks_and_dtype(ca.name().clone(), chunks, out_dtype) };
+    Ok(out.into_series())
+}
+
+/// Cumulative sum within every sublist.
+///
+/// Booleans are summed as `UInt32` and small integers as `Int64` to prevent overflow, like
+/// [`cum_sum`](crate::series::cum_sum).
+pub(super) fn cum_sum_list(ca: &ListChunked) -> PolarsResult<Series> {
+    use DataType::*;
+    let out_inner_dtype = match ca.inner_dtype() {
+        Boolean => UInt32,
+        Int8 | UInt8 | Int16 | UInt16 => Int64,
+        dt if dt.is_primitive_numeric() => dt.clone(),
+        dt => polars_bail!(opq = cum_sum, dt),
+    };
+    cum_scan_list(ca, out_inner_dtype, true)
+}
+
+/// Cumulative product within every sublist.
+///
+/// Booleans and integers smaller than 64 bits are multiplied as `Int64` to prevent overflow,
+/// like [`cum_prod`](crate::series::cum_prod).
+pub(super) fn cum_prod_list(ca: &ListChunked) -> PolarsResult<Series> {
+    use DataType::*;
+    let out_inner_dtype = match ca.inner_dtype() {
+        Boolean | Int8 | UInt8 | Int16 | UInt16 | Int32 | UInt32 => Int64,
+        dt if dt.is_primitive_numeric() => dt.clone(),
+        dt => polars_bail!(opq = cum_prod, dt),
+    };
+    cum_scan_list(ca, out_inner_dtype, false)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_list_cum_sum_prod() -> PolarsResult<()> {
+        let s = Series::new(
+            "a".into(),
+            &[
+                Series::new("".into(), &[Some(1i32), Some(2), Some(3)]),
+                Series::new("".into(), &[Some(4i32), None, Some(5)]),
+            ],
+        );
+        let ca = s.list()?;
+
+        let out = cum_sum_list(ca)?;
+        let out = out.list()?;
+        assert_eq!(
+            Vec::from(out.get_as_series(0).unwrap().i32()?),
+            &[Some(1), Some(3), Some(6)]
+        );
+        assert_eq!(
+            Vec::from(out.get_as_series(1).unwrap().i32()?),
+            &[Some(4), None, Some(9)]
+        );
+
+        let out = cum_prod_list(ca)?;
+        let out = out.list()?;
+        assert_eq!(
+            Vec::from(out.get_as_series(0).unwrap().i64()?),
+            &[Some(1), Some(2), Some(6)]
+        );
+        assert_eq!(
+            Vec::from(out.get_as_series(1).unwrap().i64()?),
+            &[Some(4), None, Some(20)]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+use arrow::array::{Array, ListArray};
+use arrow::datatypes::IdxArr;
+use arrow::offset::{Offsets, OffsetsBuffer};
+use polars_compute::gather::take_unchecked;
+
+use super::*;
+
+/// Concatenate the sublists of every list in `outer`, removing one level of nesting.
+///
+/// Null sublists are skipped, null outer lists stay null.
+fn flatten_one_level(outer: &ListArray<i64>) -> ArrayRef {
+    let inner = outer
+        .values()
+        .as_any()
+        .downcast_ref::<ListArray<i64>>()
+        .unwrap();
+    let outer_offsets = outer.offsets().as_slice();
+    let inner_offsets = inner.offsets().as_slice();
+
+    let (offsets, values) = if inner.null_count() == 0 {
+        // Every sublist is valid, so the inner values can be reused as is.
+        let offsets: Vec<i64> = outer_offsets
+            .iter()
+            .map(|&o| inner_offsets[o as usize])
+            .collect();
+        // SAFETY: the offsets are gathered monotonically from valid inner offsets.
+        let offsets = unsafe { OffsetsBuffer::new_unchecked(offsets.into()) };
+        (offsets, inner.values().clone())
+    } else {
+        // Null sublists may still point at values, gather only the valid ones.
+        let mut offsets = Offsets::<i64>::with_capacity(outer.len());
+        let mut idx = Vec::<IdxSize>::new();
+        for w in outer_offsets.windows(2) {
+            let start = idx.len();
+            for j in w[0] as usize..w[1] as usize {
+                if inner.is_valid(j) {
+                    idx.extend(inner_offsets[j] as IdxSize..inner_offsets[j + 1] as IdxSize);
+                }
+            }
+            offsets.try_push(idx.len() - start).unwrap();
+        }
+        // SAFETY: the indices are taken from valid inner offsets.
+        let values = unsafe { take_unchecked(&**inner.values(), &IdxArr::from_vec(idx)) };
+        (offsets.into(), values)
+    };
+
+    let dtype = ListArray::<i64>::default_datatype(values.dtype().clone());
+    Box::new(ListArray::<i64>::new(
+        dtype,
+        offsets,
+        values,
+        outer.validity().cloned(),
+    ))
+}
+
+/// Flatten up to `n` levels of nesting within every list, stopping early once the inner
+/// type is no longer a list.
+pub(super) fn flatten_n_list(ca: &ListChunked, n: usize) -> ListChunked {
+    let mut out = ca.clone();
+    for _ in 0..n {
+        let DataType::List(inner) = out.inner_dtype() else {
+            break;
+        };
+        let inner_dtype = inner.as_ref().clone();
+        let chunks = out
+            .downcast_iter()
+            .map(flatten_one_level)
+            .collect::<Vec<_>>();
+        // SAFETY: the values keep the physical type of `inner_dtype`.
+        out = unsafe {
+            ListChunked::from_chunks_and_dtype(
+                ca.name().clone(),
+                chunks,
+                DataType::List(Box::new(inner_dtype)),
+            )
+        };
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn nested_3_deep() -> ListChunked {
+        let a = Series::new("".into(), [1i32, 2]);
+        let b = Series::new("".into(), [3i32]);
+        let c = Series::new("".into(), [4i32, 5, 6]);
+        let row0 = Series::new(
+            "".into(),
+            [
+                Series::new("".into(), [a, b]),
+                Series::new("".into(), [c.clone()]),
+            ],
+        );
+        let row1 = Series::new("".into(), [Series::new("".into(), [c])]);
+        Series::new("a".into(), [row0, row1])
+            .list()
+            .unwrap()
+            .clone()
+    }
+
+    #[test]
+    fn test_flatten_n() {
+        let ca = nested_3_deep();
+        let leaf = DataType::Int32;
+        assert_eq!(
+            ca.dtype(),
+            &DataType::List(Box::new(DataType::List(Box::new(DataType::List(
+                Box::new(leaf.clone())
+            )))))
+        );
+
+        assert_eq!(flatten_n_list(&ca, 0).dtype(), ca.dtype());
+
+        let out = flatten_n_list(&ca, 2);
+        assert_eq!(out.dtype(), &DataType::List(Box::new(leaf.clone())));
+        let expected = Series::new("".into(), [1i32, 2, 3, 4, 5, 6]);
+        assert!(out.get_as_series(0).unwrap().equals(&expected));
+        let expected = Series::new("".into(), [4i32, 5, 6]);
+        assert!(out.get_as_series(1).unwrap().equals(&expected));
+
+        let out = flatten_n_list(&ca, usize::MAX);
+        assert_eq!(out.dtype(), &DataType::List(Box::new(leaf)));
+        assert_eq!(out.len(), 2);
+    }
+}
@@ -1,7 +1,11 @@
 use polars_core::prelude::*;
 
+mod chunk_every;
 mod count;
+#[cfg(feature = "cum_agg")]
+mod cum_agg;
 mod dispersion;
+mod flatten;
 mod get;
 mod min_max;
 mod namespace;
@@ -8,6 +8,10 @@ use polars_core::series::ops::NullBehavior;
 use polars_core::utils::{CustomIterTools, try_get_supertype};
 
 use super::*;
+use crate::chunked_array::list::chunk_every::chunk_every_list;
+#[cfg(feature = "cum_agg")]
+use crate::chunked_array::list::cum_agg::{cum_prod_list, cum_sum_list};
+use crate::chunked_array::list::flatten::flatten_n_list;
 use crate::chunked_array::list::min_max::{list_max_function, list_min_function};
 use crate::chunked_array::list::sum_mean::sum_with_nulls;
 #[cfg(feature = "diff")]
@@ -252,6 +256,29 @@ pub trait ListNameSpaceImpl: AsList {
         ca.try_apply_amortized(|s| diff(s.as_ref(), n, null_behavior))
     }
 
+    /// Cumulative sum of every sublist.
+    #[cfg(feature = "cum_agg")]
+    fn lst_cum_sum(&self) -> PolarsResult<Series> {
+        cum_sum_list(self.as_list())
+    }
+
+    /// Cumulative product of every sublist.
+    #[cfg(feature = "cum_agg")]
+    fn lst_cum_prod(&self) -> PolarsResult<Series> {
+        cum_prod_list(self.as_list())
+    }
+
+    /// Flatten up to `n` levels of nesting within every sublist.
+    fn lst_flatten_n(&self, n: usize) -> ListChunked {
+        flatten_n_list(self.as_list(), n)
+    }
+
+    /// Split every sublist into consecutive sublists of length `n`, the last one may be
+    /// shorter.
+    fn lst_chunk_every(&self, n: usize) -> PolarsResult<ListChunked> {
+        chunk_every_list(self.as_list(), n)
+    }
+
     fn lst_shift(&self, periods: &Column) -> PolarsResult<ListChunked> {
         let ca = self.as_list();
         let periods_s = periods.cast(&DataType::Int64)?;
@@ -1,4 +1,6 @@
 use polars_core::prelude::StringChunked;
+#[cfg(feature = "string_titlecase")]
+use unicode_segmentation::UnicodeSegmentation;
 
 // Inlined from std.
 fn convert_while_ascii(b: &[u8], convert: fn(&u8) -> u8, out: &mut Vec<u8>) {
@@ -162,3 +164,44 @@ pub(super) fn to_titlecase<'a>(ca: &'a StringChunked) -> StringChunked {
     };
     ca.apply_mut(f)
 }
+
+/// Like [`to_titlecase`], but words are delimited by the Unicode word boundaries of UAX #29
+/// instead of by non-alphabetic characters.
+#[cfg(feature = "string_titlecase")]
+pub(super) fn to_titlecase_unicode_words<'a>(ca: &'a StringChunked) -> StringChunked {
+    // Amortize allocation.
+    let mut buf = Vec::new();
+
+    // Temporary scratch space.
+    // We have a double copy as we first convert to lowercase and then copy to `buf`.
+    let mut scratch = Vec::new();
+    let f = |s: &'a str| -> &'a str {
+        to_lowercase_helper(s, &mut scratch);
+        let lowercased = unsafe { std::str::from_utf8_unchecked(&scratch) };
+
+        // SAFETY: the buffer is clear, empty string is valid UTF-8.
+        buf.clear();
+        let mut s = unsafe { String::from_utf8_unchecked(std::mem::take(&mut buf)) };
+
+        // Capitalize the first character of every segment that is a word, which
+        // matches the segments returned by `unicode_words`.
+        for segment in lowercased.split_word_bounds() {
+            let mut chars = segment.chars();
+            match chars.next() {
+                Some(first) if segment.chars().any(char::is_alphanumeric) => {
+                    s.extend(first.to_uppercase());
+                    s.push_str(chars.as_str());
+                },
+                _ => s.push_str(segment),
+            }
+        }
+
+        // Put buf back for next iteration.
+        buf = s.into_bytes();
+
+        // SAFETY: apply_mut will copy value from buf before next iteration.
+        let slice = unsafe { std::str::from_utf8_unchecked(&buf) };
+        unsafe { std::mem::transmute::<&str, &'a str>(slice) }
+    };
+    ca.apply_mut(f)
+}
@@ -1,8 +1,10 @@
 use std::iter::zip;
 
 #[cfg(feature = "extract_groups")]
-use arrow::array::{Array, StructArray};
+use arrow::array::{Array, ListArray, StructArray};
 use arrow::array::{MutablePlString, Utf8ViewArray};
+#[cfg(feature = "extract_groups")]
+use arrow::offset::Offsets;
 use polars_core::prelude::arity::{try_binary_mut_with_options, try_unary_mut_with_options};
 use regex::Regex;
 
@@ -69,6 +71,63 @@ pub(super) fn extract_groups(
     Series::try_from((ca.name().clone(), chunks))
 }
 
+#[cfg(feature = "extract_groups")]
+fn extract_all_groups_array(
+    arr: &Utf8ViewArray,
+    reg: &Regex,
+    dtype: ArrowDataType,
+) -> PolarsResult<ArrayRef> {
+    let struct_dtype = ListArray::<i64>::get_child_type(&dtype).clone();
+    let mut builders = (1..reg.captures_len())
+        .map(|_| MutablePlString::with_capacity(arr.len()))
+        .collect::<Vec<_>>();
+    let mut offsets = Offsets::<i64>::with_capacity(arr.len());
+
+    for opt_v in arr {
+        let mut n_matches = 0;
+        if let Some(s) = opt_v {
+            for caps in reg.captures_iter(s) {
+                for (i, builder) in builders.iter_mut().enumerate() {
+                    builder.push(caps.get(i + 1).map(|m| m.as_str()));
+                }
+                n_matches += 1;
+            }
+        }
+        offsets.try_push(n_matches).unwrap();
+    }
+
+    let n_total = *offsets.last() as usize;
+    let values = builders.into_iter().map(|a| a.freeze().boxed()).collect();
+    let structs = StructArray::new(struct_dtype, n_total, values, None);
+    Ok(ListArray::<i64>::new(
+        dtype,
+        offsets.into(),
+        structs.boxed(),
+        arr.validity().cloned(),
+    )
+    .boxed())
+}
+
+#[cfg(feature = "extract_groups")]
+pub(super) fn extract_all_groups(
+    ca: &StringChunked,
+    pat: &str,
+    dtype: &DataType,
+) -> PolarsResult<Series> {
+    let reg = polars_utils::regex_cache::compile_regex(pat)?;
+    polars_ensure!(
+        reg.captures_len() > 1,
+        InvalidOperation: "`extract_all_groups` expects a pattern with at least one capture group"
+    );
+    let arrow_dtype = dtype.try_to_arrow(CompatLevel::newest())?;
+    let chunks = ca
+        .downcast_iter()
+        .map(|array| extract_all_groups_array(array, &reg, arrow_dtype.clone()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    Series::try_from((ca.name().clone(), chunks))
+}
+
 fn extract_group_reg_lit(
     arr: &Utf8ViewArray,
     reg: &Regex,
@@ -174,3 +233,31 @@ pub(super) fn extract_group(
         },
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "extract_groups")]
+    fn test_extract_all_groups() -> PolarsResult<()> {
+        let ca = StringChunked::new("a".into(), [Some("12-abc 34-def"), None, Some("none")]);
+        let fields = vec![
+            Field::new("1".into(), DataType::String),
+            Field::new("2".into(), DataType::String),
+        ];
+        let dtype = DataType::List(Box::new(DataType::Struct(fields)));
+
+        let out = extract_all_groups(&ca, r"(\d+)-(\w+)", &dtype)?;
+        assert_eq!(out.dtype(), &dtype);
+        let out = out.list()?;
+        assert_eq!(out.null_count(), 1);
+        assert_eq!(out.get_as_series(2).unwrap().len(), 0);
+
+        let matches = out.get_as_series(0).unwrap();
+        let matches = matches.struct_()?.fields_as_series();
+        assert_eq!(Vec::from(matches[0].str()?), &[Some("12"), Some("34")]);
+        assert_eq!(Vec::from(matches[1].str()?), &[Some("abc"), Some("def")]);
+        Ok(())
+    }
+}
@@ -24,8 +24,14 @@ mod split;
 mod strip;
 #[cfg(feature = "strings")]
 mod substring;
+#[cfg(feature = "string_tokenize")]
+mod tokenize;
+#[cfg(feature = "strings")]
+mod truncate_bytes;
 #[cfg(feature = "strings")]
 mod unicode_internals;
+#[cfg(feature = "string_word_count")]
+mod word_count;
 
 #[cfg(feature = "strings")]
 pub use concat::*;
@@ -569,6 +569,13 @@ pub trait StringNameSpaceImpl: AsString {
         super::extract::extract_groups(ca, pat, dtype)
     }
 
+    #[cfg(feature = "extract_groups")]
+    /// Extract the capture groups of every match of the pattern as a list of structs.
+    fn extract_all_groups(&self, pat: &str, dtype: &DataType) -> PolarsResult<Series> {
+        let ca = self.as_string();
+        super::extract::extract_all_groups(ca, pat, dtype)
+    }
+
     /// Count all successive non-overlapping regex matches.
     fn count_matches(&self, pat: &str, literal: bool) -> PolarsResult<UInt32Chunked> {
         let ca = self.as_string();
@@ -643,6 +650,15 @@ pub trait StringNameSpaceImpl: AsString {
         case::to_titlecase(ca)
     }
 
+    /// Modify the strings to their titlecase equivalent, delimiting the words by the Unicode
+    /// word boundaries of UAX #29.
+    #[must_use]
+    #[cfg(feature = "string_titlecase")]
+    fn to_titlecase_unicode_words(&self) -> StringChunked {
+        let ca = self.as_string();
+        case::to_titlecase_unicode_words(ca)
+    }
+
     /// Concat with the values from a second StringChunked.
     #[must_use]
     fn concat(&self, other: &StringChunked) -> StringChunked {
@@ -666,6 +682,30 @@ pub trait StringNameSpaceImpl: AsString {
         reverse::reverse(ca)
     }
 
+    /// Count the Unicode words of the string values.
+    #[must_use]
+    #[cfg(feature = "string_word_count")]
+    fn str_word_count(&self) -> UInt32Chunked {
+        let ca = self.as_string();
+        word_count::word_count(ca)
+    }
+
+    /// Split the string values into tokens along the Unicode word boundaries.
+    #[must_use]
+    #[cfg(feature = "string_tokenize")]
+    fn str_tokenize(&self, words_only: bool) -> ListChunked {
+        let ca = self.as_string();
+        tokenize::tokenize(ca, words_only)
+    }
+
+    /// Truncate the string values to at most `n` bytes without splitting a UTF-8 character,
+    /// ending the truncated ones with `ellipsis`.
+    #[must_use]
+    fn str_truncate_bytes(&self, n: usize, ellipsis: Option<&str>) -> StringChunked {
+        let ca = self.as_string();
+        truncate_bytes::truncate_bytes(ca, n, ellipsis)
+    }
+
     /// Slice the string values.
     ///
     /// Determines a substring starting from `offset` and with length `length` of each of the elements in `array`.
@@ -710,3 +750,26 @@ pub trait StringNameSpaceImpl: AsString {
 }
 
 impl StringNameSpaceImpl for StringChunked {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_count_matches() -> PolarsResult<()> {
+        let ca = StringChunked::new("a".into(), &[Some("abab"), Some("abcab"), None]);
+        for literal in [true, false] {
+            let out = ca.count_matches("ab", literal)?;
+            assert_eq!(Vec::from(&out), &[Some(2), Some(2), None]);
+        }
+
+        // Matches don't overlap.
+        let ca = StringChunked::new("a".into(), &["aaaa"]);
+        assert_eq!(Vec::from(&ca.count_matches("aa", true)?), &[Some(2)]);
+        // Regex metacharacters are only special if `literal` is false.
+        let ca = StringChunked::new("a".into(), &["a.b.c"]);
+        assert_eq!(Vec::from(&ca.count_matches(".", true)?), &[Some(2)]);
+        assert_eq!(Vec::from(&ca.count_matches(".", false)?), &[Some(5)]);
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use arrow::array::ValueSize;
+use polars_core::prelude::*;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Split every string into tokens following the Unicode word boundaries of UAX #29.
+///
+/// Whitespace between tokens is dropped. If `words_only` is set, punctuation is dropped as
+/// well and only the words are kept.
+pub fn tokenize(ca: &StringChunked, words_only: bool) -> ListChunked {
+    let mut builder =
+        ListStringChunkedBuilder::new(ca.name().clone(), ca.len(), ca.get_values_size());
+
+    ca.for_each(|opt_s| match opt_s {
+        Some(s) if words_only => builder.append_values_iter(s.unicode_words()),
+        Some(s) => builder.append_values_iter(
+            s.split_word_bounds()
+                .filter(|token| !token.chars().all(char::is_whitespace)),
+        ),
+        None => builder.append_null(),
+    });
+
+    builder.finish()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn tokens(ca: &ListChunked, idx: usize) -> Option<Vec<Option<String>>> {
+        ca.get_as_series(idx).map(|s| {
+            s.str()
+                .unwrap()
+                .into_iter()
+                .map(|t| t.map(String::from))
+                .collect()
+        })
+    }
+
+    #[test]
+    fn test_tokenize() {
+        let ca = StringChunked::new("a".into(), &[Some("Hello, world! 2024"), Some(""), None]);
+
+        let out = tokenize(&ca, true);
+        assert_eq!(
+            tokens(&out, 0),
+            Some(vec![
+                Some("Hello".into()),
+                Some("world".into()),
+                Some("2024".into())
+            ])
+        );
+        assert_eq!(tokens(&out, 1), Some(vec![]));
+        assert_eq!(tokens(&out, 2), None);
+
+        let out = tokenize(&ca, false);
+        assert_eq!(
+            tokens(&out, 0),
+            Some(vec![
+                Some("Hello".into()),
+                Some(",".into()),
+                Some("world".into()),
+                Some("!".into()),
+                Some("2024".into())
+            ])
+        );
+    }
+}
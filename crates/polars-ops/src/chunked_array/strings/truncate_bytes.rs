@@ -0,0 +1,41 @@
+use polars_core::prelude::StringChunked;
+
+/// Truncate every string to at most `n` bytes, cutting at the last character boundary that
+/// fits. Strings that are cut end with `ellipsis`, which counts towards the `n` bytes and is
+/// left out if it doesn't fit on its own.
+pub fn truncate_bytes(ca: &StringChunked, n: usize, ellipsis: Option<&str>) -> StringChunked {
+    let ellipsis = ellipsis.filter(|e| e.len() <= n).unwrap_or("");
+    let budget = n - ellipsis.len();
+    ca.apply_into_string_amortized(|s: &str, buf: &mut String| {
+        if s.len() <= n {
+            buf.push_str(s);
+        } else {
+            // A character is at most 4 bytes, so this looks at no more than 4 positions.
+            let end = (0..=budget).rev().find(|&i| s.is_char_boundary(i)).unwrap();
+            buf.push_str(&s[..end]);
+            buf.push_str(ellipsis);
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_truncate_bytes() {
+        // The globe takes 4 bytes, starting at byte 6.
+        let ca = StringChunked::new("a".into(), &[Some("hello 🌍"), Some("hi"), None]);
+
+        let out = truncate_bytes(&ca, 8, None);
+        assert_eq!(Vec::from(&out), &[Some("hello "), Some("hi"), None]);
+
+        let out = truncate_bytes(&ca, 8, Some("…"));
+        assert_eq!(Vec::from(&out), &[Some("hello…"), Some("hi"), None]);
+
+        let out = truncate_bytes(&ca, 2, Some("..."));
+        assert_eq!(Vec::from(&out), &[Some("he"), Some("hi"), None]);
+    }
+}
@@ -0,0 +1,26 @@
+use polars_core::prelude::arity::unary_elementwise_values;
+use polars_core::prelude::{StringChunked, UInt32Chunked};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Count the words of every string following the Unicode word boundaries of UAX #29.
+/// Whitespace and punctuation between words are not counted.
+pub fn word_count(ca: &StringChunked) -> UInt32Chunked {
+    unary_elementwise_values(ca, |s: &str| s.unicode_words().count() as u32)
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_word_count() {
+        let ca = StringChunked::new(
+            "a".into(),
+            &[Some("Hello, world! Ñoño"), Some(""), Some("  a  b  "), None],
+        );
+        let out = word_count(&ca);
+        assert_eq!(Vec::from(&out), &[Some(3), Some(0), Some(2), None]);
+    }
+}
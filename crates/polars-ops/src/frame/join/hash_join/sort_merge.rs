@@ -176,6 +176,76 @@ pub(super) fn par_sorted_merge_inner_no_nulls(
     }
 }
 
+/// The nulls of a sorted key are grouped at its start or end. Returns the offset and length of
+/// its non-null values.
+#[cfg(feature = "performant")]
+fn sorted_valid_range(s: &Series) -> (usize, usize) {
+    let null_count = s.null_count();
+    let valid_len = s.len() - null_count;
+    if null_count > 0 && s.get(0).is_ok_and(|av| av.is_null()) {
+        (null_count, valid_len)
+    } else {
+        (0, valid_len)
+    }
+}
+
+#[cfg(feature = "performant")]
+fn par_sorted_merge_inner(
+    s_left: &Series,
+    s_right: &Series,
+    nulls_equal: bool,
+) -> (Vec<IdxSize>, Vec<IdxSize>) {
+    let (left_offset, left_len) = sorted_valid_range(s_left);
+    let (right_offset, right_len) = sorted_valid_range(s_right);
+    let (mut left, mut right) = par_sorted_merge_inner_no_nulls(
+        &s_left.slice(left_offset as i64, left_len),
+        &s_right.slice(right_offset as i64, right_len),
+    );
+    RAYON.install(|| {
+        if left_offset > 0 {
+            left.par_iter_mut()
+                .for_each(|idx| *idx += left_offset as IdxSize);
+        }
+        if right_offset > 0 {
+            right
+                .par_iter_mut()
+                .for_each(|idx| *idx += right_offset as IdxSize);
+        }
+    });
+
+    if !nulls_equal || left_len == s_left.len() || right_len == s_right.len() {
+        return (left, right);
+    }
+
+    // Null keys match each other. Add them on the side where the left nulls are, so the left
+    // indices stay sorted.
+    let null_range = |offset: usize, valid_len: usize, len: usize| {
+        if offset > 0 {
+            0..offset
+        } else {
+            valid_len..len
+        }
+    };
+    let left_nulls = null_range(left_offset, left_len, s_left.len());
+    let right_nulls = null_range(right_offset, right_len, s_right.len());
+    let (mut null_left, mut null_right): (Vec<IdxSize>, Vec<IdxSize>) = left_nulls
+        .flat_map(|l| {
+            right_nulls
+                .clone()
+                .map(move |r| (l as IdxSize, r as IdxSize))
+        })
+        .unzip();
+    if left_offset > 0 {
+        null_left.append(&mut left);
+        null_right.append(&mut right);
+        (null_left, null_right)
+    } else {
+        left.append(&mut null_left);
+        right.append(&mut null_right);
+        (left, right)
+    }
+}
+
 pub(crate) fn to_left_join_ids(
     left_idx: Vec<IdxSize>,
     right_idx: Vec<NullableIdxSize>,
@@ -239,6 +309,12 @@ pub(crate) fn _sort_or_hash_inner(
             }
             Ok((par_sorted_merge_inner_no_nulls(s_left, s_right), true))
         },
+        (IsSorted::Ascending, IsSorted::Ascending, false) if is_numeric => {
+            if verbose {
+                eprintln!("inner join: keys are sorted: use sorted merge join");
+            }
+            Ok((par_sorted_merge_inner(s_left, s_right, nulls_equal), true))
+        },
         (IsSorted::Ascending, _, true)
             if is_numeric && size_factor_rhs < size_factor_acceptable =>
         {
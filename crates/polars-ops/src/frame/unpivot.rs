@@ -212,4 +212,21 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_unpivot_supertype() -> PolarsResult<()> {
+        let df = df!("id" => &["x", "y"],
+         "a" => &[1i32, 2],
+         "b" => &[0.5f64, 1.5]
+        )?;
+
+        let unpivoted = df.unpivot(Some(["a", "b"]), ["id"])?;
+        assert_eq!(unpivoted.column("value")?.dtype(), &DataType::Float64);
+        assert_eq!(
+            Vec::from(unpivoted.column("value")?.f64()?),
+            &[Some(1.0), Some(2.0), Some(0.5), Some(1.5)]
+        );
+
+        Ok(())
+    }
 }
@@ -74,6 +74,27 @@ pub fn clip(s: &Series, min: &Series, max: &Series) -> PolarsResult<Series> {
     })
 }
 
+/// Clamp every element to `[lower[i], upper[i]]`.
+///
+/// Unlike [`clip`], the bounds are not broadcast: both must have the same length as `s`.
+/// A null bound leaves that side of the element unbounded.
+pub fn clip_by(s: &Series, lower: &Series, upper: &Series) -> PolarsResult<Series> {
+    for (i, (name, length)) in [("lower", lower.len()), ("upper", upper.len())]
+        .into_iter()
+        .enumerate()
+    {
+        polars_ensure!(
+            length == s.len(),
+            length_mismatch = "clip_by",
+            length,
+            s.len(),
+            argument = name,
+            argument_idx = i + 1
+        );
+    }
+    clip(s, lower, upper)
+}
+
 /// Set values above the given maximum to the maximum value.
 pub fn clip_max(s: &Series, max: &Series) -> PolarsResult<Series> {
     polars_ensure!(
@@ -236,3 +257,21 @@ where
         }
     })
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_clip_by() -> PolarsResult<()> {
+        let s = Series::new("a".into(), &[-1i32, 6]);
+        let lower = Series::new("lower".into(), &[0i32, 5]);
+        let upper = Series::new("upper".into(), &[10i32, 8]);
+        let out = clip_by(&s, &lower, &upper)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(0), Some(6)]);
+
+        let short = Series::new("upper".into(), &[10i32]);
+        assert!(clip_by(&s, &lower, &short).is_err());
+        Ok(())
+    }
+}
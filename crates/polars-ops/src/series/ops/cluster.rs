@@ -0,0 +1,192 @@
+use arrow::array::{Array, FixedSizeListArray, PrimitiveArray};
+use arrow::datatypes::ArrowDataType;
+use polars_compute::distance::squared_euclidean_f32;
+use polars_core::POOL;
+use polars_core::prelude::*;
+use rand::prelude::*;
+use rayon::prelude::*;
+
+/// Index of the centroid closest to `point`.
+fn nearest(point: &[f32], centroids: &[f32], width: usize) -> u32 {
+    let mut best = (0, f32::INFINITY);
+    for (i, centroid) in centroids.chunks_exact(width).enumerate() {
+        let dist = squared_euclidean_f32(point, centroid);
+        if dist < best.1 {
+            best = (i, dist);
+        }
+    }
+    best.0 as u32
+}
+
+fn assign(points: &[f32], centroids: &[f32], width: usize) -> Vec<u32> {
+    POOL.install(|| {
+        points
+            .par_chunks_exact(width)
+            .map(|point| nearest(point, centroids, width))
+            .collect()
+    })
+}
+
+/// Pick the initial centroids with k-means++: every next centroid is drawn with a probability
+/// proportional to the squared distance of a point to its closest centroid so far.
+fn init_centroids(points: &[f32], width: usize, k: usize, rng: &mut SmallRng) -> Vec<f32> {
+    let n = points.len() / width;
+    let point = |i: usize| &points[i * width..(i + 1) * width];
+
+    let mut centroids = Vec::with_capacity(k * width);
+    centroids.extend_from_slice(point(rng.random_range(0..n)));
+    let mut dists = points
+        .chunks_exact(width)
+        .map(|p| squared_euclidean_f32(p, &centroids))
+        .collect::<Vec<_>>();
+
+    for _ in 1..k {
+        let total = dists.iter().map(|&d| d as f64).sum::<f64>();
+        let next = if total > 0.0 {
+            let mut target = rng.random::<f64>() * total;
+            dists
+                .iter()
+                .position(|&d| {
+                    target -= d as f64;
+                    target < 0.0
+                })
+                .unwrap_or(n - 1)
+        } else {
+            // Every point already coincides with a centroid.
+            rng.random_range(0..n)
+        };
+        let centroid = point(next);
+        for (d, p) in dists.iter_mut().zip(points.chunks_exact(width)) {
+            *d = d.min(squared_euclidean_f32(p, centroid));
+        }
+        centroids.extend_from_slice(centroid);
+    }
+    centroids
+}
+
+/// Cluster the rows of an `Array(Float32, n)` column into `k` clusters with Lloyd's algorithm.
+///
+/// The centroids are initialized with k-means++ from `seed` and refined for at most `max_iter`
+/// iterations, stopping early once no point changes cluster. Returns the cluster of every row
+/// as `UInt32`, null for null rows, and the `k` centroids as an `Array(Float32, n)` column.
+pub fn kmeans_cluster(
+    embeddings: &Series,
+    k: usize,
+    max_iter: usize,
+    seed: u64,
+) -> PolarsResult<(Series, Series)> {
+    let width = match embeddings.dtype() {
+        DataType::Array(inner, width) if **inner == DataType::Float32 && *width > 0 => *width,
+        dt => polars_bail!(
+            InvalidOperation: "kmeans_cluster expects an Array(Float32, n) column with n > 0, got {}", dt
+        ),
+    };
+    let ca = embeddings.array()?.rechunk();
+    let arr = ca.downcast_as_array();
+    let values = arr
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f32>>()
+        .unwrap();
+
+    let mut points = Vec::with_capacity(values.len());
+    for i in (0..arr.len()).filter(|&i| arr.is_valid(i)) {
+        let range = i * width..(i + 1) * width;
+        polars_ensure!(
+            range.clone().all(|j| values.is_valid(j)),
+            ComputeError: "kmeans_cluster does not support null values within an embedding"
+        );
+        points.extend_from_slice(&values.values()[range]);
+    }
+    let n = points.len() / width;
+    polars_ensure!(
+        k > 0 && k <= n,
+        ComputeError: "kmeans_cluster needs 0 < k <= {} (the number of non-null rows), got k = {}", n, k
+    );
+
+    let mut rng = SmallRng::seed_from_u64(seed);
+    let mut centroids = init_centroids(&points, width, k, &mut rng);
+    let mut labels = assign(&points, &centroids, width);
+    for _ in 0..max_iter {
+        let mut sums = vec![0.0f64; k * width];
+        let mut counts = vec![0usize; k];
+        for (point, &label) in points.chunks_exact(width).zip(&labels) {
+            let label = label as usize;
+            counts[label] += 1;
+            for (s, &v) in sums[label * width..(label + 1) * width]
+                .iter_mut()
+                .zip(point)
+            {
+                *s += v as f64;
+            }
+        }
+        // Empty clusters keep their previous centroid.
+        for (c, &count) in counts.iter().enumerate().filter(|(_, count)| **count > 0) {
+            let range = c * width..(c + 1) * width;
+            for (centroid, &s) in centroids[range.clone()].iter_mut().zip(&sums[range]) {
+                *centroid = (s / count as f64) as f32;
+            }
+        }
+
+        let new_labels = assign(&points, &centroids, width);
+        let converged = new_labels == labels;
+        labels = new_labels;
+        if converged {
+            break;
+        }
+    }
+
+    let name = embeddings.name().clone();
+    let labels = if arr.null_count() == 0 {
+        UInt32Chunked::from_vec(name.clone(), labels)
+    } else {
+        let mut labels = labels.into_iter();
+        let iter = (0..arr.len()).map(|i| arr.is_valid(i).then(|| labels.next().unwrap()));
+        UInt32Chunked::from_iter_options(name.clone(), iter)
+    };
+    let centroids = FixedSizeListArray::new(
+        FixedSizeListArray::default_datatype(ArrowDataType::Float32, width),
+        k,
+        PrimitiveArray::from_vec(centroids).boxed(),
+        None,
+    );
+    let centroids = ArrayChunked::with_chunk(name, centroids);
+    Ok((labels.into_series(), centroids.into_series()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_kmeans_two_blobs() -> PolarsResult<()> {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let centers = [[-5.0f32, -5.0, 0.0], [5.0, 5.0, 1.0]];
+        let truth = (0..200).map(|i| i % 2).collect::<Vec<usize>>();
+        let rows = truth
+            .iter()
+            .map(|&c| {
+                let row = centers[c].map(|x| x + rng.random_range(-1.0..1.0));
+                Series::new("".into(), row)
+            })
+            .collect::<Vec<_>>();
+        let embeddings = Series::new("emb".into(), rows)
+            .cast(&DataType::Array(Box::new(DataType::Float32), 3))?;
+
+        let (labels, centroids) = kmeans_cluster(&embeddings, 2, 100, 42)?;
+        assert_eq!(centroids.len(), 2);
+        let labels = labels.u32()?;
+        // Cluster ids are arbitrary, all points of a blob must share the same id.
+        let first = labels.get(0).unwrap();
+        for (label, &c) in labels.into_no_null_iter().zip(&truth) {
+            assert_eq!(label == first, c == 0);
+        }
+
+        let centroids = centroids.array()?;
+        let centroid = centroids.get_as_series(first as usize).unwrap();
+        assert!((centroid.f32()?.get(0).unwrap() + 5.0).abs() < 0.5);
+
+        assert!(kmeans_cluster(&embeddings, 0, 10, 0).is_err());
+        Ok(())
+    }
+}
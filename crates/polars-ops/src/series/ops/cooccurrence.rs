@@ -0,0 +1,95 @@
+use polars_core::prelude::*;
+
+/// Cross-tabulate two string or categorical series into a contingency table.
+///
+/// Returns a [`DataFrame`] with a row for every distinct value of `a` and a column for every
+/// distinct value of `b`, both in order of first appearance, holding how often each pair
+/// occurs. The first column holds the values of `a` and is named after it. Pairs with a null
+/// are not counted. If `normalize` is set, the counts are divided by the number of counted
+/// pairs, giving `Float64` relative frequencies.
+pub fn count_matrix(a: &Series, b: &Series, normalize: bool) -> PolarsResult<DataFrame> {
+    polars_ensure!(
+        a.len() == b.len(),
+        length_mismatch = "count_matrix",
+        a.len(),
+        b.len()
+    );
+    for s in [a, b] {
+        let dtype = s.dtype();
+        polars_ensure!(
+            dtype.is_string() || dtype.is_categorical() || dtype.is_enum(),
+            InvalidOperation: "count_matrix expects string or categorical series, got {}", dtype
+        );
+    }
+    let a_str = a.cast(&DataType::String)?;
+    let b_str = b.cast(&DataType::String)?;
+
+    let mut rows = PlIndexMap::<&str, usize>::default();
+    let mut cols = PlIndexMap::<&str, usize>::default();
+    let mut pairs = Vec::with_capacity(a.len());
+    for (x, y) in a_str.str()?.iter().zip(b_str.str()?.iter()) {
+        let (Some(x), Some(y)) = (x, y) else {
+            continue;
+        };
+        let n_rows = rows.len();
+        let n_cols = cols.len();
+        pairs.push((
+            *rows.entry(x).or_insert(n_rows),
+            *cols.entry(y).or_insert(n_cols),
+        ));
+    }
+
+    let mut counts = vec![vec![0 as IdxSize; rows.len()]; cols.len()];
+    for &(i, j) in &pairs {
+        counts[j][i] += 1;
+    }
+
+    let mut columns = Vec::with_capacity(cols.len() + 1);
+    columns.push(Column::new(
+        a.name().clone(),
+        rows.keys().copied().collect::<Vec<_>>(),
+    ));
+    for (&name, counts) in cols.keys().zip(counts) {
+        let column = if normalize {
+            let total = pairs.len() as f64;
+            let freqs = counts.iter().map(|&c| c as f64 / total).collect::<Vec<_>>();
+            Column::new(name.into(), freqs)
+        } else {
+            Column::new(name.into(), counts)
+        };
+        columns.push(column);
+    }
+    DataFrame::new(rows.len(), columns)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_count_matrix() -> PolarsResult<()> {
+        let categorical = DataType::from_categories(Categories::global());
+        let a = Series::new("a".into(), ["x", "y", "x", "z", "x", "y"]).cast(&categorical)?;
+        let b = Series::new("b".into(), ["p", "q", "p", "r", "q", "q"]).cast(&categorical)?;
+
+        let out = count_matrix(&a, &b, false)?;
+        let expected = df![
+            "a" => ["x", "y", "z"],
+            "p" => [2 as IdxSize, 0, 0],
+            "q" => [1 as IdxSize, 2, 0],
+            "r" => [0 as IdxSize, 0, 1]
+        ]?;
+        assert!(out.equals(&expected));
+
+        let out = count_matrix(&a, &b, true)?;
+        assert_eq!(
+            Vec::from(out.column("q")?.f64()?),
+            &[Some(1.0 / 6.0), Some(2.0 / 6.0), Some(0.0)]
+        );
+
+        let ints = Series::new("i".into(), [1, 2, 3, 4, 5, 6]);
+        assert!(count_matrix(&a, &ints, false).is_err());
+        Ok(())
+    }
+}
@@ -3,13 +3,32 @@ use std::ops::{AddAssign, Mul};
 use arity::unary_elementwise_values;
 use arrow::array::{Array, BooleanArray};
 use arrow::bitmap::{Bitmap, BitmapBuilder};
-use num_traits::{AsPrimitive, Bounded, One, Zero};
+use num_traits::{AsPrimitive, Bounded, CheckedMul, One, SaturatingMul, Zero};
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 use polars_core::utils::{CustomIterTools, NoNull};
-use polars_core::with_match_physical_numeric_polars_type;
+use polars_core::{
+    with_match_physical_integer_polars_type, with_match_physical_numeric_polars_type,
+};
 use polars_utils::float::IsFloat;
 use polars_utils::min_max::MinMax;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How [`cum_prod_with_overflow`] handles integer overflow.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum OverflowPolicy {
+    /// Integers narrower than 64 bits are cast to `Int64` first, the product wraps around on
+    /// overflow. This is the behavior of [`cum_prod`].
+    #[default]
+    Wrapping,
+    /// Keep the integer type and raise an error on overflow.
+    Checked,
+    /// Keep the integer type and clamp the product to the bounds of that type.
+    Saturating,
+}
 
 fn det_max<T>(state: &mut T, v: Option<T>) -> Option<Option<T>>
 where
@@ -77,6 +96,19 @@ where
     }
 }
 
+fn det_prod_saturating<T>(state: &mut T, v: Option<T>) -> Option<Option<T>>
+where
+    T: Copy + SaturatingMul,
+{
+    match v {
+        Some(v) => {
+            *state = state.saturating_mul(&v);
+            Some(Some(*state))
+        },
+        None => Some(None),
+    }
+}
+
 fn cum_scan_numeric<T, S, F>(
     ca: &ChunkedArray<T>,
     reverse: bool,
@@ -281,6 +313,38 @@ where
     cum_scan_numeric(ca, reverse, init, det_prod)
 }
 
+fn cum_prod_checked_numeric<T>(ca: &ChunkedArray<T>, reverse: bool) -> PolarsResult<ChunkedArray<T>>
+where
+    T: PolarsIntegerType,
+    T::Native: CheckedMul,
+{
+    let mut state = T::Native::one();
+    let update = |opt_v: Option<T::Native>| {
+        let Some(v) = opt_v else {
+            return Ok(None);
+        };
+        state = state.checked_mul(&v).ok_or_else(
+            || polars_err!(ComputeError: "overflow in cum_prod of dtype {}", ca.dtype()),
+        )?;
+        Ok(Some(state))
+    };
+    let out = if reverse {
+        let mut values = ca
+            .iter()
+            .rev()
+            .map(update)
+            .collect::<PolarsResult<Vec<_>>>()?;
+        values.reverse();
+        values
+    } else {
+        ca.iter().map(update).collect::<PolarsResult<Vec<_>>>()?
+    };
+    Ok(ChunkedArray::from_iter_options(
+        ca.name().clone(),
+        out.into_iter(),
+    ))
+}
+
 pub fn cum_prod_with_init(
     s: &Series,
     reverse: bool,
@@ -315,6 +379,29 @@ pub fn cum_prod(s: &Series, reverse: bool) -> PolarsResult<Series> {
     cum_prod_with_init(s, reverse, &AnyValue::Null)
 }
 
+/// Get an array with the cumulative product computed at every element, handling integer
+/// overflow according to `overflow`.
+///
+/// With [`OverflowPolicy::Checked`] and [`OverflowPolicy::Saturating`] integer columns keep
+/// their type. Other types are not affected by the policy.
+pub fn cum_prod_with_overflow(
+    s: &Series,
+    reverse: bool,
+    overflow: OverflowPolicy,
+) -> PolarsResult<Series> {
+    if overflow == OverflowPolicy::Wrapping || !s.dtype().is_integer() {
+        return cum_prod(s, reverse);
+    }
+    with_match_physical_integer_polars_type!(s.dtype(), |$T| {
+        let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+        let out = match overflow {
+            OverflowPolicy::Checked => cum_prod_checked_numeric(ca, reverse)?,
+            _ => cum_scan_numeric(ca, reverse, One::one(), det_prod_saturating),
+        };
+        Ok(out.into_series())
+    })
+}
+
 pub fn cum_sum_with_init(
     s: &Series,
     reverse: bool,
@@ -402,9 +489,37 @@ pub fn cum_min_with_init(
     }
 }
 
+/// Set all values from the first null in scan order onwards to null.
+fn propagate_nulls(s: Series, reverse: bool) -> PolarsResult<Series> {
+    let is_null = s.is_null();
+    let is_null = if reverse { is_null.reverse() } else { is_null };
+    let Some(n_valid) = is_null.first_true_idx() else {
+        return Ok(s);
+    };
+
+    let nulls = Series::full_null(s.name().clone(), s.len() - n_valid, s.dtype());
+    let out = if reverse {
+        let mut out = nulls;
+        out.append_owned(s.slice(-(n_valid as i64), n_valid))?;
+        out
+    } else {
+        let mut out = s.slice(0, n_valid);
+        out.append_owned(nulls)?;
+        out
+    };
+    Ok(out)
+}
+
 /// Get an array with the cumulative min computed at every element.
-pub fn cum_min(s: &Series, reverse: bool) -> PolarsResult<Series> {
-    cum_min_with_init(s, reverse, &AnyValue::Null)
+///
+/// If `skip_nulls` is `false`, a null makes all values after it in scan order null as well.
+pub fn cum_min(s: &Series, reverse: bool, skip_nulls: bool) -> PolarsResult<Series> {
+    let out = cum_min_with_init(s, reverse, &AnyValue::Null)?;
+    if skip_nulls {
+        Ok(out)
+    } else {
+        propagate_nulls(out, reverse)
+    }
 }
 
 pub fn cum_max_with_init(
@@ -441,8 +556,15 @@ pub fn cum_max_with_init(
 }
 
 /// Get an array with the cumulative max computed at every element.
-pub fn cum_max(s: &Series, reverse: bool) -> PolarsResult<Series> {
-    cum_max_with_init(s, reverse, &AnyValue::Null)
+///
+/// If `skip_nulls` is `false`, a null makes all values after it in scan order null as well.
+pub fn cum_max(s: &Series, reverse: bool, skip_nulls: bool) -> PolarsResult<Series> {
+    let out = cum_max_with_init(s, reverse, &AnyValue::Null)?;
+    if skip_nulls {
+        Ok(out)
+    } else {
+        propagate_nulls(out, reverse)
+    }
 }
 
 pub fn cum_count(s: &Series, reverse: bool) -> PolarsResult<Series> {
@@ -495,3 +617,26 @@ fn cum_count_no_nulls(name: PlSmallStr, len: usize, reverse: bool, init: IdxSize
     ca.rename(name);
     ca.into_series()
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cum_prod_with_overflow() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [Some(-2i32), None, Some(3), Some(1 << 30)]);
+        let out = cum_prod_with_overflow(&s, true, OverflowPolicy::Saturating)?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(i32::MIN), None, Some(i32::MAX), Some(1 << 30)]
+        );
+        assert!(cum_prod_with_overflow(&s, true, OverflowPolicy::Checked).is_err());
+
+        let out = cum_prod_with_overflow(&s.slice(0, 3), true, OverflowPolicy::Checked)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(-6), None, Some(3)]);
+
+        let out = cum_prod_with_overflow(&s, false, OverflowPolicy::Wrapping)?;
+        assert_eq!(out.dtype(), &DataType::Int64);
+        Ok(())
+    }
+}
@@ -0,0 +1,207 @@
+use std::cmp::Ordering;
+
+use arrow::array::{Array, PrimitiveArray};
+use polars_compute::distance::{dot_f32, squared_euclidean_f32};
+use polars_core::POOL;
+use polars_core::prelude::*;
+use rayon::prelude::*;
+
+/// Distance used by [`knn_indices`] to rank the reference rows.
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Debug)]
+pub enum KnnMetric {
+    /// Euclidean distance.
+    L2,
+    /// One minus the cosine similarity. Zero vectors are orthogonal to everything.
+    Cosine,
+    /// Largest dot product first.
+    DotProduct,
+}
+
+fn embedding_width(s: &Series) -> PolarsResult<usize> {
+    match s.dtype() {
+        DataType::Array(inner, width) if **inner == DataType::Float32 => Ok(*width),
+        dt => polars_bail!(
+            InvalidOperation: "knn_indices expects an Array(Float32, n) column, got {}", dt
+        ),
+    }
+}
+
+/// Embedding of every row, `None` for null rows.
+fn embedding_rows(ca: &ArrayChunked, width: usize) -> PolarsResult<Vec<Option<&[f32]>>> {
+    let arr = ca.downcast_as_array();
+    let values = arr
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f32>>()
+        .unwrap();
+    (0..arr.len())
+        .map(|i| {
+            if !arr.is_valid(i) {
+                return Ok(None);
+            }
+            let range = i * width..(i + 1) * width;
+            polars_ensure!(
+                values.null_count() == 0 || range.clone().all(|j| values.is_valid(j)),
+                ComputeError: "knn_indices does not support null values within an embedding"
+            );
+            Ok(Some(&values.values()[range]))
+        })
+        .collect()
+}
+
+fn nearest_k(
+    query: &[f32],
+    reference: &[(IdxSize, &[f32])],
+    reference_norms: &[f32],
+    k: usize,
+    metric: KnnMetric,
+) -> Vec<IdxSize> {
+    let query_norm = match metric {
+        KnnMetric::Cosine => dot_f32(query, query).sqrt(),
+        _ => 0.0,
+    };
+    let mut dists = reference
+        .iter()
+        .enumerate()
+        .map(|(j, &(idx, row))| {
+            let dist = match metric {
+                KnnMetric::L2 => squared_euclidean_f32(query, row),
+                KnnMetric::DotProduct => -dot_f32(query, row),
+                KnnMetric::Cosine => {
+                    let norm = query_norm * reference_norms[j];
+                    if norm > 0.0 {
+                        1.0 - dot_f32(query, row) / norm
+                    } else {
+                        1.0
+                    }
+                },
+            };
+            // Rank NaN distances last.
+            (if dist.is_nan() { f32::INFINITY } else { dist }, idx)
+        })
+        .collect::<Vec<_>>();
+
+    // Ties are broken by the index in the reference.
+    let cmp = |a: &(f32, IdxSize), b: &(f32, IdxSize)| -> Ordering {
+        a.0.total_cmp(&b.0).then(a.1.cmp(&b.1))
+    };
+    if k == 0 {
+        return vec![];
+    }
+    if k < dists.len() {
+        dists.select_nth_unstable_by(k - 1, cmp);
+        dists.truncate(k);
+    }
+    dists.sort_unstable_by(cmp);
+    dists.into_iter().map(|(_, idx)| idx).collect()
+}
+
+/// Find the `k` nearest rows of `reference` for every row of `queries`.
+///
+/// Both columns must be `Array(Float32, n)` of the same width. This is an exact brute-force
+/// search, returning for every query a list with the indices of its nearest reference rows
+/// ordered from nearest to farthest, or null for null queries. Null reference rows are never
+/// returned, so a list holds fewer than `k` indices if there are fewer valid reference rows.
+pub fn knn_indices(
+    queries: &Series,
+    reference: &Series,
+    k: usize,
+    metric: KnnMetric,
+) -> PolarsResult<Series> {
+    let width = embedding_width(queries)?;
+    let reference_width = embedding_width(reference)?;
+    polars_ensure!(
+        width == reference_width,
+        ShapeMismatch: "knn_indices expects embeddings of the same width, got {} for the queries and {} for the reference",
+        width, reference_width
+    );
+
+    let queries_ca = queries.array()?.rechunk();
+    let reference_ca = reference.array()?.rechunk();
+    let queries_rows = embedding_rows(&queries_ca, width)?;
+    let reference_rows = embedding_rows(&reference_ca, width)?
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, row)| row.map(|row| (i as IdxSize, row)))
+        .collect::<Vec<_>>();
+    let reference_norms = match metric {
+        KnnMetric::Cosine => reference_rows
+            .iter()
+            .map(|(_, row)| dot_f32(row, row).sqrt())
+            .collect(),
+        _ => vec![],
+    };
+
+    let k = k.min(reference_rows.len());
+    let neighbors = POOL.install(|| {
+        queries_rows
+            .par_iter()
+            .map(|query| {
+                query.map(|query| nearest_k(query, &reference_rows, &reference_norms, k, metric))
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let mut builder = ListPrimitiveChunkedBuilder::<IdxType>::new(
+        queries.name().clone(),
+        neighbors.len(),
+        neighbors.len() * k,
+        IDX_DTYPE,
+    );
+    for indices in &neighbors {
+        builder.append_opt_slice(indices.as_deref());
+    }
+    Ok(builder.finish().into_series())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn embeddings(rows: &[Option<[f32; 2]>]) -> PolarsResult<Series> {
+        let rows = rows
+            .iter()
+            .map(|row| row.map(|row| Series::new("".into(), row)))
+            .collect::<Vec<_>>();
+        Series::new("emb".into(), rows).cast(&DataType::Array(Box::new(DataType::Float32), 2))
+    }
+
+    fn neighbors(out: &Series) -> PolarsResult<Vec<Option<Vec<IdxSize>>>> {
+        out.list()?
+            .into_iter()
+            .map(|opt_s| {
+                opt_s
+                    .map(|s| Ok(s.idx()?.into_no_null_iter().collect()))
+                    .transpose()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_knn_indices() -> PolarsResult<()> {
+        let reference = embeddings(&[
+            Some([0.0, 0.0]),
+            Some([10.0, 0.0]),
+            None,
+            Some([0.0, 3.0]),
+            Some([1.0, 1.0]),
+        ])?;
+        let queries = embeddings(&[Some([0.5, 0.0]), None, Some([9.0, 1.0])])?;
+
+        let out = knn_indices(&queries, &reference, 2, KnnMetric::L2)?;
+        assert_eq!(neighbors(&out)?, [Some(vec![0, 4]), None, Some(vec![1, 4])]);
+
+        // The direction of [0.5, 0.0] is closest to [10, 0], then [1, 1]. [0, 0] has no
+        // direction and ties with the orthogonal [0, 3].
+        let out = knn_indices(&queries, &reference, 4, KnnMetric::Cosine)?;
+        assert_eq!(neighbors(&out)?[0], Some(vec![1, 4, 0, 3]));
+
+        let out = knn_indices(&queries, &reference, 1, KnnMetric::DotProduct)?;
+        assert_eq!(neighbors(&out)?[2], Some(vec![1]));
+
+        // Only four reference rows are valid.
+        let out = knn_indices(&queries, &reference, 10, KnnMetric::L2)?;
+        assert_eq!(neighbors(&out)?[0].as_ref().unwrap().len(), 4);
+        Ok(())
+    }
+}
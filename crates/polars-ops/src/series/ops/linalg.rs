@@ -0,0 +1,150 @@
+use arrow::array::{Array, FixedSizeListArray, PrimitiveArray};
+use arrow::datatypes::ArrowDataType;
+use polars_core::prelude::*;
+
+/// `out = a * b` for row-major `d x d` matrices.
+fn matmul(a: &[f64], b: &[f64], out: &mut [f64], d: usize) {
+    out.fill(0.0);
+    // i-k-j order, so the innermost loop runs over contiguous rows of `b` and `out`.
+    for (a_row, out_row) in a.chunks_exact(d).zip(out.chunks_exact_mut(d)) {
+        for (&a_ik, b_row) in a_row.iter().zip(b.chunks_exact(d)) {
+            for (o, &b_kj) in out_row.iter_mut().zip(b_row) {
+                *o += a_ik * b_kj;
+            }
+        }
+    }
+}
+
+/// `m^n` for a row-major `d x d` matrix, by repeated squaring.
+fn pow(m: &[f64], mut n: u32, d: usize) -> Vec<f64> {
+    let mut result = vec![0.0; d * d];
+    for i in 0..d {
+        result[i * d + i] = 1.0;
+    }
+    let mut base = m.to_vec();
+    let mut scratch = vec![0.0; d * d];
+    while n > 0 {
+        if n & 1 == 1 {
+            matmul(&result, &base, &mut scratch, d);
+            std::mem::swap(&mut result, &mut scratch);
+        }
+        n >>= 1;
+        if n > 0 {
+            matmul(&base, &base, &mut scratch, d);
+            std::mem::swap(&mut base, &mut scratch);
+        }
+    }
+    result
+}
+
+/// Raise every row of an `Array(_, d * d)` column, read as a row-major `d x d` matrix, to the
+/// `n`-th power.
+///
+/// Returns an `Array(Float64, d * d)` column, null for null rows. `n = 0` gives the identity.
+pub fn matrix_pow(s: &Series, n: u32) -> PolarsResult<Series> {
+    let width = match s.dtype() {
+        DataType::Array(inner, width) if inner.is_primitive_numeric() => *width,
+        dt => polars_bail!(
+            InvalidOperation: "matrix_pow expects a numeric Array column, got {}", dt
+        ),
+    };
+    let d = (width as f64).sqrt().round() as usize;
+    polars_ensure!(
+        d > 0 && d * d == width,
+        InvalidOperation: "matrix_pow expects non-empty square matrices, got an Array of width {}", width
+    );
+
+    let ca = s
+        .cast(&DataType::Array(Box::new(DataType::Float64), width))?
+        .array()?
+        .rechunk()
+        .into_owned();
+    let arr = ca.downcast_as_array();
+    let values = arr
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f64>>()
+        .unwrap();
+
+    let mut out = Vec::with_capacity(arr.len() * width);
+    for i in 0..arr.len() {
+        if !arr.is_valid(i) {
+            out.extend(std::iter::repeat_n(0.0, width));
+            continue;
+        }
+        let range = i * width..(i + 1) * width;
+        polars_ensure!(
+            values.null_count() == 0 || range.clone().all(|j| values.is_valid(j)),
+            ComputeError: "matrix_pow does not support null values within a matrix"
+        );
+        out.extend(pow(&values.values()[range], n, d));
+    }
+
+    let out = FixedSizeListArray::new(
+        FixedSizeListArray::default_datatype(ArrowDataType::Float64, width),
+        arr.len(),
+        PrimitiveArray::from_vec(out).boxed(),
+        arr.validity().cloned(),
+    );
+    Ok(ArrayChunked::with_chunk(s.name().clone(), out).into_series())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn matrices(rows: &[Option<[f64; 4]>]) -> PolarsResult<Series> {
+        let rows = rows
+            .iter()
+            .map(|row| row.map(|row| Series::new("".into(), row)))
+            .collect::<Vec<_>>();
+        Series::new("m".into(), rows).cast(&DataType::Array(Box::new(DataType::Float64), 4))
+    }
+
+    fn rotation(theta: f64) -> [f64; 4] {
+        let (sin, cos) = theta.sin_cos();
+        [cos, -sin, sin, cos]
+    }
+
+    #[test]
+    fn test_matrix_pow() -> PolarsResult<()> {
+        let theta = 0.3;
+        let s = matrices(&[Some(rotation(theta)), None, Some([1.0, 1.0, 0.0, 1.0])])?;
+
+        let squared = matrix_pow(&s, 2)?;
+        let squared = squared.array()?;
+        let row = |i: usize| -> Option<Vec<f64>> {
+            let s = squared.get_as_series(i)?;
+            Some(s.f64().unwrap().into_no_null_iter().collect())
+        };
+        for (x, expected) in row(0).unwrap().iter().zip(rotation(2.0 * theta)) {
+            assert!((x - expected).abs() < 1e-12);
+        }
+        assert_eq!(row(1), None);
+        assert_eq!(row(2), Some(vec![1.0, 2.0, 0.0, 1.0]));
+
+        let out = matrix_pow(&s, 5)?;
+        let shear = out.array()?.get_as_series(2).unwrap();
+        assert_eq!(
+            Vec::from(shear.f64()?),
+            &[Some(1.0), Some(5.0), Some(0.0), Some(1.0)]
+        );
+        let out = matrix_pow(&s, 0)?;
+        let identity = out.array()?.get_as_series(0).unwrap();
+        assert_eq!(
+            Vec::from(identity.f64()?),
+            &[Some(1.0), Some(0.0), Some(0.0), Some(1.0)]
+        );
+
+        let not_square = Series::new("".into(), [Series::new("".into(), [1.0, 2.0, 3.0])])
+            .cast(&DataType::Array(Box::new(DataType::Float64), 3))?;
+        assert!(matrix_pow(&not_square, 2).is_err());
+        let empty = Series::new(
+            "".into(),
+            [Series::new_empty("".into(), &DataType::Float64)],
+        )
+        .cast(&DataType::Array(Box::new(DataType::Float64), 0))?;
+        assert!(matrix_pow(&empty, 2).is_err());
+        Ok(())
+    }
+}
@@ -5,6 +5,10 @@ mod bitwise;
 #[cfg(feature = "business")]
 mod business;
 mod clip;
+#[cfg(feature = "kmeans")]
+mod cluster;
+#[cfg(feature = "count_matrix")]
+mod cooccurrence;
 #[cfg(feature = "cum_agg")]
 mod cum_agg;
 #[cfg(feature = "cutqcut")]
@@ -39,6 +43,10 @@ mod is_in;
 mod is_last_distinct;
 #[cfg(feature = "is_unique")]
 mod is_unique;
+#[cfg(feature = "knn")]
+mod knn;
+#[cfg(feature = "linalg")]
+mod linalg;
 mod linear_space;
 #[cfg(feature = "log")]
 mod log;
@@ -60,6 +68,8 @@ pub mod round;
 #[cfg(feature = "search_sorted")]
 mod search_sorted;
 mod strings;
+#[cfg(feature = "tdigest")]
+mod tdigest;
 #[cfg(feature = "to_dummies")]
 mod to_dummies;
 #[cfg(feature = "unique_counts")]
@@ -73,6 +83,10 @@ pub use bitwise::*;
 #[cfg(feature = "business")]
 pub use business::*;
 pub use clip::*;
+#[cfg(feature = "kmeans")]
+pub use cluster::*;
+#[cfg(feature = "count_matrix")]
+pub use cooccurrence::*;
 #[cfg(feature = "cum_agg")]
 pub use cum_agg::*;
 #[cfg(feature = "cutqcut")]
@@ -111,6 +125,10 @@ pub use is_in::*;
 pub use is_last_distinct::*;
 #[cfg(feature = "is_unique")]
 pub use is_unique::*;
+#[cfg(feature = "knn")]
+pub use knn::*;
+#[cfg(feature = "linalg")]
+pub use linalg::*;
 pub use linear_space::*;
 #[cfg(feature = "log")]
 pub use log::*;
@@ -134,6 +152,8 @@ pub use round::*;
 #[cfg(feature = "search_sorted")]
 pub use search_sorted::*;
 pub use strings::*;
+#[cfg(feature = "tdigest")]
+pub use tdigest::*;
 #[cfg(feature = "to_dummies")]
 pub use to_dummies::*;
 #[cfg(feature = "unique_counts")]
@@ -65,6 +65,27 @@ unsafe fn rank_impl<F: FnMut(&mut [IdxSize])>(idxs: &IdxCa, neq: &BooleanArray,
     flush_ties(&mut ties_indices);
 }
 
+/// Indices of the non-null values in sorted order.
+fn sort_idx(s: &Series, descending: bool) -> IdxCa {
+    s.arg_sort(SortOptions {
+        descending,
+        nulls_last: true,
+        ..Default::default()
+    })
+    .slice(0, s.len() - s.null_count())
+}
+
+/// Whether every sorted value differs from the one before it.
+fn tie_breaks(s: &Series, sort_idx_ca: &IdxCa) -> BooleanChunked {
+    let sorted_values = unsafe { s.take_unchecked(sort_idx_ca) };
+    sorted_values
+        .slice(1, sorted_values.len() - 1)
+        .not_equal(&sorted_values.slice(0, sorted_values.len() - 1))
+        .unwrap()
+        .rechunk()
+        .into_owned()
+}
+
 fn rank(s: &Series, method: RankMethod, descending: bool, seed: Option<u64>) -> Series {
     let len = s.len();
     let null_count = s.null_count();
@@ -100,14 +121,7 @@ fn rank(s: &Series, method: RankMethod, descending: bool, seed: Option<u64>) ->
         };
     }
 
-    let sort_idx_ca = s
-        .arg_sort(SortOptions {
-            descending,
-            nulls_last: true,
-            ..Default::default()
-        })
-        .slice(0, len - null_count);
-
+    let sort_idx_ca = sort_idx(s, descending);
     let validity = concatenate_validities(s.chunks());
 
     use RankMethod::*;
@@ -122,12 +136,7 @@ fn rank(s: &Series, method: RankMethod, descending: bool, seed: Option<u64>) ->
         }
         IdxCa::from_vec_validity(s.name().clone(), out, validity).into_series()
     } else {
-        let sorted_values = unsafe { s.take_unchecked(&sort_idx_ca) };
-        let not_consecutive_same = sorted_values
-            .slice(1, sorted_values.len() - 1)
-            .not_equal(&sorted_values.slice(0, sorted_values.len() - 1))
-            .unwrap();
-        let neq = not_consecutive_same.rechunk();
+        let neq = tie_breaks(s, &sort_idx_ca);
         let neq = neq.downcast_as_array();
 
         let mut rank = 1;
@@ -193,10 +202,85 @@ fn rank(s: &Series, method: RankMethod, descending: bool, seed: Option<u64>) ->
     }
 }
 
+const RANK_ALL_METHODS: [(&str, RankMethod); 5] = [
+    ("average", RankMethod::Average),
+    ("min", RankMethod::Min),
+    ("max", RankMethod::Max),
+    ("dense", RankMethod::Dense),
+    ("ordinal", RankMethod::Ordinal),
+];
+
+fn rank_all(s: &Series, descending: bool) -> PolarsResult<DataFrame> {
+    let len = s.len();
+    if len <= 1 || s.null_count() == len {
+        let columns = RANK_ALL_METHODS
+            .iter()
+            .map(|(name, method)| {
+                rank(s, *method, descending, None)
+                    .with_name((*name).into())
+                    .into_column()
+            })
+            .collect();
+        return DataFrame::new(len, columns);
+    }
+
+    let sort_idx_ca = sort_idx(s, descending);
+    let neq = tie_breaks(s, &sort_idx_ca);
+    let validity = concatenate_validities(s.chunks());
+
+    let mut average = vec![0.0; len];
+    let mut min = vec![0 as IdxSize; len];
+    let mut max = vec![0 as IdxSize; len];
+    let mut dense = vec![0 as IdxSize; len];
+    let mut ordinal = vec![0 as IdxSize; len];
+    let mut rank = 1;
+    let mut dense_rank = 1;
+    unsafe {
+        rank_impl(&sort_idx_ca, neq.downcast_as_array(), |ties| {
+            let first = rank;
+            let last = rank + ties.len() as IdxSize - 1;
+            let avg = 0.5 * (first as f64 + last as f64);
+            for (offset, i) in ties.iter().enumerate() {
+                let i = *i as usize;
+                *average.get_unchecked_mut(i) = avg;
+                *min.get_unchecked_mut(i) = first;
+                *max.get_unchecked_mut(i) = last;
+                *dense.get_unchecked_mut(i) = dense_rank;
+                *ordinal.get_unchecked_mut(i) = first + offset as IdxSize;
+            }
+            rank = last + 1;
+            dense_rank += 1;
+        });
+    }
+
+    let mut columns = vec![
+        Float64Chunked::from_vec_validity("average".into(), average, validity.clone())
+            .into_column(),
+    ];
+    for (name, out) in [
+        ("min", min),
+        ("max", max),
+        ("dense", dense),
+        ("ordinal", ordinal),
+    ] {
+        columns.push(IdxCa::from_vec_validity(name.into(), out, validity.clone()).into_column());
+    }
+    DataFrame::new(len, columns)
+}
+
 pub trait SeriesRank: SeriesSealed {
     fn rank(&self, options: RankOptions, seed: Option<u64>) -> Series {
         rank(self.as_series(), options.method, options.descending, seed)
     }
+
+    /// Rank with every deterministic [`RankMethod`] at once.
+    ///
+    /// Returns a [`DataFrame`] with the columns `average`, `min`, `max`, `dense` and
+    /// `ordinal`, equal to calling [`SeriesRank::rank`] with each method, but sorting the
+    /// values only once.
+    fn rank_all(&self, descending: bool) -> PolarsResult<DataFrame> {
+        rank_all(self.as_series(), descending)
+    }
 }
 
 impl SeriesRank for Series {}
@@ -310,6 +394,34 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_rank_all() -> PolarsResult<()> {
+        let inputs = [
+            Series::new(
+                "a".into(),
+                &[Some(1), Some(2), None, Some(3), Some(2), Some(2), Some(0)],
+            ),
+            Series::new("a".into(), &[Some(5)]),
+            Series::new("a".into(), &[None::<i32>, None]),
+            Series::new("a".into(), &[] as &[i32]),
+        ];
+        for s in &inputs {
+            for descending in [false, true] {
+                let out = s.rank_all(descending)?;
+                assert_eq!(out.width(), RANK_ALL_METHODS.len());
+                for (name, method) in RANK_ALL_METHODS {
+                    let expected = rank(s, method, descending, None).with_name(name.into());
+                    assert!(
+                        out.column(name)?
+                            .as_materialized_series()
+                            .equals_missing(&expected)
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_rank_all_null() -> PolarsResult<()> {
         let s = UInt32Chunked::new("".into(), &[None, None, None]).into_series();
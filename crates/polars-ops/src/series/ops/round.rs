@@ -358,4 +358,37 @@ mod test {
         let ca = out.f64().unwrap();
         assert_eq!(ca.get(0), Some(1.0));
     }
+
+    #[test]
+    fn test_round_ties() {
+        let series = Series::new("a".into(), &[0.5, 1.5, 2.5, 3.5, -2.5, 0.125]);
+
+        let out = series.round(0, RoundMode::HalfToEven).unwrap();
+        assert_eq!(
+            out.f64().unwrap().to_vec(),
+            &[
+                Some(0.0),
+                Some(2.0),
+                Some(2.0),
+                Some(4.0),
+                Some(-2.0),
+                Some(0.0)
+            ]
+        );
+        let out = series.round(2, RoundMode::HalfToEven).unwrap();
+        assert_eq!(out.f64().unwrap().get(5), Some(0.12));
+
+        let out = series.round(0, RoundMode::HalfAwayFromZero).unwrap();
+        assert_eq!(
+            out.f64().unwrap().to_vec(),
+            &[
+                Some(1.0),
+                Some(2.0),
+                Some(3.0),
+                Some(4.0),
+                Some(-3.0),
+                Some(0.0)
+            ]
+        );
+    }
 }
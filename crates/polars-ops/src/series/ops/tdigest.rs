@@ -0,0 +1,312 @@
+use std::f64::consts::PI;
+
+use polars_core::prelude::*;
+
+const HEADER_SIZE: usize = 3 * size_of::<f64>();
+const CENTROID_SIZE: usize = 2 * size_of::<f64>();
+/// Unmerged centroids are buffered up to this multiple of the compression before compressing.
+const BUFFER_FACTOR: usize = 10;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+/// A t-digest sketch of a distribution, for approximate quantiles.
+///
+/// The values are summarized by weighted centroids, which are small near the tails and large
+/// near the median, so extreme quantiles stay accurate. A higher `compression` keeps more
+/// centroids. Sketches can be merged, so they can be built per chunk or group and combined later.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TDigest {
+    compression: f64,
+    min: f64,
+    max: f64,
+    /// Sorted by mean.
+    centroids: Vec<Centroid>,
+}
+
+/// A [`BinaryChunked`] with a serialized [`TDigest`] in every row.
+#[derive(Clone)]
+pub struct TDigestSeries(BinaryChunked);
+
+/// Scale function of the merging t-digest, mapping a quantile to a centroid index.
+fn scale(q: f64, compression: f64) -> f64 {
+    compression / (2.0 * PI) * (2.0 * q - 1.0).asin()
+}
+
+fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+impl TDigest {
+    fn empty(compression: f64) -> Self {
+        Self {
+            compression,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+            centroids: vec![],
+        }
+    }
+
+    /// Merge centroids from left to right as long as the merged centroid spans at most one unit
+    /// of the scale function.
+    fn compress(&mut self) {
+        self.centroids
+            .sort_unstable_by(|a, b| a.mean.total_cmp(&b.mean));
+        let total = self.count();
+        let mut centroids = std::mem::take(&mut self.centroids).into_iter();
+        let Some(mut current) = centroids.next() else {
+            return;
+        };
+
+        let mut weight_before = 0.0;
+        let mut k_lower = scale(0.0, self.compression);
+        for c in centroids {
+            let q = (weight_before + current.weight + c.weight) / total;
+            if scale(q, self.compression) - k_lower <= 1.0 {
+                current.weight += c.weight;
+                current.mean += (c.mean - current.mean) * c.weight / current.weight;
+            } else {
+                weight_before += current.weight;
+                k_lower = scale(weight_before / total, self.compression);
+                self.centroids.push(current);
+                current = c;
+            }
+        }
+        self.centroids.push(current);
+    }
+
+    /// Add a centroid, compressing once the buffer of unmerged centroids is full so memory stays
+    /// bounded by the compression instead of the number of values.
+    fn push(&mut self, centroid: Centroid) {
+        self.centroids.push(centroid);
+        let capacity = (self.compression.ceil() as usize).saturating_mul(BUFFER_FACTOR);
+        if self.centroids.len() >= capacity {
+            self.compress();
+        }
+    }
+
+    fn from_values(values: impl Iterator<Item = f64>, compression: f64) -> Self {
+        let mut digest = Self::empty(compression);
+        for v in values.filter(|v| !v.is_nan()) {
+            digest.min = digest.min.min(v);
+            digest.max = digest.max.max(v);
+            digest.push(Centroid {
+                mean: v,
+                weight: 1.0,
+            });
+        }
+        digest.compress();
+        digest
+    }
+
+    fn from_bytes(bytes: &[u8]) -> PolarsResult<Self> {
+        polars_ensure!(
+            bytes.len() >= HEADER_SIZE && (bytes.len() - HEADER_SIZE) % CENTROID_SIZE == 0,
+            ComputeError: "invalid t-digest sketch of {} bytes", bytes.len()
+        );
+        let mut floats = bytes
+            .chunks_exact(size_of::<f64>())
+            .map(|b| f64::from_le_bytes(b.try_into().unwrap()));
+        let compression = floats.next().unwrap();
+        let min = floats.next().unwrap();
+        let max = floats.next().unwrap();
+        let mut centroids = Vec::with_capacity((bytes.len() - HEADER_SIZE) / CENTROID_SIZE);
+        while let (Some(mean), Some(weight)) = (floats.next(), floats.next()) {
+            centroids.push(Centroid { mean, weight });
+        }
+        Ok(Self {
+            compression,
+            min,
+            max,
+            centroids,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_SIZE + self.centroids.len() * CENTROID_SIZE);
+        for v in [self.compression, self.min, self.max] {
+            bytes.extend_from_slice(&v.to_le_bytes());
+        }
+        for c in &self.centroids {
+            bytes.extend_from_slice(&c.mean.to_le_bytes());
+            bytes.extend_from_slice(&c.weight.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Number of values summarized by this sketch.
+    pub fn count(&self) -> f64 {
+        self.centroids.iter().map(|c| c.weight).sum()
+    }
+
+    /// Estimate the `q` quantile by interpolating between the centroids, `None` if the sketch
+    /// is empty.
+    pub fn estimate_quantile(&self, q: f64) -> Option<f64> {
+        let last = self.centroids.last()?;
+        let total = self.count();
+        let target = q * total;
+        if target <= 0.0 {
+            return Some(self.min);
+        }
+        if target >= total {
+            return Some(self.max);
+        }
+
+        // Every centroid sits at the middle of the weight it covers.
+        let mut weight_before = 0.0;
+        let (mut prev_center, mut prev_mean) = (0.0, self.min);
+        for c in &self.centroids {
+            let center = weight_before + c.weight / 2.0;
+            if target < center {
+                let t = (target - prev_center) / (center - prev_center);
+                return Some(lerp(prev_mean, c.mean, t));
+            }
+            weight_before += c.weight;
+            (prev_center, prev_mean) = (center, c.mean);
+        }
+        let center = total - last.weight / 2.0;
+        let t = (target - center) / (total - center);
+        Some(lerp(last.mean, self.max, t))
+    }
+
+    /// Build a sketch of all non-null and non-NaN values of a numeric `s`.
+    pub fn build(s: &Series, compression: f64) -> PolarsResult<TDigestSeries> {
+        polars_ensure!(
+            s.dtype().is_primitive_numeric(),
+            InvalidOperation: "t-digest expects numeric data, got {}", s.dtype()
+        );
+        polars_ensure!(
+            compression.is_finite() && compression > 0.0,
+            ComputeError: "t-digest compression must be positive, got {}", compression
+        );
+        let s = s.cast(&DataType::Float64)?;
+        let digest = Self::from_values(s.f64()?.iter().flatten(), compression);
+        Ok(TDigestSeries::from_digests(
+            s.name().clone(),
+            [Some(digest)],
+        ))
+    }
+
+    /// Merge all sketches of `sketches` into a single sketch. Null rows are ignored.
+    pub fn merge(sketches: &Series) -> PolarsResult<TDigestSeries> {
+        let sketches = TDigestSeries::from_series(sketches)?;
+        let mut merged: Option<TDigest> = None;
+        for digest in sketches.digests() {
+            let Some(digest) = digest? else {
+                continue;
+            };
+            match &mut merged {
+                None => merged = Some(digest),
+                Some(acc) => {
+                    acc.compression = acc.compression.max(digest.compression);
+                    acc.min = acc.min.min(digest.min);
+                    acc.max = acc.max.max(digest.max);
+                    for c in digest.centroids {
+                        acc.push(c);
+                    }
+                },
+            }
+        }
+        let merged = merged.map(|mut digest| {
+            digest.compress();
+            digest
+        });
+        Ok(TDigestSeries::from_digests(
+            sketches.0.name().clone(),
+            [merged],
+        ))
+    }
+
+    /// Estimate the `q` quantile of every sketch in `sketches` as `Float64`, null for null or
+    /// empty sketches.
+    pub fn quantile(sketches: &Series, q: f64) -> PolarsResult<Series> {
+        polars_ensure!(
+            (0.0..=1.0).contains(&q),
+            ComputeError: "quantile should be between 0.0 and 1.0"
+        );
+        let sketches = TDigestSeries::from_series(sketches)?;
+        let out = sketches
+            .digests()
+            .map(|digest| Ok(digest?.and_then(|digest| digest.estimate_quantile(q))))
+            .collect::<PolarsResult<Float64Chunked>>()?;
+        Ok(out.with_name(sketches.0.name().clone()).into_series())
+    }
+}
+
+impl TDigestSeries {
+    fn from_digests(name: PlSmallStr, digests: impl IntoIterator<Item = Option<TDigest>>) -> Self {
+        let ca: BinaryChunked = digests
+            .into_iter()
+            .map(|digest| digest.map(|digest| digest.to_bytes()))
+            .collect();
+        Self(ca.with_name(name))
+    }
+
+    /// Interpret a `Binary` [`Series`] as serialized sketches.
+    pub fn from_series(s: &Series) -> PolarsResult<Self> {
+        Ok(Self(s.binary()?.clone()))
+    }
+
+    /// Deserialize the sketch of every row.
+    pub fn digests(&self) -> impl Iterator<Item = PolarsResult<Option<TDigest>>> + '_ {
+        self.0
+            .iter()
+            .map(|bytes| bytes.map(TDigest::from_bytes).transpose())
+    }
+
+    pub fn into_series(self) -> Series {
+        self.0.into_series()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tdigest_quantile() -> PolarsResult<()> {
+        let s = Series::new(
+            "a".into(),
+            (1..=10_000).map(|v| v as f64).collect::<Vec<_>>(),
+        );
+        let sketch = TDigest::build(&s, 100.0)?.into_series();
+        assert_eq!(sketch.len(), 1);
+        let digest = TDigestSeries::from_series(&sketch)?
+            .digests()
+            .next()
+            .unwrap()?
+            .unwrap();
+        assert_eq!(digest.count(), 10_000.0);
+        assert!(digest.centroids.len() < 200);
+
+        let quantile = |sketch: &Series, q| -> PolarsResult<f64> {
+            Ok(TDigest::quantile(sketch, q)?.f64()?.get(0).unwrap())
+        };
+        assert_eq!(quantile(&sketch, 0.0)?, 1.0);
+        assert_eq!(quantile(&sketch, 1.0)?, 10_000.0);
+        for q in [0.01, 0.25, 0.5, 0.75, 0.99] {
+            let estimate = quantile(&sketch, q)?;
+            assert!((estimate - q * 10_000.0).abs() < 50.0, "{q}: {estimate}");
+        }
+
+        // Sketches of two halves merge into a sketch of the whole.
+        let mut halves = TDigest::build(&s.slice(0, 5_000), 100.0)?.into_series();
+        halves.append(&TDigest::build(&s.slice(5_000, 5_000), 100.0)?.into_series())?;
+        let both = TDigest::quantile(&halves, 0.5)?;
+        assert!(both.f64()?.get(0).unwrap() < 5_000.0);
+        assert!(both.f64()?.get(1).unwrap() > 5_000.0);
+        let merged = TDigest::merge(&halves)?.into_series();
+        assert!((quantile(&merged, 0.5)? - 5_000.0).abs() < 50.0);
+
+        let empty = TDigest::build(&Series::new_empty("a".into(), &DataType::Int32), 100.0)?;
+        assert_eq!(
+            TDigest::quantile(&empty.into_series(), 0.5)?.null_count(),
+            1
+        );
+        assert!(TDigest::quantile(&sketch, 1.5).is_err());
+        Ok(())
+    }
+}
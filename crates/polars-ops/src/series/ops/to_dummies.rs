@@ -1,3 +1,4 @@
+use arrow::bitmap::MutableBitmap;
 use polars_utils::format_pl_smallstr;
 
 use super::*;
@@ -19,6 +20,80 @@ pub trait ToDummies {
         drop_first: bool,
         drop_nulls: bool,
     ) -> PolarsResult<DataFrame>;
+
+    /// Like [`ToDummies::to_dummies`], but only stores the rows that are set for every
+    /// indicator column.
+    fn to_dummies_sparse(
+        &self,
+        separator: Option<&str>,
+        drop_first: bool,
+        drop_nulls: bool,
+    ) -> PolarsResult<SparseFrame>;
+}
+
+/// Indicator columns stored as the sorted row indices at which they are set.
+///
+/// The memory usage grows with the length of the Series rather than with the length times
+/// the number of categories. Columns are materialized as [`BooleanChunked`] on access.
+#[derive(Clone, Debug)]
+pub struct SparseFrame {
+    height: usize,
+    columns: Vec<(PlSmallStr, Vec<IdxSize>)>,
+}
+
+impl SparseFrame {
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.columns.len()
+    }
+
+    pub fn get_column_names(&self) -> impl Iterator<Item = &PlSmallStr> {
+        self.columns.iter().map(|(name, _)| name)
+    }
+
+    /// Materialize the indicator column `name`.
+    pub fn column(&self, name: &str) -> Option<BooleanChunked> {
+        self.columns
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(n, idx)| self.materialize(n, idx))
+    }
+
+    /// Iterate over the materialized indicator columns.
+    pub fn iter(&self) -> impl Iterator<Item = BooleanChunked> + '_ {
+        self.columns
+            .iter()
+            .map(|(name, idx)| self.materialize(name, idx))
+    }
+
+    /// Convert to the dense representation returned by [`ToDummies::to_dummies`].
+    pub fn to_dense(&self) -> PolarsResult<DataFrame> {
+        let columns = self
+            .columns
+            .iter()
+            .map(|(name, idx)| dummies_helper_idx(idx, self.height, name.clone()).into_column())
+            .collect();
+        DataFrame::new(self.height, columns)
+    }
+
+    /// Estimated heap size in bytes.
+    pub fn estimated_size(&self) -> usize {
+        self.columns
+            .iter()
+            .map(|(name, idx)| name.len() + idx.len() * size_of::<IdxSize>())
+            .sum()
+    }
+
+    fn materialize(&self, name: &PlSmallStr, idx: &[IdxSize]) -> BooleanChunked {
+        let mut bitmap = MutableBitmap::from_len_zeroed(self.height);
+        for &i in idx {
+            bitmap.set(i as usize, true);
+        }
+        BooleanChunked::from_bitmap(name.clone(), bitmap.freeze())
+    }
 }
 
 impl ToDummies for Series {
@@ -28,47 +103,81 @@ impl ToDummies for Series {
         drop_first: bool,
         drop_nulls: bool,
     ) -> PolarsResult<DataFrame> {
-        let sep = separator.unwrap_or("_");
-        let col_name = self.name();
-
-        // We only need to maintain order if we need to drop the first non-null item.
-        let maintain_order = drop_first;
-        let groups = self.group_tuples(true, maintain_order)?;
-
-        // SAFETY: groups are in bounds.
-        let columns = unsafe { self.agg_first(&groups) };
-        let columns = columns.iter().zip(groups.iter());
-        let mut seen_first = false;
-        let columns = columns
-            .filter_map(|(av, group)| {
-                if av.is_null() && drop_nulls {
-                    return None;
-                } else if !seen_first && !av.is_null() && drop_first {
-                    // The position of the first non-null item could be either 0 or 1.
-                    seen_first = true;
-                    return None;
-                }
-                // strings are formatted with extra \" \" in polars, so we
-                // extract the string
-                let name = if let Some(s) = av.get_str() {
-                    format_pl_smallstr!("{col_name}{sep}{s}")
-                } else {
-                    // other types don't have this formatting issue
-                    format_pl_smallstr!("{col_name}{sep}{av}")
-                };
-
-                let ca = match group {
-                    GroupsIndicator::Idx((_, group)) => dummies_helper_idx(group, self.len(), name),
-                    GroupsIndicator::Slice([offset, len]) => {
-                        dummies_helper_slice(offset, len, self.len(), name)
-                    },
-                };
-                Some(ca.into_column())
-            })
-            .collect::<Vec<_>>();
+        let columns = dummy_groups(self, separator, drop_first, drop_nulls, |name, group| {
+            match group {
+                GroupsIndicator::Idx((_, group)) => dummies_helper_idx(group, self.len(), name),
+                GroupsIndicator::Slice([offset, len]) => {
+                    dummies_helper_slice(offset, len, self.len(), name)
+                },
+            }
+            .into_column()
+        })?;
 
         DataFrame::new_infer_height(sort_columns(columns))
     }
+
+    fn to_dummies_sparse(
+        &self,
+        separator: Option<&str>,
+        drop_first: bool,
+        drop_nulls: bool,
+    ) -> PolarsResult<SparseFrame> {
+        let mut columns = dummy_groups(self, separator, drop_first, drop_nulls, |name, group| {
+            let idx = match group {
+                GroupsIndicator::Idx((_, group)) => group.to_vec(),
+                GroupsIndicator::Slice([offset, len]) => (offset..offset + len).collect(),
+            };
+            (name, idx)
+        })?;
+        columns.sort_by(|a, b| a.0.cmp(&b.0));
+
+        Ok(SparseFrame {
+            height: self.len(),
+            columns,
+        })
+    }
+}
+
+/// Name every group of `s` and pass it to `f`, skipping the groups dropped by `drop_first`
+/// and `drop_nulls`.
+fn dummy_groups<T>(
+    s: &Series,
+    separator: Option<&str>,
+    drop_first: bool,
+    drop_nulls: bool,
+    mut f: impl FnMut(PlSmallStr, GroupsIndicator) -> T,
+) -> PolarsResult<Vec<T>> {
+    let sep = separator.unwrap_or("_");
+    let col_name = s.name();
+
+    // We only need to maintain order if we need to drop the first non-null item.
+    let maintain_order = drop_first;
+    let groups = s.group_tuples(true, maintain_order)?;
+
+    // SAFETY: groups are in bounds.
+    let columns = unsafe { s.agg_first(&groups) };
+    let columns = columns.iter().zip(groups.iter());
+    let mut seen_first = false;
+    Ok(columns
+        .filter_map(|(av, group)| {
+            if av.is_null() && drop_nulls {
+                return None;
+            } else if !seen_first && !av.is_null() && drop_first {
+                // The position of the first non-null item could be either 0 or 1.
+                seen_first = true;
+                return None;
+            }
+            // strings are formatted with extra \" \" in polars, so we
+            // extract the string
+            let name = if let Some(s) = av.get_str() {
+                format_pl_smallstr!("{col_name}{sep}{s}")
+            } else {
+                // other types don't have this formatting issue
+                format_pl_smallstr!("{col_name}{sep}{av}")
+            };
+            Some(f(name, group))
+        })
+        .collect())
 }
 
 fn dummies_helper_idx(groups: &[IdxSize], len: usize, name: PlSmallStr) -> DummyCa {
@@ -102,3 +211,34 @@ fn sort_columns(mut columns: Vec<Column>) -> Vec<Column> {
     columns.sort_by(|a, b| a.name().partial_cmp(b.name()).unwrap());
     columns
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_to_dummies_sparse() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [Some("x"), Some("y"), None, Some("x")]);
+        let sparse = s.to_dummies_sparse(None, false, false)?;
+        assert_eq!(sparse.width(), 3);
+        assert!(
+            sparse
+                .to_dense()?
+                .equals(&s.to_dummies(None, false, false)?)
+        );
+        assert_eq!(
+            Vec::from(&sparse.column("a_x").unwrap()),
+            &[Some(true), Some(false), Some(false), Some(true)]
+        );
+
+        let s = Series::new(
+            "a".into(),
+            (0..10_000).map(|i| i % 1000).collect::<Vec<i32>>(),
+        );
+        let sparse = s.to_dummies_sparse(None, false, false)?;
+        let dense = s.to_dummies(None, false, false)?;
+        assert_eq!(sparse.width(), 1000);
+        assert!(sparse.estimated_size() * 50 < dense.estimated_size());
+        Ok(())
+    }
+}
@@ -71,6 +71,40 @@ pub trait SeriesMethods: SeriesSealed {
         Ok(())
     }
 
+    /// Checks if the values of a [`Series`] never decrease.
+    ///
+    /// If `strict` is `true`, any null makes the series non-monotonic, otherwise only the
+    /// non-null values are compared.
+    fn is_monotonic_increasing(&self, strict: bool) -> PolarsResult<bool> {
+        is_monotonic(self.as_series(), false, strict)
+    }
+
+    /// Checks if the values of a [`Series`] never increase.
+    ///
+    /// If `strict` is `true`, any null makes the series non-monotonic, otherwise only the
+    /// non-null values are compared.
+    fn is_monotonic_decreasing(&self, strict: bool) -> PolarsResult<bool> {
+        is_monotonic(self.as_series(), true, strict)
+    }
+
+    /// Checks if the values of a [`Series`] never decrease, comparing nulls as values.
+    ///
+    /// If `null_equal` is `true`, nulls are equal to each other and smaller than any other
+    /// value, so the series may start with nulls. Otherwise a null equals nothing and any null
+    /// makes the series non-monotonic.
+    fn is_monotonic_increasing_by(&self, null_equal: bool) -> PolarsResult<bool> {
+        is_monotonic_by(self.as_series(), false, null_equal)
+    }
+
+    /// Checks if the values of a [`Series`] never increase, comparing nulls as values.
+    ///
+    /// If `null_equal` is `true`, nulls are equal to each other and smaller than any other
+    /// value, so the series may end with nulls. Otherwise a null equals nothing and any null
+    /// makes the series non-monotonic.
+    fn is_monotonic_decreasing_by(&self, null_equal: bool) -> PolarsResult<bool> {
+        is_monotonic_by(self.as_series(), true, null_equal)
+    }
+
     /// Checks if a [`Series`] is sorted. Tries to fail fast.
     fn is_sorted(&self, options: SortOptions) -> PolarsResult<bool> {
         let s = self.as_series();
@@ -210,6 +244,29 @@ pub trait SeriesMethods: SeriesSealed {
     }
 }
 
+fn is_monotonic(s: &Series, descending: bool, strict: bool) -> PolarsResult<bool> {
+    if !s.has_nulls() {
+        return s.is_sorted(SortOptions::default().with_order_descending(descending));
+    }
+    if strict {
+        return Ok(false);
+    }
+    s.drop_nulls()
+        .is_sorted(SortOptions::default().with_order_descending(descending))
+}
+
+fn is_monotonic_by(s: &Series, descending: bool, null_equal: bool) -> PolarsResult<bool> {
+    if !null_equal && s.has_nulls() {
+        return Ok(false);
+    }
+    // Nulls are the smallest value: first when increasing, last when decreasing.
+    s.is_sorted(
+        SortOptions::default()
+            .with_order_descending(descending)
+            .with_nulls_last(descending),
+    )
+}
+
 /// Returns whether iterator elements are non-decreasing (`descending == false`) or non-increasing
 /// (`descending == true`) under [`TotalOrd`].
 ///
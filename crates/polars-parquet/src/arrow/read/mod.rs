@@ -6,7 +6,7 @@ pub mod expr;
 pub mod schema;
 pub mod statistics;
 
-use std::io::{Read, Seek};
+use std::io::{Read, Seek, SeekFrom};
 
 use arrow::types::{NativeType, i256};
 pub use deserialize::{
@@ -30,6 +30,7 @@ pub use crate::parquet::{
     read::{
         BasicDecompressor, MutStreamingIterator, PageReader, ReadColumnIterator, State, decompress,
         get_column_iterator, read_metadata as _read_metadata,
+        read_metadata_with_limit as _read_metadata_with_limit,
     },
     schema::types::{
         GroupLogicalType, ParquetType, PhysicalType, PrimitiveConvertedType, PrimitiveLogicalType,
@@ -58,6 +59,20 @@ pub fn read_metadata<R: Read + Seek>(reader: &mut R) -> PolarsResult<FileMetadat
     Ok(_read_metadata(reader)?)
 }
 
+/// Reads parquets' metadata synchronously, erroring if the footer is larger than
+/// `max_footer_size` bytes.
+pub fn read_metadata_with_limit<R: Read + Seek>(
+    reader: &mut R,
+    max_footer_size: usize,
+) -> PolarsResult<FileMetadata> {
+    let file_size = reader.seek(SeekFrom::End(0))?;
+    Ok(_read_metadata_with_limit(
+        reader,
+        file_size,
+        max_footer_size as u64,
+    )?)
+}
+
 /// Reads parquets' metadata asynchronously.
 #[cfg(feature = "async")]
 pub async fn read_metadata_async<R: AsyncRead + AsyncSeek + Send + Unpin>(
@@ -603,7 +603,14 @@ pub fn array_to_pages<K: DictionaryKey>(
             };
 
             if let Some(stats) = &mut statistics {
-                stats.null_count = Some(array.null_count() as i64)
+                stats.null_count = Some(array.null_count() as i64);
+
+                // The statistics above are computed over the dictionary values, which are not
+                // referenced at all if every key is null.
+                if array.null_count() == array.len() {
+                    stats.min_value = None;
+                    stats.max_value = None;
+                }
             }
 
             // write DataPages pointing to DictPage
@@ -52,6 +52,7 @@ impl<W: Write> FileWriter<W> {
                 FileWriteOptions {
                     version: options.version,
                     write_statistics: options.has_statistics(),
+                    max_footer_size: options.max_footer_size,
                 },
                 created_by,
             ),
@@ -91,6 +92,17 @@ impl<W: Write> FileWriter<W> {
         Ok(self.writer.end(Some(key_value_metadata))?)
     }
 
+    /// Returns the size of the footer that [`Self::end`] would write if it was called now with
+    /// the same `key_value_metadata`.
+    pub fn estimate_footer_size(
+        &self,
+        key_value_metadata: Option<Vec<KeyValue>>,
+    ) -> PolarsResult<u64> {
+        let key_value_metadata =
+            key_value_metadata.unwrap_or_else(|| vec![schema_to_metadata_key(&self.schema)]);
+        Ok(self.writer.estimate_footer_size(Some(key_value_metadata))?)
+    }
+
     /// Consumes this writer and returns the inner writer
     pub fn into_inner(self) -> W {
         self.writer.into_inner()
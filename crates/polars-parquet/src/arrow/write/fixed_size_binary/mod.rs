@@ -184,3 +184,34 @@ pub(super) fn build_statistics_decimal256(
             .flatten(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use arrow::datatypes::ArrowDataType;
+
+    use super::super::decimal_length_from_precision;
+    use super::*;
+    use crate::parquet::schema::types::PhysicalType;
+
+    #[test]
+    fn test_decimal_statistics_are_big_endian() {
+        // 10.5 and -0.5 as Decimal(20, 1). The statistics hold the unscaled integers as
+        // big-endian two's complement with the length of the fixed-length column.
+        let size = decimal_length_from_precision(20);
+        assert_eq!(size, 9);
+        let array = PrimitiveArray::<i128>::from([Some(105), None, Some(-5)])
+            .to(ArrowDataType::Decimal(20, 1));
+        let primitive_type =
+            PrimitiveType::from_physical("a".into(), PhysicalType::FixedLenByteArray(size));
+
+        let stats =
+            build_statistics_decimal(&array, primitive_type, size, &StatisticsOptions::default());
+        let mut max = vec![0x00; 8];
+        max.push(0x69);
+        let mut min = vec![0xff; 8];
+        min.push(0xfb);
+        assert_eq!(stats.max_value, Some(max));
+        assert_eq!(stats.min_value, Some(min));
+        assert_eq!(stats.null_count, Some(1));
+    }
+}
@@ -98,13 +98,16 @@ pub struct WriteOptions {
     pub compression: CompressionOptions,
     /// The size to flush a page, defaults to 1024 * 1024 if None
     pub data_page_size: Option<usize>,
+    /// The maximum size in bytes of the file metadata in the footer, see
+    /// [`crate::parquet::write::WriteOptions::max_footer_size`].
+    pub max_footer_size: Option<usize>,
 }
 
 use arrow::compute::aggregate::estimated_bytes_size;
 use arrow::match_integer_type;
 pub use file::FileWriter;
 pub use pages::{Nested, array_to_columns, arrays_to_columns};
-use polars_error::{PolarsResult, polars_bail};
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
 pub use row_group::{RowGroupIterator, row_group_iter};
 pub use schema::{schema_to_metadata_key, to_parquet_type};
 
@@ -1213,6 +1216,65 @@ pub fn get_dtype_encoding(dtype: &ArrowDataType) -> Vec<Encoding> {
     encodings
 }
 
+/// Field metadata key to select the [`Encoding`] of a column, see [`get_field_encoding`].
+pub const ENCODING_METADATA_KEY: &str = "polars:encoding";
+
+/// Returns the encodings of the (parquet) columns of `field`, like [`get_dtype_encoding`].
+///
+/// The default encoding can be overridden per field by setting [`ENCODING_METADATA_KEY`] in
+/// its metadata to `"plain"`, `"rle_dict"` or `"delta"`. This is only supported on non-nested
+/// fields.
+pub fn get_field_encoding(field: &Field) -> PolarsResult<Vec<Encoding>> {
+    let Some(annotation) = field
+        .metadata
+        .as_ref()
+        .and_then(|md| md.get(&PlSmallStr::from_static(ENCODING_METADATA_KEY)))
+    else {
+        return Ok(get_dtype_encoding(&field.dtype));
+    };
+
+    use arrow::datatypes::PhysicalType::*;
+    use arrow::types::PrimitiveType as P;
+    let physical = field.dtype.to_physical_type();
+    polars_ensure!(
+        !matches!(physical, List | FixedSizeList | LargeList | Struct | Map | Union),
+        InvalidOperation: "parquet encoding annotation is not supported on nested column '{}'",
+        field.name
+    );
+    let encoding = match annotation.as_str() {
+        "plain" => (!matches!(physical, Dictionary(_))).then_some(Encoding::Plain),
+        "rle_dict" => (get_primitive_dtype_encoding(&field.dtype) == Encoding::RleDictionary)
+            .then_some(Encoding::RleDictionary),
+        "delta" => match physical {
+            Primitive(
+                P::Int8
+                | P::Int16
+                | P::Int32
+                | P::Int64
+                | P::UInt8
+                | P::UInt16
+                | P::UInt32
+                | P::UInt64,
+            ) => Some(Encoding::DeltaBinaryPacked),
+            Binary | LargeBinary | Utf8 | LargeUtf8 | BinaryView | Utf8View => {
+                Some(Encoding::DeltaLengthByteArray)
+            },
+            _ => None,
+        },
+        _ => polars_bail!(
+            InvalidOperation: "unknown parquet encoding '{}' for column '{}', expected one of 'plain', 'rle_dict' or 'delta'",
+            annotation, field.name
+        ),
+    };
+    match encoding {
+        Some(encoding) => Ok(vec![encoding]),
+        None => polars_bail!(
+            InvalidOperation: "parquet encoding '{}' is not supported for column '{}' of type {:?}",
+            annotation, field.name, field.dtype
+        ),
+    }
+}
+
 fn get_primitive_dtype_encoding(dtype: &ArrowDataType) -> Encoding {
     match dtype.to_physical_type() {
         PhysicalType::Dictionary(_)
@@ -394,6 +394,22 @@ mod tests {
         test_codec(CompressionOptions::Lz4Raw);
     }
 
+    #[test]
+    fn test_decompress_lz4_raw_block() {
+        // A raw LZ4 block as written by other writers: four literals, a match copying them
+        // twice more and five trailing literals.
+        let block = [
+            0x44, b'a', b'b', b'c', b'd', 0x04, 0x00, 0x50, b'x', b'y', b'z', b'w', b'v',
+        ];
+        let expected = b"abcdabcdabcdxyzwv";
+
+        let mut decompressed = vec![0; expected.len()];
+        let mut context = DecompressionContext::Unset;
+        decompress(Compression::Lz4Raw, &block, &mut decompressed, &mut context)
+            .expect("Error when decompressing");
+        assert_eq!(decompressed, expected);
+    }
+
     #[test]
     fn test_codec_zstd_default() {
         test_codec(CompressionOptions::Zstd(None));
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use hashbrown::hash_map::RawEntryMut;
-use polars_parquet_format::SortingColumn;
+use polars_parquet_format::{OffsetIndex, SortingColumn};
 use polars_utils::aliases::{InitHashMaps, PlHashMap};
 use polars_utils::idx_vec::UnitVec;
 use polars_utils::pl_str::PlSmallStr;
@@ -41,6 +41,8 @@ pub struct RowGroupMetadata {
     total_byte_size: usize,
     full_byte_range: core::ops::Range<u64>,
     sorting_columns: Option<Vec<SortingColumn>>,
+    /// Page-level offset indexes, one per column. Empty until they are read from the file.
+    offset_indexes: Arc<Vec<Option<OffsetIndex>>>,
 }
 
 impl RowGroupMetadata {
@@ -102,6 +104,19 @@ impl RowGroupMetadata {
         self.sorting_columns.as_deref()
     }
 
+    /// The [`OffsetIndex`] of the column at position `col`.
+    ///
+    /// Returns `None` if the file has no offset index for that column, or if the offset indexes
+    /// have not been loaded with [`read_offset_indexes`](crate::parquet::read::read_offset_indexes).
+    pub fn column_offset_index(&self, col: usize) -> Option<&OffsetIndex> {
+        self.offset_indexes.get(col).and_then(Option::as_ref)
+    }
+
+    pub(crate) fn set_offset_indexes(&mut self, offset_indexes: Vec<Option<OffsetIndex>>) {
+        debug_assert_eq!(offset_indexes.len(), self.columns.len());
+        self.offset_indexes = Arc::new(offset_indexes);
+    }
+
     /// Build a `RowGroupMetadata` from a [`CompactRowGroup`], joining each
     /// chunk to its descriptor in the schema.
     pub(crate) fn from_compact(
@@ -156,6 +171,7 @@ impl RowGroupMetadata {
             total_byte_size,
             full_byte_range,
             sorting_columns,
+            offset_indexes: Arc::default(),
         })
     }
 }
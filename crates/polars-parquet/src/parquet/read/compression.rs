@@ -1,4 +1,4 @@
-use polars_parquet_format::DataPageHeaderV2;
+use polars_parquet_format::{DataPageHeaderV2, OffsetIndex};
 
 use super::PageReader;
 use crate::parquet::CowBuffer;
@@ -202,6 +202,11 @@ impl BasicDecompressor {
         }
     }
 
+    /// Skips ahead to the data page that contains `row`. See [`PageReader::seek_to_row`].
+    pub fn seek_to_row(&mut self, offset_index: &OffsetIndex, row: usize) -> ParquetResult<usize> {
+        self.reader.seek_to_row(offset_index, row)
+    }
+
     pub fn reuse_page_buffer(&mut self, page: DataPage) {
         let buffer = match page.buffer {
             CowBuffer::Borrowed(_) => return,
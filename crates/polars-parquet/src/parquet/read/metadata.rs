@@ -2,8 +2,10 @@ use std::cmp::min;
 use std::io::{Read, Seek, SeekFrom};
 
 use polars_buffer::Buffer;
+use polars_parquet_format::OffsetIndex;
+use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
 
-use super::super::metadata::FileMetadata;
+use super::super::metadata::{ColumnChunkMetadata, FileMetadata};
 use super::super::{DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, HEADER_SIZE, PARQUET_MAGIC};
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::handwritten_thrift::{decode_file_metadata, decode_num_rows};
@@ -39,10 +41,47 @@ pub fn read_metadata_with_size<R: Read + Seek>(
     reader: &mut R,
     file_size: u64,
 ) -> ParquetResult<FileMetadata> {
-    let footer = fetch_footer_buf(reader, file_size)?;
+    let footer = fetch_footer_buf(reader, file_size, None)?;
     deserialize_metadata(footer)
 }
 
+/// Reads a [`FileMetadata`] like [`read_metadata_with_size`], but errors instead of reading a
+/// footer larger than `max_footer_size` bytes. This bounds the memory spent on the footer of
+/// untrusted files.
+pub fn read_metadata_with_limit<R: Read + Seek>(
+    reader: &mut R,
+    file_size: u64,
+    max_footer_size: u64,
+) -> ParquetResult<FileMetadata> {
+    let footer = fetch_footer_buf(reader, file_size, Some(max_footer_size))?;
+    deserialize_metadata(footer)
+}
+
+/// Reads a [`FileMetadata`] from a Parquet file that is fully loaded in memory.
+///
+/// Unlike [`read_metadata`] this needs no [`Seek`]: the footer is sliced out of the end of
+/// `bytes` without copying.
+pub fn read_metadata_from_bytes(bytes: Buffer<u8>) -> ParquetResult<FileMetadata> {
+    let file_size = bytes.len() as u64;
+    if file_size < HEADER_SIZE + FOOTER_SIZE {
+        return Err(ParquetError::oos(
+            "A Parquet file must contain a header and footer with at least 12 bytes",
+        ));
+    }
+    if bytes[bytes.len() - 4..] != PARQUET_MAGIC {
+        return Err(ParquetError::oos("The file must end with PAR1"));
+    }
+
+    let footer_len = FOOTER_SIZE + metadata_len(&bytes) as u64;
+    if footer_len > file_size {
+        return Err(ParquetError::oos(
+            "The footer size must be smaller or equal to the file's size",
+        ));
+    }
+    let start = (file_size - footer_len) as usize;
+    deserialize_metadata(bytes.sliced(start..))
+}
+
 /// Parse loaded metadata bytes via the hand-written Thrift compact decoder.
 ///
 /// `footer` must be a [`Buffer<u8>`] because [`FileMetadata`] holds the buffer
@@ -71,7 +110,7 @@ pub(crate) fn read_num_rows_with_size<R: Read + Seek>(
     reader: &mut R,
     file_size: u64,
 ) -> ParquetResult<i64> {
-    let footer = fetch_footer_buf(reader, file_size)?;
+    let footer = fetch_footer_buf(reader, file_size, None)?;
     decode_num_rows(footer)
 }
 
@@ -79,7 +118,11 @@ pub(crate) fn read_num_rows_with_size<R: Read + Seek>(
 /// [`Buffer<u8>`] (not a `Vec<u8>`) because [`FileMetadata`] holds the buffer
 /// for the lifetime of the metadata; column-chunk statistics store
 /// `ByteRange`s into it instead of allocating per-stat byte vecs.
-fn fetch_footer_buf<R: Read + Seek>(reader: &mut R, file_size: u64) -> ParquetResult<Buffer<u8>> {
+fn fetch_footer_buf<R: Read + Seek>(
+    reader: &mut R,
+    file_size: u64,
+    max_footer_size: Option<u64>,
+) -> ParquetResult<Buffer<u8>> {
     if file_size < HEADER_SIZE + FOOTER_SIZE {
         return Err(ParquetError::oos(
             "A Parquet file must contain a header and footer with at least 12 bytes",
@@ -108,6 +151,13 @@ fn fetch_footer_buf<R: Read + Seek>(reader: &mut R, file_size: u64) -> ParquetRe
             "The footer size must be smaller or equal to the file's size",
         ));
     }
+    if let Some(max_footer_size) = max_footer_size
+        && metadata_len > max_footer_size
+    {
+        return Err(ParquetError::oos(format!(
+            "The footer size of {metadata_len} bytes exceeds the limit of {max_footer_size} bytes"
+        )));
+    }
 
     // Both branches end with a zero-copy move from `Vec<u8>` into `Buffer`.
     let footer_buf: Buffer<u8> = if (footer_len as usize) <= buffer.len() {
@@ -125,3 +175,52 @@ fn fetch_footer_buf<R: Read + Seek>(reader: &mut R, file_size: u64) -> ParquetRe
 
     Ok(footer_buf)
 }
+
+/// Reads the page-level [`OffsetIndex`] of `column_chunk`.
+///
+/// The offset index is not part of the footer itself; the footer only records where it is
+/// located. Returns `None` if the column chunk has no offset index.
+pub fn read_offset_index<R: Read + Seek>(
+    reader: &mut R,
+    column_chunk: &ColumnChunkMetadata,
+) -> ParquetResult<Option<OffsetIndex>> {
+    let (Some(offset), Some(length)) = (
+        column_chunk.offset_index_offset(),
+        column_chunk.offset_index_length(),
+    ) else {
+        return Ok(None);
+    };
+    let offset: u64 = offset.try_into()?;
+    let length: usize = length.try_into()?;
+
+    reader.seek(SeekFrom::Start(offset))?;
+    let mut buffer = vec![];
+    buffer.try_reserve(length)?;
+    reader.take(length as u64).read_to_end(&mut buffer)?;
+    if buffer.len() != length {
+        return Err(ParquetError::oos(
+            "The offset index is out of bounds of the file",
+        ));
+    }
+
+    let mut prot = TCompactInputProtocol::new(buffer.as_slice(), length);
+    Ok(Some(OffsetIndex::read_from_in_protocol(&mut prot)?))
+}
+
+/// Reads the [`OffsetIndex`] of every column chunk in `metadata` and attaches them to their
+/// row groups, making them available through
+/// [`RowGroupMetadata::column_offset_index`](crate::parquet::metadata::RowGroupMetadata::column_offset_index).
+pub fn read_offset_indexes<R: Read + Seek>(
+    reader: &mut R,
+    metadata: &mut FileMetadata,
+) -> ParquetResult<()> {
+    for row_group in metadata.row_groups.iter_mut() {
+        let indexes = row_group
+            .parquet_columns()
+            .iter()
+            .map(|column_chunk| read_offset_index(reader, column_chunk))
+            .collect::<ParquetResult<Vec<_>>>()?;
+        row_group.set_offset_indexes(indexes);
+    }
+    Ok(())
+}
@@ -11,8 +11,9 @@ use std::io::{Cursor, Seek, SeekFrom};
 pub use column::*;
 pub use compression::{BasicDecompressor, decompress};
 pub use metadata::{
-    deserialize_metadata, deserialize_num_rows, read_metadata, read_metadata_with_size,
-    read_num_rows,
+    deserialize_metadata, deserialize_num_rows, read_metadata, read_metadata_from_bytes,
+    read_metadata_with_limit, read_metadata_with_size, read_num_rows, read_offset_index,
+    read_offset_indexes,
 };
 pub use page::{PageIterator, PageMetaData, PageReader};
 #[cfg(feature = "async")]
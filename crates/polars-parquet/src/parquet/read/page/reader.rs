@@ -2,6 +2,7 @@ use std::io::{Cursor, Seek};
 use std::sync::OnceLock;
 
 use polars_buffer::Buffer;
+use polars_parquet_format::OffsetIndex;
 use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
 
 use super::PageIterator;
@@ -81,6 +82,11 @@ pub struct PageReader {
 
     // Maximum page size (compressed or uncompressed) to limit allocations
     max_page_size: usize,
+
+    // The file offset of this column chunk and the matching position in `reader`. Used to map
+    // page offsets from the offset index onto `reader`.
+    column_start: u64,
+    reader_start: u64,
 }
 
 impl PageReader {
@@ -106,6 +112,7 @@ impl PageReader {
         scratch: Vec<u8>,
         max_page_size: usize,
     ) -> Self {
+        let reader_start = reader.position();
         Self {
             reader,
             total_num_values: reader_meta.num_values,
@@ -114,6 +121,8 @@ impl PageReader {
             descriptor: reader_meta.descriptor,
             scratch,
             max_page_size,
+            column_start: reader_meta.column_start,
+            reader_start,
         }
     }
 
@@ -127,6 +136,51 @@ impl PageReader {
         self.total_num_values as usize
     }
 
+    /// Moves the reader to the start of the data page that contains `row`, without reading
+    /// any of the pages before it.
+    ///
+    /// `offset_index` must be the [`OffsetIndex`] of this column chunk. Returns the index of the
+    /// first row of that page, so the caller knows how many rows to skip within it. If the
+    /// column chunk has a dictionary page, it has to be read with [`PageReader::read_dict`]
+    /// before seeking.
+    ///
+    /// Only supported for non-nested columns, where every row holds exactly one value.
+    pub fn seek_to_row(&mut self, offset_index: &OffsetIndex, row: usize) -> ParquetResult<usize> {
+        if self.descriptor.max_rep_level > 0 {
+            return Err(ParquetError::not_supported(
+                "seeking to a row in a nested column",
+            ));
+        }
+        let row: i64 = row.try_into()?;
+        if row >= self.total_num_values {
+            return Err(ParquetError::oos(format!(
+                "Cannot seek to row {row} in a column chunk with {} rows",
+                self.total_num_values
+            )));
+        }
+
+        let locations = &offset_index.page_locations;
+        let page_idx = locations.partition_point(|loc| loc.first_row_index <= row);
+        let location = page_idx
+            .checked_sub(1)
+            .map(|i| &locations[i])
+            .ok_or_else(|| ParquetError::oos("The offset index does not contain the first row"))?;
+
+        let offset: u64 = location.offset.try_into()?;
+        let position = offset.checked_sub(self.column_start).ok_or_else(|| {
+            ParquetError::oos("The offset index points before the start of the column chunk")
+        })? + self.reader_start;
+        if position > self.reader.get_ref().len() as u64 {
+            return Err(ParquetError::oos(
+                "The offset index points past the end of the column chunk",
+            ));
+        }
+
+        self.reader.set_position(position);
+        self.seen_num_values = location.first_row_index;
+        Ok(location.first_row_index as usize)
+    }
+
     pub fn read_dict(&mut self) -> ParquetResult<Option<CompressedDictPage>> {
         // If there are no pages, we cannot check if the first page is a dictionary page. Just
         // return the fact there is no dictionary page.
@@ -18,13 +18,44 @@ pub(super) fn start_file<W: Write>(writer: &mut W) -> ParquetResult<u64> {
     Ok(PARQUET_MAGIC.len() as u64)
 }
 
+/// Serializes the file metadata, erroring if it exceeds `max_footer_size` or doesn't fit the
+/// 4-byte length of the footer.
+pub(super) fn serialize_metadata(
+    metadata: &ThriftFileMetadata,
+    max_footer_size: Option<usize>,
+) -> ParquetResult<Vec<u8>> {
+    let mut buffer = vec![];
+    {
+        let mut protocol = TCompactOutputProtocol::new(&mut buffer);
+        metadata.write_to_out_protocol(&mut protocol)?;
+    }
+
+    let max_len = max_footer_size.map_or(i32::MAX as usize, |max| max.min(i32::MAX as usize));
+    if buffer.len() > max_len {
+        return Err(ParquetError::InvalidParameter(format!(
+            "the file metadata is {} bytes, which exceeds the maximum footer size of {} bytes; \
+             consider writing fewer columns or row groups per file",
+            buffer.len(),
+            max_len
+        )));
+    }
+    Ok(buffer)
+}
+
 pub(super) fn end_file<W: Write>(
-    mut writer: &mut W,
+    writer: &mut W,
     metadata: &ThriftFileMetadata,
+    max_footer_size: Option<usize>,
 ) -> ParquetResult<u64> {
+    let metadata = serialize_metadata(metadata, max_footer_size)?;
+    write_footer(writer, &metadata)
+}
+
+/// Writes already serialized file metadata, followed by its length and the magic bytes.
+fn write_footer<W: Write>(writer: &mut W, metadata: &[u8]) -> ParquetResult<u64> {
     // Write metadata
-    let mut protocol = TCompactOutputProtocol::new(&mut writer);
-    let metadata_len = metadata.write_to_out_protocol(&mut protocol)? as i32;
+    writer.write_all(metadata)?;
+    let metadata_len = metadata.len() as i32;
 
     // Write footer
     let metadata_bytes = metadata_len.to_le_bytes();
@@ -54,6 +85,40 @@ fn create_column_orders(schema_desc: &SchemaDescriptor) -> Vec<polars_parquet_fo
         .collect()
 }
 
+/// Writes the column indexes (if `write_statistics`) and offset indexes of all row groups
+/// starting at `offset` and records their locations in the column chunks. Returns the offset
+/// after the indexes.
+fn write_page_indexes<W: Write>(
+    writer: &mut W,
+    mut offset: u64,
+    row_groups: &mut [RowGroup],
+    page_specs: &[Vec<Vec<PageWriteSpec>>],
+    write_statistics: bool,
+) -> ParquetResult<u64> {
+    if write_statistics {
+        // write column indexes (require page statistics)
+        for (group, pages) in row_groups.iter_mut().zip(page_specs) {
+            for (column, pages) in group.columns.iter_mut().zip(pages) {
+                let length = write_column_index(writer, pages)?;
+                column.column_index_offset = Some(offset as i64);
+                column.column_index_length = Some(length as i32);
+                offset += length;
+            }
+        }
+    }
+
+    // write offset index
+    for (group, pages) in row_groups.iter_mut().zip(page_specs) {
+        for (column, pages) in group.columns.iter_mut().zip(pages) {
+            let length = write_offset_index(writer, pages)?;
+            column.offset_index_offset = Some(offset as i64);
+            column.offset_index_length = Some(length as i32);
+            offset += length;
+        }
+    }
+    Ok(offset)
+}
+
 /// An interface to write a parquet file.
 /// Use `start` to write the header, `write` to write a row group,
 /// and `end` to write the footer.
@@ -84,7 +149,7 @@ pub fn write_metadata_sidecar<W: Write>(
     metadata: &ThriftFileMetadata,
 ) -> ParquetResult<u64> {
     let mut len = start_file(writer)?;
-    len += end_file(writer, metadata)?;
+    len += end_file(writer, metadata, None)?;
     Ok(len)
 }
 
@@ -190,64 +255,67 @@ impl<W: Write> FileWriter<W> {
                 "End cannot be called twice".to_string(),
             ));
         }
-        // compute file stats
-        let num_rows = self.row_groups.iter().map(|group| group.num_rows).sum();
 
-        if self.options.write_statistics {
-            // write column indexes (require page statistics)
-            self.row_groups
-                .iter_mut()
-                .zip(self.page_specs.iter())
-                .try_for_each(|(group, pages)| {
-                    group.columns.iter_mut().zip(pages.iter()).try_for_each(
-                        |(column, pages)| {
-                            let offset = self.offset;
-                            column.column_index_offset = Some(offset as i64);
-                            self.offset += write_column_index(&mut self.writer, pages)?;
-                            let length = self.offset - offset;
-                            column.column_index_length = Some(length as i32);
-                            ParquetResult::Ok(())
-                        },
-                    )?;
-                    ParquetResult::Ok(())
-                })?;
-        };
+        // Serialize the page indexes and the metadata up front, so that a footer exceeding
+        // `max_footer_size` errors before anything is written to the file.
+        let mut page_indexes = vec![];
+        let offset = write_page_indexes(
+            &mut page_indexes,
+            self.offset,
+            &mut self.row_groups,
+            &self.page_specs,
+            self.options.write_statistics,
+        )?;
+        let metadata = self.thrift_metadata(self.row_groups.clone(), key_value_metadata);
+        let metadata_bytes = serialize_metadata(&metadata, self.options.max_footer_size)?;
+
+        self.writer.write_all(&page_indexes)?;
+        self.offset = offset;
+        let len = write_footer(&mut self.writer, &metadata_bytes)?;
+        self.state = State::Finished;
+        self.metadata = Some(metadata);
+        Ok(self.offset + len)
+    }
 
-        // write offset index
-        self.row_groups
-            .iter_mut()
-            .zip(self.page_specs.iter())
-            .try_for_each(|(group, pages)| {
-                group
-                    .columns
-                    .iter_mut()
-                    .zip(pages.iter())
-                    .try_for_each(|(column, pages)| {
-                        let offset = self.offset;
-                        column.offset_index_offset = Some(offset as i64);
-                        self.offset += write_offset_index(&mut self.writer, pages)?;
-                        column.offset_index_length = Some((self.offset - offset) as i32);
-                        ParquetResult::Ok(())
-                    })?;
-                ParquetResult::Ok(())
-            })?;
+    /// Returns the size in bytes of the footer that [`Self::end`] would write if it was called
+    /// now with the same `key_value_metadata`, including the trailing length and magic bytes.
+    ///
+    /// The page indexes and footer are serialized into a sink to measure them, nothing is
+    /// written to the file.
+    pub fn estimate_footer_size(
+        &self,
+        key_value_metadata: Option<Vec<KeyValue>>,
+    ) -> ParquetResult<u64> {
+        let mut row_groups = self.row_groups.clone();
+        let offset = self.offset.max(PARQUET_MAGIC.len() as u64);
+        write_page_indexes(
+            &mut std::io::sink(),
+            offset,
+            &mut row_groups,
+            &self.page_specs,
+            self.options.write_statistics,
+        )?;
+        let metadata = self.thrift_metadata(row_groups, key_value_metadata);
+        end_file(&mut std::io::sink(), &metadata, None)
+    }
 
-        let metadata = ThriftFileMetadata::new(
+    fn thrift_metadata(
+        &self,
+        row_groups: Vec<RowGroup>,
+        key_value_metadata: Option<Vec<KeyValue>>,
+    ) -> ThriftFileMetadata {
+        let num_rows = row_groups.iter().map(|group| group.num_rows).sum();
+        ThriftFileMetadata::new(
             self.options.version.into(),
             self.schema.clone().into_thrift(),
             num_rows,
-            self.row_groups.clone(),
+            row_groups,
             key_value_metadata,
             self.created_by.clone(),
             Some(create_column_orders(&self.schema)),
             None,
             None,
-        );
-
-        let len = end_file(&mut self.writer, &metadata)?;
-        self.state = State::Finished;
-        self.metadata = Some(metadata);
-        Ok(self.offset + len)
+        )
     }
 
     /// Returns the underlying writer.
@@ -32,6 +32,9 @@ pub struct WriteOptions {
     pub write_statistics: bool,
     /// Which Parquet version to use
     pub version: Version,
+    /// The maximum size in bytes of the serialized file metadata in the footer. Footers
+    /// beyond `i32::MAX` bytes are always rejected, as their length can't be stored.
+    pub max_footer_size: Option<usize>,
 }
 
 /// The parquet version to use
@@ -2,8 +2,8 @@ use std::io::Write;
 
 use futures::{AsyncWrite, AsyncWriteExt};
 use polars_parquet_format::RowGroup;
-use polars_parquet_format::thrift::protocol::TCompactOutputStreamProtocol;
 
+use super::file::serialize_metadata;
 use super::row_group::write_row_group_async;
 use super::{RowGroupIterColumns, WriteOptions};
 use crate::parquet::error::{ParquetError, ParquetResult};
@@ -19,12 +19,14 @@ async fn start_file<W: AsyncWrite + Unpin>(writer: &mut W) -> ParquetResult<u64>
 }
 
 async fn end_file<W: AsyncWrite + Unpin + Send>(
-    mut writer: &mut W,
+    writer: &mut W,
     metadata: polars_parquet_format::FileMetaData,
+    max_footer_size: Option<usize>,
 ) -> ParquetResult<u64> {
     // Write file metadata
-    let mut protocol = TCompactOutputStreamProtocol::new(&mut writer);
-    let metadata_len = metadata.write_to_out_stream_protocol(&mut protocol).await? as i32;
+    let metadata = serialize_metadata(&metadata, max_footer_size)?;
+    writer.write_all(&metadata).await?;
+    let metadata_len = metadata.len() as i32;
 
     // Write footer
     let metadata_bytes = metadata_len.to_le_bytes();
@@ -181,7 +183,7 @@ impl<W: AsyncWrite + Unpin + Send> FileStreamer<W> {
             None,
         );
 
-        let len = end_file(&mut self.writer, metadata).await?;
+        let len = end_file(&mut self.writer, metadata, self.options.max_footer_size).await?;
         Ok(self.offset + len)
     }
 
@@ -184,12 +184,12 @@ impl DslBuilder {
             .unwrap_or(DataTypeSelector::Float.as_selector())
             .as_expr()
             .is_nan();
-        self.remove(functions::any_horizontal([is_nan]).unwrap())
+        self.remove(functions::any_horizontal([is_nan], false).unwrap())
     }
 
     pub fn drop_nulls(self, subset: Option<Selector>) -> Self {
         let is_not_null = subset.unwrap_or(Selector::Wildcard).as_expr().is_not_null();
-        self.filter(functions::all_horizontal([is_not_null]).unwrap())
+        self.filter(functions::all_horizontal([is_not_null], false).unwrap())
     }
 
     pub fn fill_nan(self, fill_value: Expr) -> Self {
@@ -212,6 +212,15 @@ impl DateLikeNameSpace {
             .map_unary(FunctionExpr::TemporalExpr(TemporalFunction::TimeStamp(tu)))
     }
 
+    /// Return the whole seconds since the UNIX epoch of a Datetime/Date as `Int64`.
+    ///
+    /// The sub-second part is floored, so instants before the epoch go to the previous second.
+    /// Use [`Self::timestamp`] for milli-, micro- or nanoseconds.
+    pub fn epoch_seconds(self) -> Expr {
+        self.timestamp(TimeUnit::Milliseconds)
+            .floor_div(lit(1000i64))
+    }
+
     /// Truncate the Datetime/Date range into buckets.
     pub fn truncate(self, every: Expr) -> Expr {
         self.0.map_binary(
@@ -17,6 +17,14 @@ pub enum BooleanFunction {
         ignore_nulls: bool,
     },
     HasNulls,
+    IsMonotonic {
+        descending: bool,
+        ignore_nulls: bool,
+    },
+    IsMonotonicBy {
+        descending: bool,
+        null_equal: bool,
+    },
     IsNull,
     IsNotNull,
     IsFinite,
@@ -68,6 +76,18 @@ impl Display for BooleanFunction {
             } => "is_empty",
             IsEmpty { ignore_nulls: true } => "is_empty_ignore_nulls",
             HasNulls => "has_nulls",
+            IsMonotonic {
+                descending: false, ..
+            } => "is_monotonic_increasing",
+            IsMonotonic {
+                descending: true, ..
+            } => "is_monotonic_decreasing",
+            IsMonotonicBy {
+                descending: false, ..
+            } => "is_monotonic_increasing_by",
+            IsMonotonicBy {
+                descending: true, ..
+            } => "is_monotonic_decreasing_by",
             IsNull => "is_null",
             IsNotNull => "is_not_null",
             IsFinite => "is_finite",
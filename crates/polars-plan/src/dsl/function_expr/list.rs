@@ -42,6 +42,12 @@ pub enum ListFunction {
         n: i64,
         null_behavior: NullBehavior,
     },
+    #[cfg(feature = "cum_agg")]
+    CumSum,
+    #[cfg(feature = "cum_agg")]
+    CumProd,
+    FlattenN(usize),
+    ChunkEvery,
     Sort(SortOptions),
     #[cfg(feature = "list_sets")]
     SetOperation(SetOperation),
@@ -90,6 +96,12 @@ impl Display for ListFunction {
             ArgMax => "arg_max",
             #[cfg(feature = "diff")]
             Diff { .. } => "diff",
+            #[cfg(feature = "cum_agg")]
+            CumSum => "cum_sum",
+            #[cfg(feature = "cum_agg")]
+            CumProd => "cum_prod",
+            FlattenN(_) => "flatten_n",
+            ChunkEvery => "chunk_every",
             Length => "length",
             Sort(_) => "sort",
             #[cfg(feature = "list_sets")]
@@ -101,6 +101,8 @@ pub enum FunctionExpr {
     Business(BusinessFunction),
     #[cfg(feature = "abs")]
     Abs,
+    #[cfg(feature = "abs")]
+    AbsDiff,
     Negate,
     #[cfg(feature = "hist")]
     Hist {
@@ -185,6 +187,8 @@ pub enum FunctionExpr {
         has_min: bool,
         has_max: bool,
     },
+    #[cfg(feature = "round_series")]
+    ClipBy,
     #[cfg(feature = "dtype-struct")]
     AsStruct,
     #[cfg(feature = "top_k")]
@@ -206,14 +210,17 @@ pub enum FunctionExpr {
     #[cfg(feature = "cum_agg")]
     CumProd {
         reverse: bool,
+        overflow: OverflowPolicy,
     },
     #[cfg(feature = "cum_agg")]
     CumMin {
         reverse: bool,
+        skip_nulls: bool,
     },
     #[cfg(feature = "cum_agg")]
     CumMax {
         reverse: bool,
+        skip_nulls: bool,
     },
     Reverse,
     #[cfg(feature = "dtype-struct")]
@@ -247,6 +254,16 @@ pub enum FunctionExpr {
     Log1p,
     #[cfg(feature = "log")]
     Exp,
+    #[cfg(feature = "tdigest")]
+    TDigest {
+        compression: f64,
+    },
+    #[cfg(feature = "tdigest")]
+    TDigestMerge,
+    #[cfg(feature = "tdigest")]
+    TDigestQuantile {
+        quantile: f64,
+    },
     Unique(bool),
     #[cfg(feature = "round_series")]
     Round {
@@ -373,6 +390,8 @@ pub enum FunctionExpr {
         n: usize,
         offset: usize,
     },
+    GatherEveryBy,
+    SampleSystematic,
     #[cfg(feature = "reinterpret")]
     Reinterpret(Option<bool>, Option<DataType>),
     ExtendConstant,
@@ -497,6 +516,8 @@ impl Hash for FunctionExpr {
             Mode { maintain_order } => maintain_order.hash(state),
             #[cfg(feature = "abs")]
             Abs => {},
+            #[cfg(feature = "abs")]
+            AbsDiff => {},
             Negate => {},
             NullCount => {},
             #[cfg(feature = "arg_where")]
@@ -541,6 +562,8 @@ impl Hash for FunctionExpr {
                 has_min.hash(state);
                 has_max.hash(state);
             },
+            #[cfg(feature = "round_series")]
+            ClipBy => {},
             #[cfg(feature = "top_k")]
             TopK { descending } => descending.hash(state),
             #[cfg(feature = "cum_agg")]
@@ -548,11 +571,26 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "cum_agg")]
             CumSum { reverse } => reverse.hash(state),
             #[cfg(feature = "cum_agg")]
-            CumProd { reverse } => reverse.hash(state),
+            CumProd { reverse, overflow } => {
+                reverse.hash(state);
+                overflow.hash(state);
+            },
             #[cfg(feature = "cum_agg")]
-            CumMin { reverse } => reverse.hash(state),
+            CumMin {
+                reverse,
+                skip_nulls,
+            } => {
+                reverse.hash(state);
+                skip_nulls.hash(state);
+            },
             #[cfg(feature = "cum_agg")]
-            CumMax { reverse } => reverse.hash(state),
+            CumMax {
+                reverse,
+                skip_nulls,
+            } => {
+                reverse.hash(state);
+                skip_nulls.hash(state);
+            },
             #[cfg(feature = "dtype-struct")]
             ValueCounts {
                 sort,
@@ -583,6 +621,12 @@ impl Hash for FunctionExpr {
             Log1p => {},
             #[cfg(feature = "log")]
             Exp => {},
+            #[cfg(feature = "tdigest")]
+            TDigest { compression } => compression.to_bits().hash(state),
+            #[cfg(feature = "tdigest")]
+            TDigestMerge => {},
+            #[cfg(feature = "tdigest")]
+            TDigestQuantile { quantile } => quantile.to_bits().hash(state),
             Unique(a) => a.hash(state),
             #[cfg(feature = "round_series")]
             Round { decimals, mode } => {
@@ -666,6 +710,8 @@ impl Hash for FunctionExpr {
             ReplaceStrict { return_dtype } => return_dtype.hash(state),
             FillNullWithStrategy(strategy) => strategy.hash(state),
             GatherEvery { n, offset } => (n, offset).hash(state),
+            GatherEveryBy => {},
+            SampleSystematic => {},
             #[cfg(feature = "reinterpret")]
             Reinterpret(signed, dtype) => {
                 signed.hash(state);
@@ -713,6 +759,8 @@ impl Display for FunctionExpr {
             Business(func) => return write!(f, "{func}"),
             #[cfg(feature = "abs")]
             Abs => "abs",
+            #[cfg(feature = "abs")]
+            AbsDiff => "abs_diff",
             Negate => "negate",
             NullCount => "null_count",
             Pow(func) => return write!(f, "{func}"),
@@ -772,6 +820,8 @@ impl Display for FunctionExpr {
                 (true, false) => "clip_min",
                 _ => unreachable!(),
             },
+            #[cfg(feature = "round_series")]
+            ClipBy => "clip_by",
             #[cfg(feature = "dtype-struct")]
             AsStruct => "as_struct",
             #[cfg(feature = "top_k")]
@@ -819,6 +869,12 @@ impl Display for FunctionExpr {
             Log1p => "log1p",
             #[cfg(feature = "log")]
             Exp => "exp",
+            #[cfg(feature = "tdigest")]
+            TDigest { .. } => "tdigest",
+            #[cfg(feature = "tdigest")]
+            TDigestMerge => "tdigest_merge",
+            #[cfg(feature = "tdigest")]
+            TDigestQuantile { .. } => "tdigest_quantile",
             Unique(stable) => {
                 if *stable {
                     "unique_stable"
@@ -888,7 +944,8 @@ impl Display for FunctionExpr {
             #[cfg(feature = "replace")]
             ReplaceStrict { .. } => "replace_strict",
             FillNullWithStrategy(_) => "fill_null_with_strategy",
-            GatherEvery { .. } => "gather_every",
+            GatherEvery { .. } | GatherEveryBy => "gather_every",
+            SampleSystematic => "sample_systematic",
             #[cfg(feature = "reinterpret")]
             Reinterpret(_, _) => "reinterpret",
             ExtendConstant => "extend_constant",
@@ -35,6 +35,11 @@ pub enum StringFunction {
         dtype: DataType,
         pat: PlSmallStr,
     },
+    #[cfg(feature = "extract_groups")]
+    ExtractAllGroups {
+        dtype: DataType,
+        pat: PlSmallStr,
+    },
     #[cfg(feature = "regex")]
     Find {
         literal: bool,
@@ -65,6 +70,16 @@ pub enum StringFunction {
     },
     #[cfg(feature = "string_reverse")]
     Reverse,
+    #[cfg(feature = "string_word_count")]
+    WordCount,
+    #[cfg(feature = "string_tokenize")]
+    Tokenize {
+        words_only: bool,
+    },
+    TruncateBytes {
+        n: usize,
+        ellipsis: Option<PlSmallStr>,
+    },
     #[cfg(feature = "string_pad")]
     PadStart {
         fill_char: char,
@@ -111,6 +126,8 @@ pub enum StringFunction {
     },
     #[cfg(feature = "nightly")]
     Titlecase,
+    #[cfg(feature = "string_titlecase")]
+    TitlecaseUnicodeWords,
     Uppercase,
     #[cfg(feature = "string_pad")]
     ZFill,
@@ -156,6 +173,8 @@ impl Display for StringFunction {
             ExtractAll => "extract_all",
             #[cfg(feature = "extract_groups")]
             ExtractGroups { .. } => "extract_groups",
+            #[cfg(feature = "extract_groups")]
+            ExtractAllGroups { .. } => "extract_all_groups",
             #[cfg(feature = "string_to_integer")]
             ToInteger { .. } => "to_integer",
             #[cfg(feature = "regex")]
@@ -179,6 +198,11 @@ impl Display for StringFunction {
             Normalize { .. } => "normalize",
             #[cfg(feature = "string_reverse")]
             Reverse => "reverse",
+            #[cfg(feature = "string_word_count")]
+            WordCount => "word_count",
+            #[cfg(feature = "string_tokenize")]
+            Tokenize { .. } => "tokenize",
+            TruncateBytes { .. } => "truncate_bytes",
             #[cfg(feature = "string_encoding")]
             HexEncode => "hex_encode",
             #[cfg(feature = "binary_encoding")]
@@ -223,6 +247,8 @@ impl Display for StringFunction {
             },
             #[cfg(feature = "nightly")]
             Titlecase => "to_titlecase",
+            #[cfg(feature = "string_titlecase")]
+            TitlecaseUnicodeWords => "to_titlecase_unicode_words",
             #[cfg(feature = "dtype-decimal")]
             ToDecimal { .. } => "to_decimal",
             Uppercase => "to_uppercase",
@@ -12,8 +12,13 @@ pub enum StructFunction {
     JsonEncode,
     SelectFields(Selector),
     MapFieldNames(PlanCallback<PlSmallStr, PlSmallStr>),
+    /// Replaced by a `with_fields` over all fields of the input during expression expansion.
+    #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(skip))]
+    ApplyFields(FieldsExprMapper),
 }
 
+pub type FieldsExprMapper = SpecialEq<Arc<dyn Fn(&str, Expr) -> Expr + Send + Sync>>;
+
 impl Display for StructFunction {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         use StructFunction::*;
@@ -26,6 +31,7 @@ impl Display for StructFunction {
             JsonEncode => write!(f, "struct.to_json"),
             SelectFields(_) => write!(f, "struct.field"),
             MapFieldNames(_) => write!(f, "map_field_names"),
+            ApplyFields(_) => write!(f, "struct.apply_fields"),
         }
     }
 }
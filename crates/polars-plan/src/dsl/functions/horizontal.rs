@@ -107,10 +107,16 @@ where
 
 /// Create a new column with the bitwise-and of the elements in each row.
 ///
+/// Nulls follow Kleene logic: the result is null if it depends on a null, so a `false` in the
+/// row still gives `false`. If `ignore_nulls` is set, nulls are treated as `true` instead.
+///
 /// The name of the resulting column will be "all"; use [`alias`](Expr::alias) to choose a different name.
-pub fn all_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
-    let exprs = exprs.as_ref().to_vec();
+pub fn all_horizontal<E: AsRef<[Expr]>>(exprs: E, ignore_nulls: bool) -> PolarsResult<Expr> {
+    let mut exprs = exprs.as_ref().to_vec();
     polars_ensure!(!exprs.is_empty(), ComputeError: "cannot return empty fold because the number of output rows is unknown");
+    if ignore_nulls {
+        exprs = exprs.into_iter().map(|e| e.fill_null(lit(true))).collect();
+    }
     // This will be reduced to `expr & expr` during conversion to IR.
     Ok(Expr::n_ary(
         FunctionExpr::Boolean(BooleanFunction::AllHorizontal),
@@ -120,10 +126,16 @@ pub fn all_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
 
 /// Create a new column with the bitwise-or of the elements in each row.
 ///
+/// Nulls follow Kleene logic: the result is null if it depends on a null, so a `true` in the
+/// row still gives `true`. If `ignore_nulls` is set, nulls are treated as `false` instead.
+///
 /// The name of the resulting column will be "any"; use [`alias`](Expr::alias) to choose a different name.
-pub fn any_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
-    let exprs = exprs.as_ref().to_vec();
+pub fn any_horizontal<E: AsRef<[Expr]>>(exprs: E, ignore_nulls: bool) -> PolarsResult<Expr> {
+    let mut exprs = exprs.as_ref().to_vec();
     polars_ensure!(!exprs.is_empty(), ComputeError: "cannot return empty fold because the number of output rows is unknown");
+    if ignore_nulls {
+        exprs = exprs.into_iter().map(|e| e.fill_null(lit(false))).collect();
+    }
     // This will be reduced to `expr | expr` during conversion to IR.
     Ok(Expr::n_ary(
         FunctionExpr::Boolean(BooleanFunction::AnyHorizontal),
@@ -171,6 +171,36 @@ impl ListNameSpace {
         }))
     }
 
+    /// Compute the cumulative sum of every sublist.
+    #[cfg(feature = "cum_agg")]
+    pub fn cum_sum(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ListExpr(ListFunction::CumSum))
+    }
+
+    /// Compute the cumulative product of every sublist.
+    #[cfg(feature = "cum_agg")]
+    pub fn cum_prod(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ListExpr(ListFunction::CumProd))
+    }
+
+    /// Flatten up to `n` levels of nesting within every sublist.
+    ///
+    /// Stops early once the inner type is no longer a list, so `usize::MAX` flattens every
+    /// level and `0` is a no-op.
+    pub fn flatten_n(self, n: usize) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ListExpr(ListFunction::FlattenN(n)))
+    }
+
+    /// Split every sublist into consecutive sublists of length `n`, the last one may be
+    /// shorter. This is the inverse of flattening one level with [`ListNameSpace::flatten_n`].
+    pub fn chunk_every(self, n: Expr) -> Expr {
+        self.0
+            .map_binary(FunctionExpr::ListExpr(ListFunction::ChunkEvery), n)
+    }
+
     /// Shift every sublist.
     pub fn shift(self, periods: Expr) -> Expr {
         self.0
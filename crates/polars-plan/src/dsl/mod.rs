@@ -141,6 +141,24 @@ impl Expr {
         Expr::Alias(Arc::new(self), name.into())
     }
 
+    /// Apply `f` to this expression.
+    ///
+    /// This allows reusable transformations to be chained like any other method.
+    pub fn pipe<F>(self, f: F) -> Expr
+    where
+        F: FnOnce(Expr) -> Expr,
+    {
+        f(self)
+    }
+
+    /// Apply `f` to this expression if `condition` is `true`, otherwise return it unchanged.
+    pub fn pipe_if<F>(self, condition: bool, f: F) -> Expr
+    where
+        F: FnOnce(Expr) -> Expr,
+    {
+        if condition { f(self) } else { self }
+    }
+
     /// Run is_null operation on `Expr`.
     #[allow(clippy::wrong_self_convention)]
     pub fn is_null(self) -> Self {
@@ -678,19 +696,39 @@ impl Expr {
     /// Get an array with the cumulative product computed at every element.
     #[cfg(feature = "cum_agg")]
     pub fn cum_prod(self, reverse: bool) -> Self {
-        self.map_unary(FunctionExpr::CumProd { reverse })
+        self.cum_prod_with_overflow(reverse, OverflowPolicy::Wrapping)
+    }
+
+    /// Get an array with the cumulative product computed at every element, handling integer
+    /// overflow according to `overflow`.
+    ///
+    /// With [`OverflowPolicy::Checked`] and [`OverflowPolicy::Saturating`] integer columns keep
+    /// their type instead of being upcast.
+    #[cfg(feature = "cum_agg")]
+    pub fn cum_prod_with_overflow(self, reverse: bool, overflow: OverflowPolicy) -> Self {
+        self.map_unary(FunctionExpr::CumProd { reverse, overflow })
     }
 
     /// Get an array with the cumulative min computed at every element.
+    ///
+    /// If `skip_nulls` is `false`, a null makes all values after it in scan order null as well.
     #[cfg(feature = "cum_agg")]
-    pub fn cum_min(self, reverse: bool) -> Self {
-        self.map_unary(FunctionExpr::CumMin { reverse })
+    pub fn cum_min(self, reverse: bool, skip_nulls: bool) -> Self {
+        self.map_unary(FunctionExpr::CumMin {
+            reverse,
+            skip_nulls,
+        })
     }
 
     /// Get an array with the cumulative max computed at every element.
+    ///
+    /// If `skip_nulls` is `false`, a null makes all values after it in scan order null as well.
     #[cfg(feature = "cum_agg")]
-    pub fn cum_max(self, reverse: bool) -> Self {
-        self.map_unary(FunctionExpr::CumMax { reverse })
+    pub fn cum_max(self, reverse: bool, skip_nulls: bool) -> Self {
+        self.map_unary(FunctionExpr::CumMax {
+            reverse,
+            skip_nulls,
+        })
     }
 
     /// Get the product aggregation of an expression.
@@ -747,6 +785,15 @@ impl Expr {
         )
     }
 
+    /// Clip every value to the bounds found at the same position in `lower` and `upper`.
+    ///
+    /// Unlike [`Expr::clip`], the bounds are not broadcast: both must have the same length as
+    /// `self`. A null bound leaves that side unbounded.
+    #[cfg(feature = "round_series")]
+    pub fn clip_by(self, lower: Expr, upper: Expr) -> Self {
+        self.map_ternary(FunctionExpr::ClipBy, lower, upper)
+    }
+
     /// Clip underlying values to a set boundary.
     #[cfg(feature = "round_series")]
     pub fn clip_max(self, max: Expr) -> Self {
@@ -777,6 +824,14 @@ impl Expr {
         self.map_unary(FunctionExpr::Abs)
     }
 
+    /// Compute the absolute difference `|self - other|` without an intermediate result.
+    ///
+    /// Integer results saturate instead of wrapping around.
+    #[cfg(feature = "abs")]
+    pub fn abs_diff(self, other: Expr) -> Self {
+        self.map_binary(FunctionExpr::AbsDiff, other)
+    }
+
     /// Apply window function over a subgroup.
     /// This is similar to a group_by + aggregation + self join.
     /// Or similar to [window functions in Postgres](https://www.postgresql.org/docs/9.1/tutorial-window.html).
@@ -1551,6 +1606,50 @@ impl Expr {
         self.map_unary(BooleanFunction::HasNulls)
     }
 
+    /// Returns whether the values never decrease.
+    ///
+    /// If `strict` is `true`, any null makes the column non-monotonic,
+    /// otherwise only the non-null values are compared.
+    pub fn is_monotonic_increasing(self, strict: bool) -> Self {
+        self.map_unary(BooleanFunction::IsMonotonic {
+            descending: false,
+            ignore_nulls: !strict,
+        })
+    }
+
+    /// Returns whether the values never increase.
+    ///
+    /// If `strict` is `true`, any null makes the column non-monotonic,
+    /// otherwise only the non-null values are compared.
+    pub fn is_monotonic_decreasing(self, strict: bool) -> Self {
+        self.map_unary(BooleanFunction::IsMonotonic {
+            descending: true,
+            ignore_nulls: !strict,
+        })
+    }
+
+    /// Returns whether the values never decrease, comparing nulls as values.
+    ///
+    /// If `null_equal` is `true`, nulls equal each other and are smaller than
+    /// any other value. Otherwise any null makes the column non-monotonic.
+    pub fn is_monotonic_increasing_by(self, null_equal: bool) -> Self {
+        self.map_unary(BooleanFunction::IsMonotonicBy {
+            descending: false,
+            null_equal,
+        })
+    }
+
+    /// Returns whether the values never increase, comparing nulls as values.
+    ///
+    /// If `null_equal` is `true`, nulls equal each other and are smaller than
+    /// any other value. Otherwise any null makes the column non-monotonic.
+    pub fn is_monotonic_decreasing_by(self, null_equal: bool) -> Self {
+        self.map_unary(BooleanFunction::IsMonotonicBy {
+            descending: true,
+            null_equal,
+        })
+    }
+
     #[cfg(feature = "dtype-struct")]
     /// Count all unique values and create a struct mapping value to count.
     /// (Note that it is better to turn parallel off in the aggregation context).
@@ -1596,6 +1695,27 @@ impl Expr {
     pub fn entropy(self, base: f64, normalize: bool) -> Self {
         self.map_unary(FunctionExpr::Entropy { base, normalize })
     }
+
+    #[cfg(feature = "tdigest")]
+    /// Aggregate the values into a serialized t-digest sketch for approximate quantiles.
+    ///
+    /// The sketch is a `Binary` scalar that can be combined with [`Expr::tdigest_merge`] and
+    /// queried with [`Expr::tdigest_quantile`].
+    pub fn tdigest(self, compression: f64) -> Self {
+        self.map_unary(FunctionExpr::TDigest { compression })
+    }
+
+    #[cfg(feature = "tdigest")]
+    /// Merge the t-digest sketches created by [`Expr::tdigest`] into a single sketch.
+    pub fn tdigest_merge(self) -> Self {
+        self.map_unary(FunctionExpr::TDigestMerge)
+    }
+
+    #[cfg(feature = "tdigest")]
+    /// Estimate the `quantile` of every t-digest sketch created by [`Expr::tdigest`].
+    pub fn tdigest_quantile(self, quantile: f64) -> Self {
+        self.map_unary(FunctionExpr::TDigestQuantile { quantile })
+    }
     /// Get the null count of the column/group.
     pub fn null_count(self) -> Expr {
         self.map_unary(FunctionExpr::NullCount)
@@ -1625,6 +1745,18 @@ impl Expr {
         self.map_unary(FunctionExpr::GatherEvery { n, offset })
     }
 
+    /// Like [`Expr::gather_every`], but `n` and `offset` are scalar expressions that can be
+    /// computed from other columns.
+    pub fn gather_every_by(self, n: Expr, offset: Expr) -> Expr {
+        self.map_ternary(FunctionExpr::GatherEveryBy, n, offset)
+    }
+
+    /// Take a systematic sample of `n` elements: every `len / n`-th element, starting at
+    /// `offset`. See [`Series::sample_systematic`].
+    pub fn sample_systematic(self, n: Expr, offset: Expr) -> Expr {
+        self.map_ternary(FunctionExpr::SampleSystematic, n, offset)
+    }
+
     #[cfg(feature = "reinterpret")]
     pub fn reinterpret(self, signed: Option<bool>, dtype: Option<DataType>) -> Expr {
         self.map_unary(FunctionExpr::Reinterpret(signed, dtype))
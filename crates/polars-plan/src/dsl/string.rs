@@ -160,33 +160,28 @@ impl StringNameSpace {
     pub fn extract_groups(self, pat: &str) -> PolarsResult<Expr> {
         // regex will be compiled twice, because it doesn't support serde
         // and we need to compile it here to determine the output datatype
-
-        use polars_utils::format_pl_smallstr;
-        let reg = polars_utils::regex_cache::compile_regex(pat)?;
-        let names = reg
-            .capture_names()
-            .enumerate()
-            .skip(1)
-            .map(|(idx, opt_name)| {
-                opt_name
-                    .map(PlSmallStr::from_str)
-                    .unwrap_or_else(|| format_pl_smallstr!("{idx}"))
-            })
-            .collect::<Vec<_>>();
-
-        let dtype = DataType::Struct(
-            names
-                .iter()
-                .map(|name| Field::new(name.clone(), DataType::String))
-                .collect(),
-        );
-
+        let dtype = capture_groups_dtype(pat)?;
         Ok(self.0.map_unary(StringFunction::ExtractGroups {
             dtype,
             pat: pat.into(),
         }))
     }
 
+    #[cfg(feature = "extract_groups")]
+    /// Extract the capture groups of every match of a regex pattern, as a list with a struct
+    /// per match. Groups are named like in [`StringNameSpace::extract_groups`].
+    pub fn extract_all_groups(self, pat: &str) -> PolarsResult<Expr> {
+        let dtype = capture_groups_dtype(pat)?;
+        polars_ensure!(
+            matches!(&dtype, DataType::Struct(fields) if !fields.is_empty()),
+            InvalidOperation: "`extract_all_groups` expects a pattern with at least one capture group"
+        );
+        Ok(self.0.map_unary(StringFunction::ExtractAllGroups {
+            dtype: DataType::List(Box::new(dtype)),
+            pat: pat.into(),
+        }))
+    }
+
     /// Pad the start of the string until it reaches the given length.
     ///
     /// Padding is done using the specified `fill_char`.
@@ -420,6 +415,32 @@ impl StringNameSpace {
         self.0.map_unary(StringFunction::Reverse)
     }
 
+    #[cfg(feature = "string_word_count")]
+    /// Count the words in each string, using the Unicode word boundaries of UAX #29.
+    pub fn word_count(self) -> Expr {
+        self.0.map_unary(StringFunction::WordCount)
+    }
+
+    #[cfg(feature = "string_tokenize")]
+    /// Split each string into a list of tokens, using the Unicode word boundaries of UAX #29.
+    ///
+    /// Whitespace is never part of the output. If `words_only` is set, punctuation is dropped
+    /// as well and only the words are kept.
+    pub fn tokenize(self, words_only: bool) -> Expr {
+        self.0.map_unary(StringFunction::Tokenize { words_only })
+    }
+
+    /// Truncate each string to at most `n` bytes without splitting a UTF-8 character.
+    ///
+    /// Strings that are cut end with `ellipsis`, which counts towards the `n` bytes. If
+    /// `ellipsis` is longer than `n` bytes, it is left out.
+    pub fn truncate_bytes(self, n: usize, ellipsis: Option<&str>) -> Expr {
+        self.0.map_unary(StringFunction::TruncateBytes {
+            n,
+            ellipsis: ellipsis.map(PlSmallStr::from_str),
+        })
+    }
+
     /// Remove leading and trailing characters, or whitespace if matches is None.
     pub fn strip_chars(self, matches: Expr) -> Expr {
         self.0.map_binary(StringFunction::StripChars, matches)
@@ -461,6 +482,13 @@ impl StringNameSpace {
         self.0.map_unary(StringFunction::Titlecase)
     }
 
+    /// Convert all characters to titlecase, delimiting the words by the Unicode word
+    /// boundaries of UAX #29.
+    #[cfg(feature = "string_titlecase")]
+    pub fn to_titlecase_unicode_words(self) -> Expr {
+        self.0.map_unary(StringFunction::TitlecaseUnicodeWords)
+    }
+
     #[cfg(feature = "string_to_integer")]
     /// Parse string in base radix into decimal.
     /// The resulting dtype is `dtype`
@@ -522,3 +550,22 @@ impl StringNameSpace {
         self.0.map_unary(StringFunction::EscapeRegex)
     }
 }
+
+/// Struct with a String field per capture group of `pat`, named after the group or its index.
+#[cfg(feature = "extract_groups")]
+fn capture_groups_dtype(pat: &str) -> PolarsResult<DataType> {
+    use polars_utils::format_pl_smallstr;
+    let reg = polars_utils::regex_cache::compile_regex(pat)?;
+    let fields = reg
+        .capture_names()
+        .enumerate()
+        .skip(1)
+        .map(|(idx, opt_name)| {
+            let name = opt_name
+                .map(PlSmallStr::from_str)
+                .unwrap_or_else(|| format_pl_smallstr!("{idx}"));
+            Field::new(name, DataType::String)
+        })
+        .collect();
+    Ok(DataType::Struct(fields))
+}
@@ -90,4 +90,19 @@ impl StructNameSpace {
             evaluation: fields,
         }
     }
+
+    /// Transform every field of the [`StructChunked`] with `f`.
+    ///
+    /// `f` is called with the name of every field and an expression referencing that field,
+    /// the results replace the original fields. The fields are resolved from the input schema
+    /// when the plan is converted.
+    pub fn apply_fields<F>(self, f: F) -> Expr
+    where
+        F: Fn(&str, Expr) -> Expr + Send + Sync + 'static,
+    {
+        self.0
+            .map_unary(FunctionExpr::StructExpr(StructFunction::ApplyFields(
+                SpecialEq::new(Arc::new(f)),
+            )))
+    }
 }
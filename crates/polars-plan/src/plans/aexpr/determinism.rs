@@ -102,6 +102,8 @@ fn is_inherently_nondeterministic_fn(f: &IRFunctionExpr) -> bool {
         F::Business(_) => false,
         #[cfg(feature = "abs")]
         F::Abs => false,
+        #[cfg(feature = "abs")]
+        F::AbsDiff => false,
         F::Negate => false,
         #[cfg(feature = "hist")]
         F::Hist { .. } => false,
@@ -161,7 +163,7 @@ fn is_inherently_nondeterministic_fn(f: &IRFunctionExpr) -> bool {
         F::Rank { .. } => false,
         F::Repeat => false,
         #[cfg(feature = "round_series")]
-        F::Clip { .. } => false,
+        F::Clip { .. } | F::ClipBy => false,
         #[cfg(feature = "dtype-struct")]
         F::AsStruct => false,
         #[cfg(feature = "top_k")]
@@ -194,6 +196,8 @@ fn is_inherently_nondeterministic_fn(f: &IRFunctionExpr) -> bool {
         F::Entropy { .. } => false,
         #[cfg(feature = "log")]
         F::Log | F::Log1p | F::Exp => false,
+        #[cfg(feature = "tdigest")]
+        F::TDigest { .. } | F::TDigestMerge | F::TDigestQuantile { .. } => false,
         F::Unique(_) => false,
         #[cfg(feature = "round_series")]
         F::Round { .. } | F::RoundSF { .. } | F::Truncate { .. } | F::Floor | F::Ceil => false,
@@ -218,7 +222,7 @@ fn is_inherently_nondeterministic_fn(f: &IRFunctionExpr) -> bool {
         F::EwmMeanBy { .. } => false,
         #[cfg(feature = "replace")]
         F::Replace | F::ReplaceStrict { .. } => false,
-        F::GatherEvery { .. } => false,
+        F::GatherEvery { .. } | F::GatherEveryBy | F::SampleSystematic => false,
         #[cfg(feature = "reinterpret")]
         F::Reinterpret(_) => false,
         F::ExtendConstant => false,
@@ -324,6 +328,9 @@ fn is_inherently_nondeterministic_list_fn(f: &IRListFunction) -> bool {
         L::CountMatches => false,
         #[cfg(feature = "diff")]
         L::Diff { .. } => false,
+        #[cfg(feature = "cum_agg")]
+        L::CumSum | L::CumProd => false,
+        L::FlattenN(_) | L::ChunkEvery => false,
         #[cfg(feature = "list_sets")]
         L::SetOperation(_) => false,
         #[cfg(feature = "dtype-array")]
@@ -17,6 +17,14 @@ pub enum IRBooleanFunction {
         ignore_nulls: bool,
     },
     HasNulls,
+    IsMonotonic {
+        descending: bool,
+        ignore_nulls: bool,
+    },
+    IsMonotonicBy {
+        descending: bool,
+        null_equal: bool,
+    },
     IsNull,
     IsNotNull,
     IsFinite,
@@ -74,6 +82,7 @@ impl IRBooleanFunction {
             B::Any { .. } | B::All { .. } | B::IsEmpty { .. } | B::HasNulls => {
                 FunctionOptions::aggregation().flag(FunctionFlags::NON_ORDER_OBSERVING)
             },
+            B::IsMonotonic { .. } | B::IsMonotonicBy { .. } => FunctionOptions::aggregation(),
             B::IsNull | B::IsNotNull => FunctionOptions::elementwise(),
             B::IsFinite | B::IsInfinite | B::IsNan | B::IsNotNan => FunctionOptions::elementwise()
                 .with_flags(|f| f | FunctionFlags::PRESERVES_NULL_FIRST_INPUT),
@@ -133,6 +142,18 @@ impl Display for IRBooleanFunction {
             } => "is_empty",
             IsEmpty { ignore_nulls: true } => "is_empty_ignore_nulls",
             HasNulls => "has_nulls",
+            IsMonotonic {
+                descending: false, ..
+            } => "is_monotonic_increasing",
+            IsMonotonic {
+                descending: true, ..
+            } => "is_monotonic_decreasing",
+            IsMonotonicBy {
+                descending: false, ..
+            } => "is_monotonic_increasing_by",
+            IsMonotonicBy {
+                descending: true, ..
+            } => "is_monotonic_decreasing_by",
             IsNull => "is_null",
             IsNotNull => "is_not_null",
             IsFinite => "is_finite",
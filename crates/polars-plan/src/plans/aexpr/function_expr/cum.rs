@@ -30,7 +30,10 @@ pub(super) mod dtypes {
         }
     }
 
-    pub fn cum_prod(dt: &DataType) -> DataType {
+    pub fn cum_prod(dt: &DataType, overflow: OverflowPolicy) -> DataType {
+        if overflow != OverflowPolicy::Wrapping && dt.is_integer() {
+            return dt.clone();
+        }
         match dt {
             Boolean => Int64,
             UInt64 => UInt64,
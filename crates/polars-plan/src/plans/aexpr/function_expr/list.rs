@@ -43,6 +43,12 @@ pub enum IRListFunction {
         n: i64,
         null_behavior: NullBehavior,
     },
+    #[cfg(feature = "cum_agg")]
+    CumSum,
+    #[cfg(feature = "cum_agg")]
+    CumProd,
+    FlattenN(usize),
+    ChunkEvery,
     Sort(SortOptions),
     #[cfg(feature = "list_sets")]
     SetOperation(SetOperation),
@@ -116,6 +122,20 @@ impl IRListFunction {
 
                 Ok(DataType::List(Box::new(inner_dt)))
             }),
+            #[cfg(feature = "cum_agg")]
+            CumSum => mapper.ensure_is_list()?.map_dtype(|dt| {
+                DataType::List(Box::new(list_cum_sum_dtype(dt.inner_dtype().unwrap())))
+            }),
+            #[cfg(feature = "cum_agg")]
+            CumProd => mapper.ensure_is_list()?.map_dtype(|dt| {
+                DataType::List(Box::new(list_cum_prod_dtype(dt.inner_dtype().unwrap())))
+            }),
+            FlattenN(n) => mapper
+                .ensure_is_list()?
+                .map_dtype(|dt| list_flatten_n_dtype(dt, *n)),
+            ChunkEvery => mapper
+                .ensure_is_list()?
+                .map_dtype(|dt| DataType::List(Box::new(dt.clone()))),
             Sort(_) => mapper.ensure_is_list()?.with_same_dtype(),
             Length => mapper.ensure_is_list()?.with_dtype(IDX_DTYPE),
             #[cfg(feature = "list_sets")]
@@ -177,6 +197,9 @@ impl IRListFunction {
                 .with_flags(|f| f & !FunctionFlags::RETURNS_SCALAR),
             #[cfg(feature = "diff")]
             L::Diff { .. } => FunctionOptions::elementwise(),
+            #[cfg(feature = "cum_agg")]
+            L::CumSum | L::CumProd => FunctionOptions::elementwise(),
+            L::FlattenN(_) | L::ChunkEvery => FunctionOptions::elementwise(),
             #[cfg(feature = "list_drop_nulls")]
             L::DropNulls => FunctionOptions::elementwise(),
             #[cfg(feature = "list_count")]
@@ -204,6 +227,39 @@ impl IRListFunction {
     }
 }
 
+/// Mirrors the upcasting of `polars_ops::series::cum_sum`.
+#[cfg(feature = "cum_agg")]
+fn list_cum_sum_dtype(inner: &DataType) -> DataType {
+    use DataType::*;
+    match inner {
+        Boolean => UInt32,
+        Int8 | UInt8 | Int16 | UInt16 => Int64,
+        dt => dt.clone(),
+    }
+}
+
+/// Mirrors the upcasting of `polars_ops::series::cum_prod`.
+#[cfg(feature = "cum_agg")]
+fn list_cum_prod_dtype(inner: &DataType) -> DataType {
+    use DataType::*;
+    match inner {
+        Boolean | Int8 | UInt8 | Int16 | UInt16 | Int32 | UInt32 => Int64,
+        dt => dt.clone(),
+    }
+}
+
+/// Strip up to `n` levels of list nesting below the outer list.
+fn list_flatten_n_dtype(dt: &DataType, n: usize) -> DataType {
+    let mut inner = dt.inner_dtype().unwrap();
+    for _ in 0..n {
+        match inner {
+            DataType::List(next) => inner = next.as_ref(),
+            _ => break,
+        }
+    }
+    DataType::List(Box::new(inner.clone()))
+}
+
 #[cfg(feature = "dtype-array")]
 fn map_list_dtype_to_array_dtype(datatype: &DataType, width: usize) -> PolarsResult<DataType> {
     if let DataType::List(inner) = datatype {
@@ -251,6 +307,12 @@ impl Display for IRListFunction {
             ArgMax => "arg_max",
             #[cfg(feature = "diff")]
             Diff { .. } => "diff",
+            #[cfg(feature = "cum_agg")]
+            CumSum => "cum_sum",
+            #[cfg(feature = "cum_agg")]
+            CumProd => "cum_prod",
+            FlattenN(_) => "flatten_n",
+            ChunkEvery => "chunk_every",
             Length => "length",
             Sort(_) => "sort",
             #[cfg(feature = "list_sets")]
@@ -114,6 +114,8 @@ pub enum IRFunctionExpr {
     Business(IRBusinessFunction),
     #[cfg(feature = "abs")]
     Abs,
+    #[cfg(feature = "abs")]
+    AbsDiff,
     Negate,
     #[cfg(feature = "hist")]
     Hist {
@@ -195,6 +197,8 @@ pub enum IRFunctionExpr {
         has_min: bool,
         has_max: bool,
     },
+    #[cfg(feature = "round_series")]
+    ClipBy,
     #[cfg(feature = "dtype-struct")]
     AsStruct,
     #[cfg(feature = "top_k")]
@@ -216,14 +220,17 @@ pub enum IRFunctionExpr {
     #[cfg(feature = "cum_agg")]
     CumProd {
         reverse: bool,
+        overflow: OverflowPolicy,
     },
     #[cfg(feature = "cum_agg")]
     CumMin {
         reverse: bool,
+        skip_nulls: bool,
     },
     #[cfg(feature = "cum_agg")]
     CumMax {
         reverse: bool,
+        skip_nulls: bool,
     },
     Reverse,
     #[cfg(feature = "dtype-struct")]
@@ -257,6 +264,16 @@ pub enum IRFunctionExpr {
     Log1p,
     #[cfg(feature = "log")]
     Exp,
+    #[cfg(feature = "tdigest")]
+    TDigest {
+        compression: f64,
+    },
+    #[cfg(feature = "tdigest")]
+    TDigestMerge,
+    #[cfg(feature = "tdigest")]
+    TDigestQuantile {
+        quantile: f64,
+    },
     Unique(/* maintain_order */ bool),
     #[cfg(feature = "round_series")]
     Round {
@@ -385,6 +402,8 @@ pub enum IRFunctionExpr {
         n: usize,
         offset: usize,
     },
+    GatherEveryBy,
+    SampleSystematic,
     #[cfg(feature = "reinterpret")]
     Reinterpret(DataType),
     ExtendConstant,
@@ -518,6 +537,8 @@ impl Hash for IRFunctionExpr {
             },
             #[cfg(feature = "abs")]
             Abs => {},
+            #[cfg(feature = "abs")]
+            AbsDiff => {},
             Negate => {},
             NullCount => {},
             #[cfg(feature = "arg_where")]
@@ -562,6 +583,8 @@ impl Hash for IRFunctionExpr {
                 has_min.hash(state);
                 has_max.hash(state);
             },
+            #[cfg(feature = "round_series")]
+            ClipBy => {},
             #[cfg(feature = "top_k")]
             TopK { descending } => descending.hash(state),
             #[cfg(feature = "cum_agg")]
@@ -569,11 +592,26 @@ impl Hash for IRFunctionExpr {
             #[cfg(feature = "cum_agg")]
             CumSum { reverse } => reverse.hash(state),
             #[cfg(feature = "cum_agg")]
-            CumProd { reverse } => reverse.hash(state),
+            CumProd { reverse, overflow } => {
+                reverse.hash(state);
+                overflow.hash(state);
+            },
             #[cfg(feature = "cum_agg")]
-            CumMin { reverse } => reverse.hash(state),
+            CumMin {
+                reverse,
+                skip_nulls,
+            } => {
+                reverse.hash(state);
+                skip_nulls.hash(state);
+            },
             #[cfg(feature = "cum_agg")]
-            CumMax { reverse } => reverse.hash(state),
+            CumMax {
+                reverse,
+                skip_nulls,
+            } => {
+                reverse.hash(state);
+                skip_nulls.hash(state);
+            },
             #[cfg(feature = "dtype-struct")]
             ValueCounts {
                 sort,
@@ -604,6 +642,12 @@ impl Hash for IRFunctionExpr {
             Log1p => {},
             #[cfg(feature = "log")]
             Exp => {},
+            #[cfg(feature = "tdigest")]
+            TDigest { compression } => compression.to_bits().hash(state),
+            #[cfg(feature = "tdigest")]
+            TDigestMerge => {},
+            #[cfg(feature = "tdigest")]
+            TDigestQuantile { quantile } => quantile.to_bits().hash(state),
             Unique(a) => a.hash(state),
             #[cfg(feature = "round_series")]
             Round { decimals, mode } => {
@@ -685,6 +729,8 @@ impl Hash for IRFunctionExpr {
             ReplaceStrict { return_dtype } => return_dtype.hash(state),
             FillNullWithStrategy(strategy) => strategy.hash(state),
             GatherEvery { n, offset } => (n, offset).hash(state),
+            GatherEveryBy => {},
+            SampleSystematic => {},
             #[cfg(feature = "reinterpret")]
             Reinterpret(dtype) => dtype.hash(state),
             ExtendConstant => {},
@@ -735,6 +781,8 @@ impl Display for IRFunctionExpr {
             Business(func) => return write!(f, "{func}"),
             #[cfg(feature = "abs")]
             Abs => "abs",
+            #[cfg(feature = "abs")]
+            AbsDiff => "abs_diff",
             Negate => "negate",
             NullCount => "null_count",
             Pow(func) => return write!(f, "{func}"),
@@ -793,6 +841,8 @@ impl Display for IRFunctionExpr {
                 (true, false) => "clip_min",
                 _ => unreachable!(),
             },
+            #[cfg(feature = "round_series")]
+            ClipBy => "clip_by",
             #[cfg(feature = "dtype-struct")]
             AsStruct => "as_struct",
             #[cfg(feature = "top_k")]
@@ -840,6 +890,12 @@ impl Display for IRFunctionExpr {
             Log1p => "log1p",
             #[cfg(feature = "log")]
             Exp => "exp",
+            #[cfg(feature = "tdigest")]
+            TDigest { .. } => "tdigest",
+            #[cfg(feature = "tdigest")]
+            TDigestMerge => "tdigest_merge",
+            #[cfg(feature = "tdigest")]
+            TDigestQuantile { .. } => "tdigest_quantile",
             Unique(stable) => {
                 if *stable {
                     "unique_stable"
@@ -911,7 +967,8 @@ impl Display for IRFunctionExpr {
             #[cfg(feature = "replace")]
             ReplaceStrict { .. } => "replace_strict",
             FillNullWithStrategy(_) => "fill_null_with_strategy",
-            GatherEvery { .. } => "gather_every",
+            GatherEvery { .. } | GatherEveryBy => "gather_every",
+            SampleSystematic => "sample_systematic",
             #[cfg(feature = "reinterpret")]
             Reinterpret(_) => "reinterpret",
             ExtendConstant => "extend_constant",
@@ -1035,6 +1092,8 @@ impl IRFunctionExpr {
             F::Range(e) => e.function_options(),
             #[cfg(feature = "abs")]
             F::Abs => FunctionOptions::elementwise(),
+            #[cfg(feature = "abs")]
+            F::AbsDiff => FunctionOptions::elementwise(),
             F::Negate => FunctionOptions::elementwise(),
             #[cfg(feature = "hist")]
             F::Hist { .. } => FunctionOptions::groupwise(),
@@ -1115,7 +1174,7 @@ impl IRFunctionExpr {
                 FunctionOptions::groupwise().with_flags(|f| f | FunctionFlags::ALLOW_RENAME)
             },
             #[cfg(feature = "round_series")]
-            F::Clip { .. } => FunctionOptions::elementwise(),
+            F::Clip { .. } | F::ClipBy => FunctionOptions::elementwise(),
             #[cfg(feature = "dtype-struct")]
             F::AsStruct => FunctionOptions::elementwise().with_flags(|f| {
                 f | FunctionFlags::PASS_NAME_TO_APPLY | FunctionFlags::INPUT_WILDCARD_EXPANSION
@@ -1164,6 +1223,10 @@ impl IRFunctionExpr {
             F::Entropy { .. } => {
                 FunctionOptions::aggregation().flag(FunctionFlags::NON_ORDER_OBSERVING)
             },
+            #[cfg(feature = "tdigest")]
+            F::TDigest { .. } | F::TDigestMerge => FunctionOptions::aggregation(),
+            #[cfg(feature = "tdigest")]
+            F::TDigestQuantile { .. } => FunctionOptions::elementwise(),
             F::Unique(maintain_order) => FunctionOptions::groupwise().with_flags(|f| {
                 let f = f | FunctionFlags::NON_ORDER_PRODUCING;
 
@@ -1255,7 +1318,9 @@ impl IRFunctionExpr {
             F::Replace => FunctionOptions::elementwise(),
             #[cfg(feature = "replace")]
             F::ReplaceStrict { .. } => FunctionOptions::elementwise(),
-            F::GatherEvery { .. } => FunctionOptions::groupwise(),
+            F::GatherEvery { .. } | F::GatherEveryBy | F::SampleSystematic => {
+                FunctionOptions::groupwise()
+            },
             #[cfg(feature = "reinterpret")]
             F::Reinterpret(_) => FunctionOptions::elementwise(),
             F::ExtendConstant => FunctionOptions::groupwise(),
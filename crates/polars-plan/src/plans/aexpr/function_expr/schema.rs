@@ -34,6 +34,8 @@ impl IRFunctionExpr {
             Business(func) => func.get_field(mapper),
             #[cfg(feature = "abs")]
             Abs => mapper.with_same_dtype(),
+            #[cfg(feature = "abs")]
+            AbsDiff => mapper.map_to_supertype(),
             Negate => mapper.with_same_dtype(),
             NullCount => mapper.with_dtype(IDX_DTYPE),
             Pow(pow_function) => match pow_function {
@@ -130,7 +132,8 @@ impl IRFunctionExpr {
             Clip {
                 has_min: _,
                 has_max: _,
-            } => mapper.with_same_dtype(),
+            }
+            | ClipBy => mapper.with_same_dtype(),
             Quantile { method: _ } => mapper.moment_dtype(),
             #[cfg(feature = "mode")]
             Mode { maintain_order: _ } => mapper.with_same_dtype(),
@@ -208,7 +211,7 @@ impl IRFunctionExpr {
             #[cfg(feature = "cum_agg")]
             CumSum { .. } => mapper.map_dtype(cum::dtypes::cum_sum),
             #[cfg(feature = "cum_agg")]
-            CumProd { .. } => mapper.map_dtype(cum::dtypes::cum_prod),
+            CumProd { overflow, .. } => mapper.map_dtype(|dt| cum::dtypes::cum_prod(dt, *overflow)),
             #[cfg(feature = "cum_agg")]
             CumMin { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "cum_agg")]
@@ -274,6 +277,10 @@ impl IRFunctionExpr {
             Entropy { .. } | Log1p | Exp => mapper.map_to_float_dtype(),
             #[cfg(feature = "log")]
             Log => mapper.log_dtype(),
+            #[cfg(feature = "tdigest")]
+            TDigest { .. } | TDigestMerge => mapper.with_dtype(DataType::Binary),
+            #[cfg(feature = "tdigest")]
+            TDigestQuantile { .. } => mapper.with_dtype(DataType::Float64),
             Unique(_) => mapper.with_same_dtype(),
             #[cfg(feature = "round_series")]
             Round { .. } | RoundSF { .. } | Truncate { .. } | Floor | Ceil => {
@@ -437,7 +444,7 @@ impl IRFunctionExpr {
             #[cfg(feature = "replace")]
             ReplaceStrict { return_dtype } => mapper.replace_dtype(return_dtype.clone()),
             FillNullWithStrategy(_) => mapper.with_same_dtype(),
-            GatherEvery { .. } => mapper.with_same_dtype(),
+            GatherEvery { .. } | GatherEveryBy | SampleSystematic => mapper.with_same_dtype(),
             #[cfg(feature = "reinterpret")]
             Reinterpret(dtype) => mapper.with_dtype(dtype.clone()),
             ExtendConstant => mapper.with_same_dtype(),
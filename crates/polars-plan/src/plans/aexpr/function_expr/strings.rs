@@ -41,6 +41,11 @@ pub enum IRStringFunction {
         dtype: DataType,
         pat: PlSmallStr,
     },
+    #[cfg(feature = "extract_groups")]
+    ExtractAllGroups {
+        dtype: DataType,
+        pat: PlSmallStr,
+    },
     #[cfg(feature = "regex")]
     Find {
         literal: bool,
@@ -71,6 +76,16 @@ pub enum IRStringFunction {
     },
     #[cfg(feature = "string_reverse")]
     Reverse,
+    #[cfg(feature = "string_word_count")]
+    WordCount,
+    #[cfg(feature = "string_tokenize")]
+    Tokenize {
+        words_only: bool,
+    },
+    TruncateBytes {
+        n: usize,
+        ellipsis: Option<PlSmallStr>,
+    },
     #[cfg(feature = "string_pad")]
     PadStart {
         fill_char: char,
@@ -118,6 +133,8 @@ pub enum IRStringFunction {
     },
     #[cfg(feature = "nightly")]
     Titlecase,
+    #[cfg(feature = "string_titlecase")]
+    TitlecaseUnicodeWords,
     Uppercase,
     #[cfg(feature = "string_pad")]
     ZFill,
@@ -161,6 +178,8 @@ impl IRStringFunction {
             ExtractAll => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
             #[cfg(feature = "extract_groups")]
             ExtractGroups { dtype, .. } => mapper.with_dtype(dtype.clone()),
+            #[cfg(feature = "extract_groups")]
+            ExtractAllGroups { dtype, .. } => mapper.with_dtype(dtype.clone()),
             #[cfg(feature = "string_to_integer")]
             ToInteger { dtype, .. } => mapper.with_dtype(dtype.clone().unwrap_or(DataType::Int64)),
             #[cfg(feature = "regex")]
@@ -177,6 +196,11 @@ impl IRStringFunction {
             Normalize { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "string_reverse")]
             Reverse => mapper.with_same_dtype(),
+            #[cfg(feature = "string_word_count")]
+            WordCount => mapper.with_dtype(DataType::UInt32),
+            #[cfg(feature = "string_tokenize")]
+            Tokenize { .. } => mapper.with_dtype(DataType::List(DataType::String.into())),
+            TruncateBytes { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "temporal")]
             Strptime(dtype, options) => match dtype {
                 #[cfg(feature = "dtype-datetime")]
@@ -200,6 +224,8 @@ impl IRStringFunction {
             SplitRegex { .. } => mapper.with_dtype(DataType::List(DataType::String.into())),
             #[cfg(feature = "nightly")]
             Titlecase => mapper.with_same_dtype(),
+            #[cfg(feature = "string_titlecase")]
+            TitlecaseUnicodeWords => mapper.with_same_dtype(),
             #[cfg(feature = "dtype-decimal")]
             ToDecimal { scale } => mapper.with_dtype(DataType::Decimal(DEC128_MAX_PREC, *scale)),
             #[cfg(feature = "string_encoding")]
@@ -259,6 +285,8 @@ impl IRStringFunction {
             S::ExtractAll => FunctionOptions::elementwise(),
             #[cfg(feature = "extract_groups")]
             S::ExtractGroups { .. } => FunctionOptions::elementwise(),
+            #[cfg(feature = "extract_groups")]
+            S::ExtractAllGroups { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "string_to_integer")]
             S::ToInteger { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "regex")]
@@ -276,6 +304,11 @@ impl IRStringFunction {
             S::Normalize { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "string_reverse")]
             S::Reverse => FunctionOptions::elementwise(),
+            #[cfg(feature = "string_word_count")]
+            S::WordCount => FunctionOptions::elementwise(),
+            #[cfg(feature = "string_tokenize")]
+            S::Tokenize { .. } => FunctionOptions::elementwise(),
+            S::TruncateBytes { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "temporal")]
             S::Strptime(_, options) if options.format.is_some() => FunctionOptions::elementwise(),
             #[cfg(feature = "temporal")]
@@ -283,6 +316,8 @@ impl IRStringFunction {
             S::Split(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "nightly")]
             S::Titlecase => FunctionOptions::elementwise(),
+            #[cfg(feature = "string_titlecase")]
+            S::TitlecaseUnicodeWords => FunctionOptions::elementwise(),
             #[cfg(feature = "dtype-decimal")]
             S::ToDecimal { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "string_encoding")]
@@ -337,6 +372,8 @@ impl Display for IRStringFunction {
             ExtractAll => "extract_all",
             #[cfg(feature = "extract_groups")]
             ExtractGroups { .. } => "extract_groups",
+            #[cfg(feature = "extract_groups")]
+            ExtractAllGroups { .. } => "extract_all_groups",
             #[cfg(feature = "string_to_integer")]
             ToInteger { .. } => "to_integer",
             #[cfg(feature = "regex")]
@@ -360,6 +397,11 @@ impl Display for IRStringFunction {
             Normalize { .. } => "normalize",
             #[cfg(feature = "string_reverse")]
             Reverse => "reverse",
+            #[cfg(feature = "string_word_count")]
+            WordCount => "word_count",
+            #[cfg(feature = "string_tokenize")]
+            Tokenize { .. } => "tokenize",
+            TruncateBytes { .. } => "truncate_bytes",
             #[cfg(feature = "string_encoding")]
             HexEncode => "hex_encode",
             #[cfg(feature = "binary_encoding")]
@@ -404,6 +446,8 @@ impl Display for IRStringFunction {
             },
             #[cfg(feature = "nightly")]
             Titlecase => "to_titlecase",
+            #[cfg(feature = "string_titlecase")]
+            TitlecaseUnicodeWords => "to_titlecase_unicode_words",
             #[cfg(feature = "dtype-decimal")]
             ToDecimal { .. } => "to_decimal",
             Uppercase => "to_uppercase",
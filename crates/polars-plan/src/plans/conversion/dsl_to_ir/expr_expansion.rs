@@ -243,7 +243,9 @@ fn needs_expansion(expr: &Expr) -> bool {
                     e,
                     Expr::Function {
                         function: FunctionExpr::StructExpr(
-                            StructFunction::SelectFields(_) | StructFunction::FieldByName(_)
+                            StructFunction::SelectFields(_)
+                                | StructFunction::FieldByName(_)
+                                | StructFunction::ApplyFields(_)
                         ),
                         ..
                     }
@@ -656,6 +658,39 @@ fn expand_expression_rec(
                             );
                         }
                     },
+                    #[cfg(feature = "dtype-struct")]
+                    FunctionExpr::StructExpr(StructFunction::ApplyFields(f)) => {
+                        let mut tmp_out = Vec::new();
+                        expand_single(
+                            &input[0],
+                            ignored_selector_columns,
+                            schema,
+                            &mut tmp_out,
+                            opt_flags,
+                            |e| e,
+                        )?;
+                        for e in tmp_out {
+                            let dtype = e.to_field(schema)?.dtype;
+
+                            let DataType::Struct(fields) = dtype else {
+                                polars_bail!(op = "struct.apply_fields", &dtype);
+                            };
+                            let evaluation = fields
+                                .iter()
+                                .map(|fld| {
+                                    let name = fld.name().clone();
+                                    f(name.as_str(), Expr::Field([name.clone()].into())).alias(name)
+                                })
+                                .collect();
+                            expand_expression_rec(
+                                &e.struct_().with_fields(evaluation),
+                                ignored_selector_columns,
+                                schema,
+                                out,
+                                opt_flags,
+                            )?;
+                        }
+                    },
                     _ => {
                         _ = expand_expression_by_combination(
                             input,
@@ -169,6 +169,15 @@ pub(super) fn convert_functions(
                 L::ArgMax => IL::ArgMax,
                 #[cfg(feature = "diff")]
                 L::Diff { n, null_behavior } => IL::Diff { n, null_behavior },
+                #[cfg(feature = "cum_agg")]
+                L::CumSum => IL::CumSum,
+                #[cfg(feature = "cum_agg")]
+                L::CumProd => IL::CumProd,
+                L::FlattenN(n) => IL::FlattenN(n),
+                L::ChunkEvery => {
+                    polars_ensure!(e[1].is_scalar(ctx.arena), ShapeMismatch: "'n' must be a scalar value");
+                    IL::ChunkEvery
+                },
                 L::Sort(sort_options) => IL::Sort(sort_options),
                 #[cfg(feature = "list_sets")]
                 L::SetOperation(set_operation) => IL::SetOperation(set_operation),
@@ -225,6 +234,8 @@ pub(super) fn convert_functions(
                 S::ExtractAll => IS::ExtractAll,
                 #[cfg(feature = "extract_groups")]
                 S::ExtractGroups { dtype, pat } => IS::ExtractGroups { dtype, pat },
+                #[cfg(feature = "extract_groups")]
+                S::ExtractAllGroups { dtype, pat } => IS::ExtractAllGroups { dtype, pat },
                 #[cfg(feature = "regex")]
                 S::Find { literal, strict } => IS::Find { literal, strict },
                 #[cfg(feature = "string_to_integer")]
@@ -242,6 +253,11 @@ pub(super) fn convert_functions(
                 S::Normalize { form } => IS::Normalize { form },
                 #[cfg(feature = "string_reverse")]
                 S::Reverse => IS::Reverse,
+                #[cfg(feature = "string_word_count")]
+                S::WordCount => IS::WordCount,
+                #[cfg(feature = "string_tokenize")]
+                S::Tokenize { words_only } => IS::Tokenize { words_only },
+                S::TruncateBytes { n, ellipsis } => IS::TruncateBytes { n, ellipsis },
                 #[cfg(feature = "string_pad")]
                 S::PadStart { fill_char } => IS::PadStart { fill_char },
                 #[cfg(feature = "string_pad")]
@@ -289,6 +305,8 @@ pub(super) fn convert_functions(
                 S::ToDecimal { scale } => IS::ToDecimal { scale },
                 #[cfg(feature = "nightly")]
                 S::Titlecase => IS::Titlecase,
+                #[cfg(feature = "string_titlecase")]
+                S::TitlecaseUnicodeWords => IS::TitlecaseUnicodeWords,
                 S::Uppercase => IS::Uppercase,
                 #[cfg(feature = "string_pad")]
                 S::ZFill => IS::ZFill,
@@ -339,7 +357,9 @@ pub(super) fn convert_functions(
                 S::RenameFields(pl_small_strs) => IS::RenameFields(pl_small_strs),
                 S::PrefixFields(pl_small_str) => IS::PrefixFields(pl_small_str),
                 S::SuffixFields(pl_small_str) => IS::SuffixFields(pl_small_str),
-                S::SelectFields(_) => unreachable!("handled by expression expansion"),
+                S::SelectFields(_) | S::ApplyFields(_) => {
+                    unreachable!("handled by expression expansion")
+                },
                 #[cfg(feature = "json")]
                 S::JsonEncode => IS::JsonEncode,
                 S::MapFieldNames(f) => IS::MapFieldNames(f),
@@ -440,6 +460,20 @@ pub(super) fn convert_functions(
                 B::All { ignore_nulls } => IB::All { ignore_nulls },
                 B::IsEmpty { ignore_nulls } => IB::IsEmpty { ignore_nulls },
                 B::HasNulls => IB::HasNulls,
+                B::IsMonotonic {
+                    descending,
+                    ignore_nulls,
+                } => IB::IsMonotonic {
+                    descending,
+                    ignore_nulls,
+                },
+                B::IsMonotonicBy {
+                    descending,
+                    null_equal,
+                } => IB::IsMonotonicBy {
+                    descending,
+                    null_equal,
+                },
                 B::IsNull => IB::IsNull,
                 B::IsNotNull => IB::IsNotNull,
                 B::IsFinite => IB::IsFinite,
@@ -544,6 +578,8 @@ pub(super) fn convert_functions(
         }),
         #[cfg(feature = "abs")]
         F::Abs => I::Abs,
+        #[cfg(feature = "abs")]
+        F::AbsDiff => I::AbsDiff,
         F::Negate => I::Negate,
         #[cfg(feature = "hist")]
         F::Hist {
@@ -835,6 +871,8 @@ pub(super) fn convert_functions(
         },
         #[cfg(feature = "round_series")]
         F::Clip { has_min, has_max } => I::Clip { has_min, has_max },
+        #[cfg(feature = "round_series")]
+        F::ClipBy => I::ClipBy,
         #[cfg(feature = "dtype-struct")]
         F::AsStruct => I::AsStruct,
         #[cfg(feature = "top_k")]
@@ -846,11 +884,23 @@ pub(super) fn convert_functions(
         #[cfg(feature = "cum_agg")]
         F::CumSum { reverse } => I::CumSum { reverse },
         #[cfg(feature = "cum_agg")]
-        F::CumProd { reverse } => I::CumProd { reverse },
+        F::CumProd { reverse, overflow } => I::CumProd { reverse, overflow },
         #[cfg(feature = "cum_agg")]
-        F::CumMin { reverse } => I::CumMin { reverse },
+        F::CumMin {
+            reverse,
+            skip_nulls,
+        } => I::CumMin {
+            reverse,
+            skip_nulls,
+        },
         #[cfg(feature = "cum_agg")]
-        F::CumMax { reverse } => I::CumMax { reverse },
+        F::CumMax {
+            reverse,
+            skip_nulls,
+        } => I::CumMax {
+            reverse,
+            skip_nulls,
+        },
         F::Reverse => I::Reverse,
         #[cfg(feature = "dtype-struct")]
         F::ValueCounts {
@@ -888,6 +938,12 @@ pub(super) fn convert_functions(
         F::Log1p => I::Log1p,
         #[cfg(feature = "log")]
         F::Exp => I::Exp,
+        #[cfg(feature = "tdigest")]
+        F::TDigest { compression } => I::TDigest { compression },
+        #[cfg(feature = "tdigest")]
+        F::TDigestMerge => I::TDigestMerge,
+        #[cfg(feature = "tdigest")]
+        F::TDigestQuantile { quantile } => I::TDigestQuantile { quantile },
         F::Unique(v) => I::Unique(v),
         #[cfg(feature = "round_series")]
         F::Round { decimals, mode } => I::Round { decimals, mode },
@@ -1061,6 +1117,16 @@ pub(super) fn convert_functions(
             },
         },
         F::GatherEvery { n, offset } => I::GatherEvery { n, offset },
+        F::GatherEveryBy => {
+            polars_ensure!(&e[1].is_scalar(ctx.arena), ShapeMismatch: "'n' must be a scalar value");
+            polars_ensure!(&e[2].is_scalar(ctx.arena), ShapeMismatch: "'offset' must be a scalar value");
+            I::GatherEveryBy
+        },
+        F::SampleSystematic => {
+            polars_ensure!(&e[1].is_scalar(ctx.arena), ShapeMismatch: "'n' must be a scalar value");
+            polars_ensure!(&e[2].is_scalar(ctx.arena), ShapeMismatch: "'offset' must be a scalar value");
+            I::SampleSystematic
+        },
         #[cfg(feature = "reinterpret")]
         F::Reinterpret(signed, dtype) => {
             let input_dtype = e[0].dtype(ctx.schema, ctx.arena)?;
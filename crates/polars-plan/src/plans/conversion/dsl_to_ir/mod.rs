@@ -33,6 +33,8 @@ mod utils;
 pub use expr_expansion::{expand_expression, is_regex_projection, prepare_projection};
 pub use expr_to_ir::{ExprToIRContext, to_expr_ir};
 use expr_to_ir::{to_expr_ir_materialized_lit, to_expr_irs};
+#[cfg(feature = "parquet")]
+pub use scans::read_parquet_metadata;
 use utils::DslConversionContext;
 
 macro_rules! failed_here {
@@ -561,6 +563,7 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                     .filter(
                         all_horizontal(
                             predicate_names.iter().map(|n| col(n.clone())).collect_vec(),
+                            false,
                         )
                         .unwrap(),
                     )
@@ -1438,10 +1441,14 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                     }
 
                     #[cfg(feature = "csv")]
-                    if let FileWriteFormat::Csv(csv_options) = &options.file_format
-                        && csv_options.check_extension
-                    {
-                        compression_opt = Some(csv_options.compression);
+                    if let FileWriteFormat::Csv(csv_options) = &options.file_format {
+                        if csv_options.check_extension {
+                            compression_opt = Some(csv_options.compression);
+                        }
+                        polars_ensure!(
+                            !csv_options.append || matches!(&options.target, SinkTarget::Path(path) if !path.has_scheme()),
+                            InvalidOperation: "appending is only supported when sinking to a local file"
+                        );
                     }
 
                     #[cfg(feature = "json")]
@@ -1481,6 +1488,12 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                     max_rows_per_file,
                     approximate_bytes_per_file,
                 }) => {
+                    #[cfg(feature = "csv")]
+                    polars_ensure!(
+                        !matches!(&file_format, FileWriteFormat::Csv(csv_options) if csv_options.append),
+                        InvalidOperation: "appending is not supported for partitioned sinks"
+                    );
+
                     let expr_to_ir_cx = &mut ExprToIRContext::new_with_opt_eager(
                         ctxt.expr_arena,
                         &input_schema,
@@ -241,6 +241,7 @@ pub(super) async fn parquet_file_info(
     sources: &ScanSources,
     row_index: Option<&RowIndex>,
     #[allow(unused)] cloud_options: Option<&polars_io::cloud::CloudOptions>,
+    max_footer_size: Option<usize>,
 ) -> PolarsResult<(
     FileInfo,
     Option<FileMetadataRef>,
@@ -259,7 +260,9 @@ pub(super) async fn parquet_file_info(
             let first_path = first_scan_source.as_path().unwrap();
             feature_gated!("cloud", {
                 let mut reader =
-                    ParquetObjectStore::from_uri(first_path.clone(), cloud_options, None).await?;
+                    ParquetObjectStore::from_uri(first_path.clone(), cloud_options, None)
+                        .await?
+                        .with_max_footer_size(max_footer_size);
 
                 (
                     reader.schema().await?,
@@ -269,7 +272,8 @@ pub(super) async fn parquet_file_info(
             })
         } else {
             let memslice = first_scan_source.to_memslice()?;
-            let mut reader = ParquetReader::new(Cursor::new(memslice));
+            let mut reader =
+                ParquetReader::new(Cursor::new(memslice)).with_max_footer_size(max_footer_size);
             (
                 reader.schema()?,
                 reader.num_rows()?,
@@ -319,7 +323,7 @@ pub(super) async fn parquet_file_info(
                 // Each file decoded with its own schema: per-file schemas
                 // may differ in columns, dtypes, or column order.
                 let mut futures = (1..n_sources)
-                    .map(|i| read_parquet_metadata(sources.at(i), cloud_options))
+                    .map(|i| read_parquet_metadata(sources.at(i), cloud_options, max_footer_size))
                     .collect::<FuturesOrdered<_>>();
 
                 // Push slot 0 (satisfying the `metadata_per_source[0] ==
@@ -354,26 +358,33 @@ pub(super) async fn parquet_file_info(
     Ok((file_info, Some(first_metadata), metadata_per_source))
 }
 
-/// Fetch one source's full footer. Used by [`parquet_file_info`] in
-/// `Full` resolve mode.
+/// Fetch one source's full footer, from cloud storage or the local file system. Used by
+/// [`parquet_file_info`] in `Full` resolve mode.
 #[cfg(feature = "parquet")]
-async fn read_parquet_metadata(
+pub async fn read_parquet_metadata(
     source: ScanSourceRef<'_>,
     #[allow(unused)] cloud_options: Option<&polars_io::cloud::CloudOptions>,
+    max_footer_size: Option<usize>,
 ) -> PolarsResult<FileMetadataRef> {
     use polars_core::error::feature_gated;
 
     if source.is_cloud_url() {
         let path = source.as_path().unwrap();
         feature_gated!("cloud", {
-            let mut reader =
-                ParquetObjectStore::from_uri(path.clone(), cloud_options, None).await?;
+            let mut reader = ParquetObjectStore::from_uri(path.clone(), cloud_options, None)
+                .await?
+                .with_max_footer_size(max_footer_size);
             reader.get_metadata().await.cloned()
         })
     } else {
         let memslice = source.to_memslice()?;
         let mut cursor = Cursor::new(memslice);
-        let md = polars_parquet::parquet::read::read_metadata(&mut cursor)?;
+        let md = match max_footer_size {
+            Some(max_footer_size) => {
+                polars_parquet::read::read_metadata_with_limit(&mut cursor, max_footer_size)?
+            },
+            None => polars_parquet::parquet::read::read_metadata(&mut cursor)?,
+        };
         Ok(Arc::new(md))
     }
 }
@@ -986,6 +997,7 @@ this scan to succeed with an empty DataFrame.",
                                 sources,
                                 unified_scan_args.row_index.as_ref(),
                                 cloud_options,
+                                options.max_footer_size,
                             )
                             .await?;
 
@@ -423,6 +423,12 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IL::ArgMax => L::ArgMax,
                 #[cfg(feature = "diff")]
                 IL::Diff { n, null_behavior } => L::Diff { n, null_behavior },
+                #[cfg(feature = "cum_agg")]
+                IL::CumSum => L::CumSum,
+                #[cfg(feature = "cum_agg")]
+                IL::CumProd => L::CumProd,
+                IL::FlattenN(n) => L::FlattenN(n),
+                IL::ChunkEvery => L::ChunkEvery,
                 IL::Sort(sort_options) => L::Sort(sort_options),
                 #[cfg(feature = "list_sets")]
                 IL::SetOperation(set_operation) => L::SetOperation(set_operation),
@@ -463,6 +469,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::ExtractAll => B::ExtractAll,
                 #[cfg(feature = "extract_groups")]
                 IB::ExtractGroups { dtype, pat } => B::ExtractGroups { dtype, pat },
+                #[cfg(feature = "extract_groups")]
+                IB::ExtractAllGroups { dtype, pat } => B::ExtractAllGroups { dtype, pat },
                 #[cfg(feature = "regex")]
                 IB::Find { literal, strict } => B::Find { literal, strict },
                 #[cfg(feature = "string_to_integer")]
@@ -480,6 +488,11 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::Normalize { form } => B::Normalize { form },
                 #[cfg(feature = "string_reverse")]
                 IB::Reverse => B::Reverse,
+                #[cfg(feature = "string_word_count")]
+                IB::WordCount => B::WordCount,
+                #[cfg(feature = "string_tokenize")]
+                IB::Tokenize { words_only } => B::Tokenize { words_only },
+                IB::TruncateBytes { n, ellipsis } => B::TruncateBytes { n, ellipsis },
                 #[cfg(feature = "string_pad")]
                 IB::PadStart { fill_char } => B::PadStart { fill_char },
                 #[cfg(feature = "string_pad")]
@@ -516,6 +529,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::ToDecimal { scale } => B::ToDecimal { scale },
                 #[cfg(feature = "nightly")]
                 IB::Titlecase => B::Titlecase,
+                #[cfg(feature = "string_titlecase")]
+                IB::TitlecaseUnicodeWords => B::TitlecaseUnicodeWords,
                 IB::Uppercase => B::Uppercase,
                 #[cfg(feature = "string_pad")]
                 IB::ZFill => B::ZFill,
@@ -670,6 +685,20 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::All { ignore_nulls } => B::All { ignore_nulls },
                 IB::IsEmpty { ignore_nulls } => B::IsEmpty { ignore_nulls },
                 IB::HasNulls => B::HasNulls,
+                IB::IsMonotonic {
+                    descending,
+                    ignore_nulls,
+                } => B::IsMonotonic {
+                    descending,
+                    ignore_nulls,
+                },
+                IB::IsMonotonicBy {
+                    descending,
+                    null_equal,
+                } => B::IsMonotonicBy {
+                    descending,
+                    null_equal,
+                },
                 IB::IsNull => B::IsNull,
                 IB::IsNotNull => B::IsNotNull,
                 IB::IsFinite => B::IsFinite,
@@ -715,6 +744,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         },
         #[cfg(feature = "abs")]
         IF::Abs => F::Abs,
+        #[cfg(feature = "abs")]
+        IF::AbsDiff => F::AbsDiff,
         IF::Negate => F::Negate,
         #[cfg(feature = "hist")]
         IF::Hist {
@@ -932,6 +963,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         IF::Repeat => F::Repeat,
         #[cfg(feature = "round_series")]
         IF::Clip { has_min, has_max } => F::Clip { has_min, has_max },
+        #[cfg(feature = "round_series")]
+        IF::ClipBy => F::ClipBy,
         #[cfg(feature = "dtype-struct")]
         IF::AsStruct => F::AsStruct,
         #[cfg(feature = "top_k")]
@@ -943,11 +976,23 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         #[cfg(feature = "cum_agg")]
         IF::CumSum { reverse } => F::CumSum { reverse },
         #[cfg(feature = "cum_agg")]
-        IF::CumProd { reverse } => F::CumProd { reverse },
+        IF::CumProd { reverse, overflow } => F::CumProd { reverse, overflow },
         #[cfg(feature = "cum_agg")]
-        IF::CumMin { reverse } => F::CumMin { reverse },
+        IF::CumMin {
+            reverse,
+            skip_nulls,
+        } => F::CumMin {
+            reverse,
+            skip_nulls,
+        },
         #[cfg(feature = "cum_agg")]
-        IF::CumMax { reverse } => F::CumMax { reverse },
+        IF::CumMax {
+            reverse,
+            skip_nulls,
+        } => F::CumMax {
+            reverse,
+            skip_nulls,
+        },
         IF::Reverse => F::Reverse,
         #[cfg(feature = "dtype-struct")]
         IF::ValueCounts {
@@ -982,6 +1027,12 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         IF::Log1p => F::Log1p,
         #[cfg(feature = "log")]
         IF::Exp => F::Exp,
+        #[cfg(feature = "tdigest")]
+        IF::TDigest { compression } => F::TDigest { compression },
+        #[cfg(feature = "tdigest")]
+        IF::TDigestMerge => F::TDigestMerge,
+        #[cfg(feature = "tdigest")]
+        IF::TDigestQuantile { quantile } => F::TDigestQuantile { quantile },
         IF::Unique(v) => F::Unique(v),
         #[cfg(feature = "round_series")]
         IF::Round { decimals, mode } => F::Round { decimals, mode },
@@ -1149,6 +1200,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
             return_dtype: return_dtype.map(Into::into),
         },
         IF::GatherEvery { n, offset } => F::GatherEvery { n, offset },
+        IF::GatherEveryBy => F::GatherEveryBy,
+        IF::SampleSystematic => F::SampleSystematic,
         #[cfg(feature = "reinterpret")]
         IF::Reinterpret(dtype) => F::Reinterpret(None, Some(dtype)),
         IF::ExtendConstant => F::ExtendConstant,
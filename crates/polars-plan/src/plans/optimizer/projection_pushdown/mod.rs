@@ -1179,6 +1179,8 @@ impl ProjectionPushdownVisitor<'_, '_> {
                 #[cfg(feature = "dynamic_group_by")]
                 if let Some(options) = &options.dynamic {
                     input_names_projection.insert(options.index_column.clone());
+                    input_names_projection.extend(options.every_by_group.iter().cloned());
+                    input_names_projection.extend(options.period_by_group.iter().cloned());
                 }
 
                 #[cfg(feature = "dynamic_group_by")]
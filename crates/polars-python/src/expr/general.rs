@@ -709,11 +709,11 @@ impl PyExpr {
     fn cum_sum(&self, reverse: bool) -> Self {
         self.inner.clone().cum_sum(reverse).into()
     }
-    fn cum_max(&self, reverse: bool) -> Self {
-        self.inner.clone().cum_max(reverse).into()
+    fn cum_max(&self, reverse: bool, skip_nulls: bool) -> Self {
+        self.inner.clone().cum_max(reverse, skip_nulls).into()
     }
-    fn cum_min(&self, reverse: bool) -> Self {
-        self.inner.clone().cum_min(reverse).into()
+    fn cum_min(&self, reverse: bool, skip_nulls: bool) -> Self {
+        self.inner.clone().cum_min(reverse, skip_nulls).into()
     }
     fn cum_prod(&self, reverse: bool) -> Self {
         self.inner.clone().cum_prod(reverse).into()
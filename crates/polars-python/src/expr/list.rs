@@ -35,6 +35,18 @@ impl PyExpr {
         Ok(self.inner.clone().list().diff(n, null_behavior.0).into())
     }
 
+    fn list_cum_sum(&self) -> Self {
+        self.inner.clone().list().cum_sum().into()
+    }
+
+    fn list_cum_prod(&self) -> Self {
+        self.inner.clone().list().cum_prod().into()
+    }
+
+    fn list_flatten_n(&self, n: usize) -> Self {
+        self.inner.clone().list().flatten_n(n).into()
+    }
+
     fn list_eval(&self, expr: PyExpr, _parallel: bool) -> Self {
         self.inner.clone().list().eval(expr.inner).into()
     }
@@ -127,8 +127,12 @@ impl PyExpr {
     }
 
     #[cfg(feature = "nightly")]
-    fn str_to_titlecase(&self) -> Self {
-        self.inner.clone().str().to_titlecase().into()
+    fn str_to_titlecase(&self, unicode_words: bool) -> Self {
+        if unicode_words {
+            self.inner.clone().str().to_titlecase_unicode_words().into()
+        } else {
+            self.inner.clone().str().to_titlecase().into()
+        }
     }
 
     fn str_len_bytes(&self) -> Self {
@@ -6,16 +6,16 @@ use crate::error::PyPolarsErr;
 use crate::expr::ToExprs;
 
 #[pyfunction]
-pub fn all_horizontal(exprs: Vec<PyExpr>) -> PyResult<PyExpr> {
+pub fn all_horizontal(exprs: Vec<PyExpr>, ignore_nulls: bool) -> PyResult<PyExpr> {
     let exprs = exprs.to_exprs();
-    let e = dsl::all_horizontal(exprs).map_err(PyPolarsErr::from)?;
+    let e = dsl::all_horizontal(exprs, ignore_nulls).map_err(PyPolarsErr::from)?;
     Ok(e.into())
 }
 
 #[pyfunction]
-pub fn any_horizontal(exprs: Vec<PyExpr>) -> PyResult<PyExpr> {
+pub fn any_horizontal(exprs: Vec<PyExpr>, ignore_nulls: bool) -> PyResult<PyExpr> {
     let exprs = exprs.to_exprs();
-    let e = dsl::any_horizontal(exprs).map_err(PyPolarsErr::from)?;
+    let e = dsl::any_horizontal(exprs, ignore_nulls).map_err(PyPolarsErr::from)?;
     Ok(e.into())
 }
 
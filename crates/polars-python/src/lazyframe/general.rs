@@ -312,6 +312,7 @@ impl PyLazyFrame {
             parallel,
             low_memory,
             use_statistics,
+            max_footer_size: None,
         };
 
         let sources = sources.0;
@@ -704,6 +705,7 @@ impl PyLazyFrame {
             key_value_metadata: metadata.0,
             arrow_schema: arrow_schema.map(|x| Arc::new(x.0)),
             compat_level: None,
+            max_footer_size: None,
         };
 
         let target = target.extract_file_sink_destination()?;
@@ -764,7 +766,7 @@ impl PyLazyFrame {
         target, sink_options, include_bom, compression, compression_level, check_extension,
         include_header, separator, line_terminator, quote_char, batch_size, datetime_format,
         date_format, time_format, float_scientific, float_precision, decimal_comma, null_value,
-        quote_style
+        quote_style, append
     ))]
     fn sink_csv(
         &self,
@@ -788,6 +790,7 @@ impl PyLazyFrame {
         decimal_comma: bool,
         null_value: Option<Wrap<PlSmallStr>>,
         quote_style: Option<Wrap<QuoteStyle>>,
+        append: bool,
     ) -> PyResult<PyLazyFrame> {
         let quote_style = quote_style.map_or(QuoteStyle::default(), |wrap| wrap.0);
         let null_value = null_value
@@ -816,6 +819,7 @@ impl PyLazyFrame {
             include_header,
             batch_size,
             serialize_options: serialize_options.into(),
+            append,
         };
 
         let target = target.extract_file_sink_destination()?;
@@ -5,7 +5,7 @@ use polars_compute::rolling::QuantileMethod;
 use polars_core::chunked_array::ops::FillNullStrategy;
 #[cfg(feature = "string_normalize")]
 use polars_ops::chunked_array::UnicodeForm;
-use polars_ops::prelude::RankMethod;
+use polars_ops::prelude::{OverflowPolicy, RankMethod};
 use polars_ops::series::InterpolationMethod;
 #[cfg(feature = "search_sorted")]
 use polars_ops::series::SearchSortedSide;
@@ -140,6 +140,7 @@ pub enum PyStringFunction {
     Extract,
     ExtractAll,
     ExtractGroups,
+    ExtractAllGroups,
     Find,
     ToInteger,
     LenBytes,
@@ -149,6 +150,9 @@ pub enum PyStringFunction {
     JsonPathMatch,
     Replace,
     Reverse,
+    WordCount,
+    Tokenize,
+    TruncateBytes,
     PadStart,
     PadEnd,
     Slice,
@@ -171,6 +175,7 @@ pub enum PyStringFunction {
     SplitRegex,
     ToDecimal,
     Titlecase,
+    TitlecaseUnicodeWords,
     Uppercase,
     ZFill,
     ContainsAny,
@@ -193,6 +198,8 @@ pub enum PyBooleanFunction {
     All,
     IsEmpty,
     HasNulls,
+    IsMonotonic,
+    IsMonotonicBy,
     IsNull,
     IsNotNull,
     IsFinite,
@@ -861,6 +868,13 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                         pat.as_str(),
                     )
                         .into_py_any(py),
+                    #[cfg(feature = "extract_groups")]
+                    IRStringFunction::ExtractAllGroups { dtype, pat } => (
+                        PyStringFunction::ExtractAllGroups,
+                        &Wrap(dtype.clone()),
+                        pat.as_str(),
+                    )
+                        .into_py_any(py),
                     #[cfg(feature = "regex")]
                     IRStringFunction::Find { literal, strict } => {
                         (PyStringFunction::Find, literal, strict).into_py_any(py)
@@ -895,6 +909,16 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                     )
                         .into_py_any(py),
                     IRStringFunction::Reverse => (PyStringFunction::Reverse,).into_py_any(py),
+                    IRStringFunction::WordCount => (PyStringFunction::WordCount,).into_py_any(py),
+                    IRStringFunction::Tokenize { words_only } => {
+                        (PyStringFunction::Tokenize, words_only).into_py_any(py)
+                    },
+                    IRStringFunction::TruncateBytes { n, ellipsis } => (
+                        PyStringFunction::TruncateBytes,
+                        n,
+                        ellipsis.as_ref().map(|e| e.as_str()),
+                    )
+                        .into_py_any(py),
                     IRStringFunction::PadStart { fill_char } => {
                         (PyStringFunction::PadStart, fill_char).into_py_any(py)
                     },
@@ -953,6 +977,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                     },
                     #[cfg(feature = "nightly")]
                     IRStringFunction::Titlecase => (PyStringFunction::Titlecase,).into_py_any(py),
+                    IRStringFunction::TitlecaseUnicodeWords => {
+                        (PyStringFunction::TitlecaseUnicodeWords,).into_py_any(py)
+                    },
                     IRStringFunction::Uppercase => (PyStringFunction::Uppercase,).into_py_any(py),
                     IRStringFunction::ZFill => (PyStringFunction::ZFill,).into_py_any(py),
                     #[cfg(feature = "find_many")]
@@ -1124,6 +1151,18 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                         (PyBooleanFunction::IsEmpty, *ignore_nulls).into_py_any(py)
                     },
                     IRBooleanFunction::HasNulls => (PyBooleanFunction::HasNulls,).into_py_any(py),
+                    IRBooleanFunction::IsMonotonic {
+                        descending,
+                        ignore_nulls,
+                    } => {
+                        (PyBooleanFunction::IsMonotonic, *descending, *ignore_nulls).into_py_any(py)
+                    },
+                    IRBooleanFunction::IsMonotonicBy {
+                        descending,
+                        null_equal,
+                    } => {
+                        (PyBooleanFunction::IsMonotonicBy, *descending, *null_equal).into_py_any(py)
+                    },
                     IRBooleanFunction::IsNull => (PyBooleanFunction::IsNull,).into_py_any(py),
                     IRBooleanFunction::IsNotNull => (PyBooleanFunction::IsNotNull,).into_py_any(py),
                     IRBooleanFunction::IsFinite => (PyBooleanFunction::IsFinite,).into_py_any(py),
@@ -1164,6 +1203,7 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                     IRBooleanFunction::Not => (PyBooleanFunction::Not,).into_py_any(py),
                 },
                 IRFunctionExpr::Abs => ("abs",).into_py_any(py),
+                IRFunctionExpr::AbsDiff => ("abs_diff",).into_py_any(py),
                 #[cfg(feature = "hist")]
                 IRFunctionExpr::Hist {
                     bin_count,
@@ -1304,14 +1344,28 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                 IRFunctionExpr::Clip { has_min, has_max } => {
                     ("clip", has_min, has_max).into_py_any(py)
                 },
+                IRFunctionExpr::ClipBy => ("clip_by",).into_py_any(py),
                 IRFunctionExpr::AsStruct => ("as_struct",).into_py_any(py),
                 #[cfg(feature = "top_k")]
                 IRFunctionExpr::TopK { descending } => ("top_k", descending).into_py_any(py),
                 IRFunctionExpr::CumCount { reverse } => ("cum_count", reverse).into_py_any(py),
                 IRFunctionExpr::CumSum { reverse } => ("cum_sum", reverse).into_py_any(py),
-                IRFunctionExpr::CumProd { reverse } => ("cum_prod", reverse).into_py_any(py),
-                IRFunctionExpr::CumMin { reverse } => ("cum_min", reverse).into_py_any(py),
-                IRFunctionExpr::CumMax { reverse } => ("cum_max", reverse).into_py_any(py),
+                IRFunctionExpr::CumProd { reverse, overflow } => {
+                    let overflow = match overflow {
+                        OverflowPolicy::Wrapping => "wrapping",
+                        OverflowPolicy::Checked => "checked",
+                        OverflowPolicy::Saturating => "saturating",
+                    };
+                    ("cum_prod", reverse, overflow).into_py_any(py)
+                },
+                IRFunctionExpr::CumMin {
+                    reverse,
+                    skip_nulls,
+                } => ("cum_min", reverse, skip_nulls).into_py_any(py),
+                IRFunctionExpr::CumMax {
+                    reverse,
+                    skip_nulls,
+                } => ("cum_max", reverse, skip_nulls).into_py_any(py),
                 IRFunctionExpr::Reverse => ("reverse",).into_py_any(py),
                 IRFunctionExpr::ValueCounts {
                     sort,
@@ -1347,6 +1401,14 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                 IRFunctionExpr::Log => ("log",).into_py_any(py),
                 IRFunctionExpr::Log1p => ("log1p",).into_py_any(py),
                 IRFunctionExpr::Exp => ("exp",).into_py_any(py),
+                #[cfg(feature = "tdigest")]
+                IRFunctionExpr::TDigest { compression } => ("tdigest", compression).into_py_any(py),
+                #[cfg(feature = "tdigest")]
+                IRFunctionExpr::TDigestMerge => ("tdigest_merge",).into_py_any(py),
+                #[cfg(feature = "tdigest")]
+                IRFunctionExpr::TDigestQuantile { quantile } => {
+                    ("tdigest_quantile", quantile).into_py_any(py)
+                },
                 IRFunctionExpr::Unique(maintain_order) => {
                     ("unique", maintain_order).into_py_any(py)
                 },
@@ -1448,6 +1510,8 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                 IRFunctionExpr::GatherEvery { n, offset } => {
                     ("gather_every", offset, n).into_py_any(py)
                 },
+                IRFunctionExpr::GatherEveryBy => ("gather_every_by",).into_py_any(py),
+                IRFunctionExpr::SampleSystematic => ("sample_systematic",).into_py_any(py),
                 IRFunctionExpr::Reinterpret(dtype) => {
                     ("reinterpret", &Wrap(dtype.clone())).into_py_any(py)
                 },
@@ -1584,7 +1584,7 @@ impl SQLContext {
             // ...otherwise parse and apply the filter as normal
             let mut filter_expression = parse_sql_expr(expr, self, Some(schema).as_deref())?;
             if filter_expression.clone().meta().has_multiple_outputs() {
-                filter_expression = all_horizontal([filter_expression])?;
+                filter_expression = all_horizontal([filter_expression], false)?;
             }
             lf = self.process_subqueries(lf, vec![&mut filter_expression])?;
             lf = if invert_filter {
@@ -1644,7 +1644,7 @@ impl SQLContext {
             let schema = self.get_frame_schema(&mut lf)?;
             let mut filter_expression = parse_sql_expr(expr, self, Some(&schema))?;
             if filter_expression.clone().meta().has_multiple_outputs() {
-                filter_expression = all_horizontal([filter_expression])?;
+                filter_expression = all_horizontal([filter_expression], false)?;
             }
             lf = self.process_subqueries(lf, vec![&mut filter_expression])?;
             lf = lf.filter(filter_expression);
@@ -1605,7 +1605,7 @@ impl SQLFunctionVisitor<'_> {
             CovarSamp => self.visit_binary(|a, b| polars_lazy::dsl::cov(a, b, 1)),
             First => self.visit_unary(Expr::first),
             Last => self.visit_unary(Expr::last),
-            Max => self.visit_unary_with_opt_cumulative(Expr::max, Expr::cum_max),
+            Max => self.visit_unary_with_opt_cumulative(Expr::max, |e, r| e.cum_max(r, true)),
             Median => self.visit_unary(Expr::median),
             QuantileCont | QuantileDisc => {
                 let (fname, method) = if matches!(function_name, QuantileCont) {
@@ -1638,7 +1638,7 @@ impl SQLFunctionVisitor<'_> {
                     _ => polars_bail!(SQLSyntax: "{} expects 2 arguments (found {})", fname, args.len()),
                 }
             },
-            Min => self.visit_unary_with_opt_cumulative(Expr::min, Expr::cum_min),
+            Min => self.visit_unary_with_opt_cumulative(Expr::min, |e, r| e.cum_min(r, true)),
             StdDev => self.visit_unary(|e| e.std(1)),
             StringAgg => self.visit_string_agg(),
             Sum => self.visit_unary_with_opt_cumulative(Expr::sum, Expr::cum_sum),
@@ -56,7 +56,7 @@ fn test_cumulative_sum() {
 fn test_cumulative_min() {
     let expr = col("Sales")
         .sort(SortOptions::default().with_order_descending(true))
-        .cum_min(false);
+        .cum_min(false, true);
 
     let sql_expr = "MIN(Sales) OVER (ORDER BY Sales DESC)";
     let (expected, actual) = create_expected(expr, sql_expr);
@@ -68,7 +68,7 @@ fn test_cumulative_min() {
 fn test_cumulative_max() {
     let expr = col("Sales")
         .sort(SortOptions::default().with_order_descending(true))
-        .cum_max(false);
+        .cum_max(false, true);
 
     let sql_expr = "MAX(Sales) OVER (ORDER BY Sales DESC)";
     let (expected, actual) = create_expected(expr, sql_expr);
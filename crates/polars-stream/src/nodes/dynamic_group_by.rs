@@ -60,8 +60,20 @@ impl DynamicGroupBy {
             include_boundaries,
             closed_window,
             start_by,
+            clock_skew_tolerance,
+            every_by_group,
+            period_by_group,
         } = options;
 
+        polars_ensure!(
+            clock_skew_tolerance.is_none(),
+            InvalidOperation: "'clock_skew_tolerance' is not supported in the streaming engine"
+        );
+        polars_ensure!(
+            every_by_group.is_none() && period_by_group.is_none(),
+            InvalidOperation: "'every_by_group' and 'period_by_group' require 'group_by'"
+        );
+
         polars_ensure!(!every.negative(), ComputeError: "'every' argument must be positive");
 
         let (index_column_idx, _, index_dtype) = schema.get_full(&index_column).unwrap();
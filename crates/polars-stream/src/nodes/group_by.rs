@@ -1,22 +1,24 @@
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use polars_async::executor;
 use polars_core::prelude::{IntoColumn, PlHashSet, PlRandomState};
 use polars_core::runtime::{ASYNC, RAYON};
 use polars_core::schema::Schema;
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_error::polars_err;
 use polars_expr::groups::Grouper;
 use polars_expr::hash_keys::HashKeys;
 use polars_expr::hot_groups::{HotGrouper, new_hash_hot_grouper};
 use polars_expr::reduce::GroupedReduction;
-use polars_ooc::{MostRecentSpillContext, SpillFrame};
+use polars_ooc::{MostRecentSpillContext, SpillFile, SpillFrame};
 use polars_utils::cardinality_sketch::CardinalitySketch;
 use polars_utils::hashing::HashPartitioner;
 use polars_utils::itertools::Itertools;
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::reuse_vec::reuse_vec;
 use polars_utils::sparse_init_vec::SparseInitVec;
-use polars_utils::{IdxSize, UnitVec};
+use polars_utils::{IdxSize, UnitVec, format_pl_smallstr};
 use rayon::prelude::*;
 use tokio::sync::mpsc::{Receiver, channel};
 
@@ -51,6 +53,10 @@ struct LocalGroupBySinkState {
     morsel_idxs_values_per_p: Vec<Vec<IdxSize>>,
     morsel_idxs_offsets_per_p: Vec<usize>,
 
+    // Cold morsels written to disk once the memory limit was hit, already split
+    // by partition. The key columns come first, followed by the reduction inputs.
+    spilled_morsels_per_p: Vec<Vec<(usize, u64, SpillFile)>>,
+
     // Similar to the above, but for (evicted) pre-aggregates.
     // The UnitVec contains the indices of the grouped reductions.
     pre_aggs: Vec<PreAgg>,
@@ -79,12 +85,50 @@ impl LocalGroupBySinkState {
             morsel_idxs_values_per_p: vec![Vec::new(); num_partitions],
             morsel_idxs_offsets_per_p: vec![0; num_partitions],
 
+            spilled_morsels_per_p: (0..num_partitions).map(|_| Vec::new()).collect(),
+
             pre_aggs: Vec::new(),
             pre_agg_idxs_values_per_p: vec![Vec::new(); num_partitions],
             pre_agg_idxs_offsets_per_p: vec![0; num_partitions],
         }
     }
 
+    /// Splits a cold morsel that is going to be spilled by partition, removing its indices from
+    /// `morsel_idxs_values_per_p`.
+    ///
+    /// # Safety
+    /// The indices in `morsel_idxs_values_per_p[p][start_per_p[p]..]` must be in-bounds for
+    /// `key_df` and `df`.
+    unsafe fn split_cold_morsel(
+        &mut self,
+        key_df: &DataFrame,
+        df: &DataFrame,
+        start_per_p: &[usize],
+    ) -> Vec<(usize, DataFrame)> {
+        let mut columns = key_df
+            .columns()
+            .iter()
+            .enumerate()
+            .map(|(i, c)| {
+                c.clone()
+                    .with_name(format_pl_smallstr!("__POLARS_GB_KEY_{i}"))
+            })
+            .collect_vec();
+        columns.extend(df.columns().iter().cloned());
+        let combined = unsafe { DataFrame::new_unchecked(df.height(), columns) };
+
+        let mut p_dfs = Vec::new();
+        for (p, start) in start_per_p.iter().enumerate() {
+            let idxs = &self.morsel_idxs_values_per_p[p][*start..];
+            if !idxs.is_empty() {
+                let p_df = unsafe { combined.take_slice_unchecked_impl(idxs, false) };
+                p_dfs.push((p, p_df));
+            }
+            self.morsel_idxs_values_per_p[p].truncate(*start);
+        }
+        p_dfs
+    }
+
     fn flush_evictions(
         &mut self,
         input_idx: usize,
@@ -123,6 +167,21 @@ impl LocalGroupBySinkState {
     }
 }
 
+/// Writes the partitions of a cold morsel to disk on the blocking thread pool.
+async fn write_spill_files(
+    p_dfs: Vec<(usize, DataFrame)>,
+) -> PolarsResult<Vec<(usize, SpillFile)>> {
+    ASYNC
+        .spawn_blocking(move || {
+            p_dfs
+                .into_iter()
+                .map(|(p, df)| Ok((p, SpillFile::write(df)?)))
+                .collect()
+        })
+        .await
+        .unwrap()
+}
+
 struct GroupBySinkState {
     key_selectors_per_input: Vec<Vec<StreamExpr>>,
     reductions_per_input: Vec<Vec<usize>>,
@@ -134,6 +193,9 @@ struct GroupBySinkState {
     random_state: PlRandomState,
     partitioner: HashPartitioner,
     has_order_sensitive_agg: bool,
+    // Cold morsels are written to disk once they take up more than this many bytes.
+    max_cold_bytes: Option<usize>,
+    cold_bytes: AtomicUsize,
 }
 
 impl GroupBySinkState {
@@ -153,6 +215,8 @@ impl GroupBySinkState {
             let random_state = &self.random_state;
             let partitioner = self.partitioner.clone();
             let has_order_sensitive_agg = self.has_order_sensitive_agg;
+            let max_cold_bytes = self.max_cold_bytes;
+            let cold_bytes = &self.cold_bytes;
             join_handles.push(scope.spawn_task(TaskPriority::High, async move {
                 let mut hot_idxs = Vec::new();
                 let mut hot_group_idxs = Vec::new();
@@ -221,17 +285,37 @@ impl GroupBySinkState {
                             let cold_keys = hash_keys.gather_unchecked(&cold_idxs);
                             let cold_df = df.take_slice_unchecked_impl(&cold_idxs, false);
 
+                            let start_per_p = local
+                                .morsel_idxs_values_per_p
+                                .iter()
+                                .map(|vp| vp.len())
+                                .collect_vec();
                             cold_keys.gen_idxs_per_partition(
                                 &partitioner,
                                 &mut local.morsel_idxs_values_per_p,
                                 &mut local.sketch_per_p,
                                 true,
                             );
-                            local
-                                .morsel_idxs_offsets_per_p
-                                .extend(local.morsel_idxs_values_per_p.iter().map(|vp| vp.len()));
-                            let sf = SpillFrame::new(cold_df, spill_ctx).await;
-                            local.cold_morsels.push((input_idx, seq, cold_keys, sf));
+
+                            let cold_size = cold_df.estimated_size();
+                            let prev_cold_bytes =
+                                cold_bytes.fetch_add(cold_size, Ordering::Relaxed);
+                            if max_cold_bytes.is_some_and(|max| prev_cold_bytes + cold_size > max) {
+                                cold_bytes.fetch_sub(cold_size, Ordering::Relaxed);
+                                let cold_key_df = keys.take_slice_unchecked_impl(&cold_idxs, false);
+                                let p_dfs =
+                                    local.split_cold_morsel(&cold_key_df, &cold_df, &start_per_p);
+                                for (p, spill_file) in write_spill_files(p_dfs).await? {
+                                    local.spilled_morsels_per_p[p]
+                                        .push((input_idx, seq, spill_file));
+                                }
+                            } else {
+                                local.morsel_idxs_offsets_per_p.extend(
+                                    local.morsel_idxs_values_per_p.iter().map(|vp| vp.len()),
+                                );
+                                let sf = SpillFrame::new(cold_df, spill_ctx).await;
+                                local.cold_morsels.push((input_idx, seq, cold_keys, sf));
+                            }
                         }
                     }
 
@@ -298,6 +382,12 @@ impl GroupBySinkState {
         }
         let (drop_q_send, drop_q_recv) = async_channel::bounded(self.locals.len());
         let num_partitions = self.locals[0].sketch_per_p.len();
+        let mut spilled_morsels_per_p = (0..num_partitions).map(|_| Vec::new()).collect_vec();
+        for l in &mut self.locals {
+            for (p, spilled_morsels) in l.spilled_morsels_per_p.iter_mut().enumerate() {
+                spilled_morsels_per_p[p].append(spilled_morsels);
+            }
+        }
         let output_per_partition: SparseInitVec<GroupByPartition> =
             SparseInitVec::with_capacity(num_partitions);
         let locals = &self.locals;
@@ -458,6 +548,7 @@ impl GroupBySinkState {
                             GroupByPartition {
                                 grouper: p_grouper,
                                 grouped_reductions: p_reductions,
+                                spilled_morsels: Vec::new(),
                             },
                         )
                         .ok()
@@ -492,13 +583,58 @@ impl GroupBySinkState {
                 .for_each(drop);
         });
 
-        Ok(output_per_partition.try_assume_init().ok().unwrap())
+        let mut partitions = output_per_partition.try_assume_init().ok().unwrap();
+        for (partition, spilled_morsels) in partitions.iter_mut().zip(spilled_morsels_per_p) {
+            partition.spilled_morsels = spilled_morsels;
+        }
+        Ok(partitions)
+    }
+
+    /// Reads back the morsels of a partition that were spilled to disk and inserts them.
+    fn insert_spilled_morsels(&self, partition: &mut GroupByPartition) -> PolarsResult<()> {
+        let mut group_idxs = Vec::new();
+        for (input_idx, seq_id, spill_file) in core::mem::take(&mut partition.spilled_morsels) {
+            let height = spill_file.height();
+            let mut columns = spill_file.read()?.into_columns();
+            drop(spill_file);
+            let num_keys = self.key_selectors_per_input[input_idx].len();
+            let value_columns = columns.split_off(num_keys);
+            let p_morsel_idxs = (0..height as IdxSize).collect_vec();
+            unsafe {
+                let morsel_df = DataFrame::new_unchecked(height, value_columns);
+                let keys = DataFrame::new_unchecked(height, columns);
+                let keys = HashKeys::from_df(&keys, self.random_state.clone(), true, false);
+
+                group_idxs.clear();
+                partition
+                    .grouper
+                    .insert_keys_subset(&keys, &p_morsel_idxs, Some(&mut group_idxs));
+
+                for red_idx in &self.reductions_per_input[input_idx] {
+                    let in_cols = self.grouped_reduction_cols[*red_idx]
+                        .iter()
+                        .map(|col| morsel_df.column(col).unwrap())
+                        .collect_vec();
+                    let reduction = &mut partition.grouped_reductions[*red_idx];
+                    reduction.resize(partition.grouper.num_groups());
+                    reduction.update_groups_subset(
+                        &in_cols,
+                        &p_morsel_idxs,
+                        &group_idxs,
+                        seq_id,
+                    )?;
+                }
+            }
+        }
+        Ok(())
     }
 }
 
 struct GroupByPartition {
     grouper: Box<dyn Grouper>,
     grouped_reductions: Vec<Box<dyn GroupedReduction>>,
+    // Morsels of this partition that were spilled to disk and still have to be inserted.
+    spilled_morsels: Vec<(usize, u64, SpillFile)>,
 }
 
 impl GroupByPartition {
@@ -545,10 +681,23 @@ impl GroupByNode {
         random_state: PlRandomState,
         num_pipelines: usize,
         has_order_sensitive_agg: bool,
-    ) -> Self {
+    ) -> PolarsResult<Self> {
         let hot_table_size = std::env::var("POLARS_HOT_TABLE_SIZE")
             .map(|sz| sz.parse::<usize>().unwrap())
             .unwrap_or(DEFAULT_HOT_TABLE_SIZE);
+        let max_cold_bytes = std::env::var("POLARS_STREAMING_GROUP_BY_MAX_MEMORY_MB")
+            .ok()
+            .map(|mb| {
+                mb.parse::<usize>()
+                    .map(|mb| mb.saturating_mul(1024 * 1024))
+                    .map_err(|_| {
+                        polars_err!(
+                            InvalidOperation:
+                            "invalid value for POLARS_STREAMING_GROUP_BY_MAX_MEMORY_MB: {mb}"
+                        )
+                    })
+            })
+            .transpose()?;
         let num_inputs = key_selectors_per_input.len();
         let num_partitions = num_pipelines;
         let uniq_grouped_reduction_cols_per_input = reductions_per_input
@@ -575,7 +724,7 @@ impl GroupByNode {
             })
             .collect();
         let partitioner = HashPartitioner::new(num_partitions, 0);
-        Self {
+        Ok(Self {
             state: GroupByState::Sink(GroupBySinkState {
                 key_selectors_per_input,
                 reductions_per_input,
@@ -587,13 +736,15 @@ impl GroupByNode {
                 locals,
                 partitioner,
                 has_order_sensitive_agg,
+                max_cold_bytes,
+                cold_bytes: AtomicUsize::new(0),
             }),
             key_schema,
             num_inputs,
             num_pipelines,
             output_schema,
             spill_ctx: MostRecentSpillContext::new(),
-        }
+        })
     }
 }
 
@@ -624,12 +775,24 @@ impl ComputeNode for GroupByNode {
                     unreachable!()
                 };
                 let partitions = sink.combine_locals()?;
-                let dfs = RAYON.install(|| {
+                let dfs = if partitions.iter().any(|p| !p.spilled_morsels.is_empty()) {
+                    // Merge the spilled morsels one partition at a time, so only a single
+                    // partition is read back from disk and aggregated at any moment.
                     partitions
-                        .into_par_iter()
-                        .map(|p| p.into_df(&self.key_schema, &self.output_schema))
-                        .collect::<Result<Vec<_>, _>>()
-                })?;
+                        .into_iter()
+                        .map(|mut p| {
+                            sink.insert_spilled_morsels(&mut p)?;
+                            p.into_df(&self.key_schema, &self.output_schema)
+                        })
+                        .collect::<PolarsResult<Vec<_>>>()?
+                } else {
+                    RAYON.install(|| {
+                        partitions
+                            .into_par_iter()
+                            .map(|p| p.into_df(&self.key_schema, &self.output_schema))
+                            .collect::<Result<Vec<_>, _>>()
+                    })?
+                };
 
                 let df = accumulate_dataframes_vertical_unchecked(dfs);
                 let source = InMemorySourceNode::new(Arc::new(df), MorselSeq::new(0));
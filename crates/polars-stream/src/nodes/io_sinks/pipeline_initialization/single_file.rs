@@ -7,8 +7,9 @@ use polars_core::frame::DataFrame;
 use polars_core::runtime::ASYNC;
 use polars_error::PolarsResult;
 use polars_io::metrics::IOMetrics;
+use polars_io::utils::file::Writeable;
 use polars_plan::dsl::sink::SinkedPathInfo;
-use polars_plan::dsl::{SinkTarget, UnifiedSinkArgs};
+use polars_plan::dsl::{FileWriteFormat, SinkTarget, UnifiedSinkArgs};
 use polars_utils::pl_str::PlSmallStr;
 
 use crate::execute::StreamingExecutionState;
@@ -72,9 +73,22 @@ pub fn start_single_file_sink_pipeline(
     let file_schema = input_schema;
     let verbose = polars_core::config::verbose();
 
+    let append = match &file_format {
+        #[cfg(feature = "csv")]
+        FileWriteFormat::Csv(options) => options.append,
+        _ => false,
+    };
+
     let file_open_task = {
         let io_metrics = io_metrics.clone();
         tokio_handle_ext::AbortOnDropHandle(ASYNC.spawn(async move {
+            if append && let SinkTarget::Path(path) = &target {
+                if mkdir {
+                    polars_io::utils::mkdir::tokio_mkdir_recursive(path).await?;
+                }
+                return Writeable::try_new_append(path.clone());
+            }
+
             target
                 .open_into_writeable_async(
                     cloud_options.as_deref(),
@@ -5,7 +5,7 @@ use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
 use polars_io::prelude::{CsvWriterOptions, ExternalCompression, UTF8_BOM, csv_header};
 use polars_io::utils::compression::CompressedWriter;
-use polars_io::utils::file::{AsyncDynWriteable, AsyncWriteable};
+use polars_io::utils::file::{AsyncDynWriteable, AsyncWriteable, Writeable};
 use tokio::io::AsyncWriteExt as _;
 
 use crate::nodes::io_sinks::components::sink_morsel::SinkMorselPermit;
@@ -35,6 +35,12 @@ impl IOWriter {
 
         let (writable, sync_on_close) = file.await?;
 
+        // When appending to a non-empty file, the BOM and header are already there.
+        let is_appending = match &writable {
+            Writeable::Local(file) if options.append => file.metadata()?.len() > 0,
+            _ => false,
+        };
+
         let mut writer = match options.compression {
             // Natively convert into `AsyncWriteable` to allow native async optimizations.
             ExternalCompression::Uncompressed => writable.try_into_async_writeable()?,
@@ -50,11 +56,11 @@ impl IOWriter {
             )),
         };
 
-        if options.include_bom {
+        if options.include_bom && !is_appending {
             writer.write_all(&UTF8_BOM).await?;
         }
 
-        if options.include_header {
+        if options.include_header && !is_appending {
             let names: Vec<&str> = schema.iter_names().map(|s| s.as_str()).collect();
             writer
                 .write_all(&csv_header(names.as_slice(), &options.serialize_options)?)
@@ -89,7 +89,7 @@ impl FileWriterStarter for ParquetWriterStarter {
 
             if initialized_state.is_none() {
                 let schema_descriptor = Arc::new(to_parquet_schema(&self.arrow_schema)?);
-                let encodings = get_encodings(&self.arrow_schema);
+                let encodings = get_encodings(&self.arrow_schema)?;
 
                 *initialized_state = Some(InitializedState {
                     encodings,
@@ -110,6 +110,7 @@ impl FileWriterStarter for ParquetWriterStarter {
             compression: self.options.compression.into(),
             version: Version::V1,
             data_page_size: self.options.data_page_size,
+            max_footer_size: self.options.max_footer_size,
         };
 
         let arrow_schema = Arc::clone(&self.arrow_schema);
@@ -68,6 +68,7 @@ impl DeletionFilesProvider {
                         parallel: polars_io::prelude::ParallelStrategy::Auto,
                         low_memory: false,
                         use_statistics: false,
+                        max_footer_size: None,
                     }),
                     prefetch_limit: RelaxedCell::new_usize(0),
                     prefetch_semaphore: std::sync::OnceLock::new(),
@@ -3,9 +3,11 @@ use polars_error::PolarsResult;
 use polars_io::utils::byte_source::{ByteSource, DynByteSource};
 
 /// Read the metadata bytes of a parquet file, does not decode the bytes. If during metadata fetch
-/// the bytes of the entire file are loaded, it is returned in the second return value.
+/// the bytes of the entire file are loaded, it is returned in the second return value. Errors if
+/// the footer is larger than `max_footer_size` bytes.
 pub async fn read_parquet_metadata_bytes(
     byte_source: &DynByteSource,
+    max_footer_size: Option<usize>,
     verbose: bool,
 ) -> PolarsResult<(Buffer<u8>, Option<Buffer<u8>>)> {
     use polars_parquet::parquet::PARQUET_MAGIC;
@@ -47,6 +49,15 @@ pub async fn read_parquet_metadata_bytes(
         .into());
     }
 
+    if let Some(max_footer_size) = max_footer_size
+        && footer_size as usize > max_footer_size
+    {
+        return Err(ParquetError::OutOfSpec(format!(
+            "footer size ({footer_size}) exceeds the limit of {max_footer_size} bytes"
+        ))
+        .into());
+    }
+
     let footer_size = footer_size as usize + FOOTER_HEADER_SIZE;
 
     if file_size < footer_size {
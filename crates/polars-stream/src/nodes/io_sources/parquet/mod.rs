@@ -109,10 +109,16 @@ impl FileReader for ParquetFileReader {
         } else {
             let (metadata_bytes, opt_full_bytes) = {
                 let byte_source = byte_source.clone();
+                let max_footer_size = self.config.max_footer_size;
 
                 ASYNC
                     .spawn(async move {
-                        metadata_utils::read_parquet_metadata_bytes(&byte_source, verbose).await
+                        metadata_utils::read_parquet_metadata_bytes(
+                            &byte_source,
+                            max_footer_size,
+                            verbose,
+                        )
+                        .await
                     })
                     .await
                     .unwrap()?
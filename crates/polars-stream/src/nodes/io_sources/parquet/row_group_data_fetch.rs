@@ -210,3 +210,49 @@ fn get_row_group_byte_ranges_for_projection<'a>(
             })
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use polars_core::prelude::*;
+    use polars_io::SerReader;
+    use polars_io::prelude::{ParquetReader, ParquetWriter};
+
+    use super::*;
+
+    #[test]
+    fn test_projected_byte_ranges_skip_unprojected_columns() -> PolarsResult<()> {
+        let columns = (0..20i64)
+            .map(|i| {
+                Column::new(
+                    format!("c{i}").into(),
+                    (0..1000i64).map(|v| v * i).collect::<Vec<_>>(),
+                )
+            })
+            .collect();
+        let mut df = DataFrame::new(1000, columns)?;
+
+        let mut buf = Cursor::new(Vec::new());
+        ParquetWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+        let metadata = ParquetReader::new(buf).get_metadata()?.clone();
+        let row_group = &metadata.row_groups[0];
+
+        let total_bytes = |names: &[PlSmallStr]| -> usize {
+            get_row_group_byte_ranges_for_projection(row_group, &mut names.iter())
+                .map(|range| range.len())
+                .sum()
+        };
+
+        let all = df.get_column_names_owned();
+        let projected = [all[3].clone(), all[17].clone()];
+        let n_projected =
+            get_row_group_byte_ranges_for_projection(row_group, &mut projected.iter()).count();
+
+        // Only the column chunks of the projected columns are fetched.
+        assert_eq!(n_projected, 2);
+        assert!(total_bytes(&projected) * 5 < total_bytes(&all));
+        Ok(())
+    }
+}
@@ -620,6 +620,9 @@ fn visualize_plan_rec(
                 include_boundaries,
                 closed_window,
                 start_by,
+                clock_skew_tolerance,
+                every_by_group,
+                period_by_group,
             } = options;
             let mut s = String::new();
             let f = &mut s;
@@ -650,6 +653,15 @@ fn visualize_plan_rec(
                 )
                 .unwrap();
             }
+            if let Some(tolerance) = clock_skew_tolerance {
+                write!(f, "clock_skew_tolerance: {tolerance}\\n").unwrap();
+            }
+            if let Some(column) = every_by_group {
+                write!(f, "every_by_group: {column}\\n").unwrap();
+            }
+            if let Some(column) = period_by_group {
+                write!(f, "period_by_group: {column}\\n").unwrap();
+            }
             if let Some((offset, length)) = slice {
                 write!(f, "slice: {offset}, {length}\\n").unwrap();
             }
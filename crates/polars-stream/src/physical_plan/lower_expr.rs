@@ -1153,11 +1153,20 @@ fn lower_exprs_with_ctx(
             ref agg_expr @ AExpr::Function {
                 input: ref inner_exprs,
                 function:
-                    ref function @ (IRFunctionExpr::CumMin { reverse }
-                    | IRFunctionExpr::CumMax { reverse }
+                    ref function @ (IRFunctionExpr::CumMin {
+                        reverse,
+                        skip_nulls: true,
+                    }
+                    | IRFunctionExpr::CumMax {
+                        reverse,
+                        skip_nulls: true,
+                    }
                     | IRFunctionExpr::CumSum { reverse }
                     | IRFunctionExpr::CumCount { reverse }
-                    | IRFunctionExpr::CumProd { reverse }),
+                    | IRFunctionExpr::CumProd {
+                        reverse,
+                        overflow: polars_ops::series::OverflowPolicy::Wrapping,
+                    }),
                 options: _,
             } if !reverse => {
                 use crate::nodes::cum_agg::CumAggKind;
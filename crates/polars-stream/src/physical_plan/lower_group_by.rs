@@ -1242,6 +1242,9 @@ pub fn build_group_by_stream(
         }
         return Ok(input);
     } else if let Some(dynamic_options) = options.as_ref().dynamic.as_ref()
+        && dynamic_options.clock_skew_tolerance.is_none()
+        && dynamic_options.every_by_group.is_none()
+        && dynamic_options.period_by_group.is_none()
         && keys.is_empty()
         && apply.is_none()
     {
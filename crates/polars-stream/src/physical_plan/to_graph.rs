@@ -977,7 +977,7 @@ fn to_graph_rec<'a>(
                     PlRandomState::default(),
                     ctx.num_pipelines,
                     has_order_sensitive_agg,
-                ),
+                )?,
                 key_ports,
             )
         },
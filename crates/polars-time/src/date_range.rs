@@ -1,5 +1,9 @@
 use arrow::legacy::time_zone::Tz;
+#[cfg(feature = "dtype-date")]
+use arrow::temporal_conversions::EPOCH_DAYS_FROM_CE;
 use chrono::{Datelike, NaiveDateTime, NaiveTime};
+#[cfg(feature = "dtype-date")]
+use chrono::{NaiveDate, Weekday};
 use polars_core::chunked_array::temporal::time_to_time64ns;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
@@ -62,6 +66,73 @@ pub fn datetime_range_impl(
     Ok(out)
 }
 
+/// How [`business_date_range`] treats an `end` date that is not a business day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BusinessDayRoll {
+    /// Extend the range up to the next business day.
+    Forward,
+    /// End the range at the previous business day.
+    Backward,
+    /// Roll forward, unless that crosses into the next month; then roll backward.
+    Modified,
+}
+
+/// Create a [`DateChunked`] with all business days from `start` to `end`, both inclusive.
+///
+/// Saturdays, Sundays and the given `holidays` are not business days. If `end` is not a
+/// business day, it is first rolled to one according to `roll`.
+#[cfg(feature = "dtype-date")]
+pub fn business_date_range(
+    name: PlSmallStr,
+    start: NaiveDate,
+    end: NaiveDate,
+    roll: BusinessDayRoll,
+    holidays: &[NaiveDate],
+) -> PolarsResult<DateChunked> {
+    let holidays: PlHashSet<NaiveDate> = holidays.iter().copied().collect();
+    let is_business_day = |date: &NaiveDate| {
+        !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) && !holidays.contains(date)
+    };
+    // Terminates as there are only finitely many holidays.
+    let roll_to_business_day = |mut date: NaiveDate, forward: bool| {
+        while !is_business_day(&date) {
+            let next = if forward {
+                date.succ_opt()
+            } else {
+                date.pred_opt()
+            };
+            date = next.ok_or_else(
+                || polars_err!(ComputeError: "date out of range while rolling {}", date),
+            )?;
+        }
+        PolarsResult::Ok(date)
+    };
+
+    let end = match roll {
+        BusinessDayRoll::Forward => roll_to_business_day(end, true)?,
+        BusinessDayRoll::Backward => roll_to_business_day(end, false)?,
+        BusinessDayRoll::Modified => {
+            let rolled = roll_to_business_day(end, true)?;
+            if rolled.month() == end.month() {
+                rolled
+            } else {
+                roll_to_business_day(end, false)?
+            }
+        },
+    };
+
+    let days = start
+        .iter_days()
+        .take_while(|date| *date <= end)
+        .filter(|date| is_business_day(date))
+        .map(|date| date.num_days_from_ce() - EPOCH_DAYS_FROM_CE)
+        .collect::<Vec<_>>();
+    let mut out = Int32Chunked::new_vec(name, days).into_date();
+
+    out.physical_mut().set_sorted_flag(IsSorted::Ascending);
+    Ok(out)
+}
+
 /// Create a [`TimeChunked`] from a given `start` and `end` date and a given `interval`.
 pub fn time_range(
     name: PlSmallStr,
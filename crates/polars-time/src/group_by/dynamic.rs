@@ -4,6 +4,7 @@ use polars_core::runtime::RAYON;
 use polars_core::series::IsSorted;
 use polars_core::utils::flatten::flatten_par;
 use polars_ops::series::SeriesMethods;
+use polars_utils::idx_vec::IdxVec;
 use polars_utils::itertools::Itertools;
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::slice::SortedSlice;
@@ -34,6 +35,15 @@ pub struct DynamicGroupOptions {
     pub include_boundaries: bool,
     pub closed_window: ClosedWindow,
     pub start_by: StartBy,
+    /// Accept an index column that is out of order by at most this duration. Such rows are
+    /// sorted into the windows they belong to instead of raising an error.
+    pub clock_skew_tolerance: Option<Duration>,
+    /// String column with the `every` duration of every `group_by` group, overriding `every`.
+    /// The value must be the same for all rows of a group.
+    pub every_by_group: Option<PlSmallStr>,
+    /// String column with the `period` duration of every `group_by` group, overriding `period`.
+    /// The value must be the same for all rows of a group.
+    pub period_by_group: Option<PlSmallStr>,
 }
 
 impl Default for DynamicGroupOptions {
@@ -47,6 +57,9 @@ impl Default for DynamicGroupOptions {
             include_boundaries: false,
             closed_window: ClosedWindow::Left,
             start_by: Default::default(),
+            clock_skew_tolerance: None,
+            every_by_group: None,
+            period_by_group: None,
         }
     }
 }
@@ -74,6 +87,122 @@ impl Default for RollingGroupOptions {
     }
 }
 
+/// Sorts an index column that is out of order by at most `tolerance`, returning the sort
+/// indices. Returns `None` if the column already is sorted.
+fn sort_skewed_index(
+    dt: &mut Column,
+    tolerance: Duration,
+    tu: TimeUnit,
+) -> PolarsResult<Option<IdxCa>> {
+    let tolerance = match tu {
+        TimeUnit::Nanoseconds => tolerance.duration_ns(),
+        TimeUnit::Microseconds => tolerance.duration_us(),
+        TimeUnit::Milliseconds => tolerance.duration_ms(),
+    };
+    let ts = dt.datetime().unwrap().physical();
+    let ts = ts.cont_slice().unwrap();
+    if ts.is_sorted_ascending() {
+        return Ok(None);
+    }
+
+    let mut max = i64::MIN;
+    for t in ts {
+        polars_ensure!(
+            *t >= max.saturating_sub(tolerance),
+            ComputeError: "input data is not sorted and arrives later than 'clock_skew_tolerance' allows"
+        );
+        max = max.max(*t);
+    }
+
+    let sort_idx = dt
+        .as_materialized_series()
+        .arg_sort(SortOptions::default().with_maintain_order(true));
+    *dt = dt.take(&sort_idx)?;
+    Ok(Some(sort_idx))
+}
+
+/// Converts slice groups over the sorted rows to index groups over the original rows.
+fn unsorted_groups(groups: &GroupsType, sort_idx: &IdxCa) -> GroupsType {
+    let GroupsType::Slice { groups, .. } = groups else {
+        unreachable!()
+    };
+    let sort_idx = sort_idx.cont_slice().unwrap();
+    let (first, all) = groups
+        .iter()
+        .map(|[start, len]| {
+            let idx = &sort_idx[*start as usize..(*start + *len) as usize];
+            (
+                idx.first().copied().unwrap_or(0),
+                IdxVec::from(idx.to_vec()),
+            )
+        })
+        .unzip();
+    GroupsType::Idx(GroupsIdx::new(first, all, false))
+}
+
+/// Parse the duration in `column` of every group, `default` if there is no column.
+fn durations_by_group(
+    df: &DataFrame,
+    column: Option<&PlSmallStr>,
+    default: Duration,
+    groups: &GroupsSlice,
+    time_type: &DataType,
+    variable_name: &str,
+) -> PolarsResult<Vec<Duration>> {
+    let Some(column) = column else {
+        return Ok(vec![default; groups.len()]);
+    };
+    let ca = df.column(column)?.str()?;
+    groups
+        .iter()
+        .map(|&[start, len]| {
+            let group = ca.slice(start as i64, len as usize);
+            let first = group.get(0);
+            polars_ensure!(
+                first.is_some() && group.iter().all(|v| v == first),
+                ComputeError: "'{}' must be a non-null duration that is the same within every group",
+                variable_name
+            );
+            let duration = Duration::try_parse(first.unwrap())?;
+            ensure_duration_matches_dtype(duration, time_type, variable_name)?;
+            Ok(duration)
+        })
+        .collect()
+}
+
+/// The window of every group if `every_by_group` or `period_by_group` is set.
+fn windows_by_group(
+    df: &DataFrame,
+    groups: &GroupsSlice,
+    options: &DynamicGroupOptions,
+    time_type: &DataType,
+) -> PolarsResult<Vec<Window>> {
+    let every = durations_by_group(
+        df,
+        options.every_by_group.as_ref(),
+        options.every,
+        groups,
+        time_type,
+        "every_by_group",
+    )?;
+    let period = durations_by_group(
+        df,
+        options.period_by_group.as_ref(),
+        options.period,
+        groups,
+        time_type,
+        "period_by_group",
+    )?;
+    every
+        .into_iter()
+        .zip(period)
+        .map(|(every, period)| {
+            polars_ensure!(!every.negative, ComputeError: "'every' argument must be positive");
+            Ok(Window::new(every, period, options.offset))
+        })
+        .collect()
+}
+
 fn check_sortedness_slice(v: &[i64]) -> PolarsResult<()> {
     polars_ensure!(v.is_sorted_ascending(), ComputeError: "input data is not sorted");
     Ok(())
@@ -195,18 +324,45 @@ impl Wrap<&DataFrame> {
         options: &DynamicGroupOptions,
     ) -> PolarsResult<(Column, Vec<Column>, GroupPositions)> {
         let time = self.0.column(&options.index_column)?.rechunk();
-        if group_by.is_none() {
+        if let Some(tolerance) = options.clock_skew_tolerance {
+            polars_ensure!(
+                group_by.is_none(),
+                InvalidOperation: "'clock_skew_tolerance' is not supported in combination with 'group_by'"
+            );
+            polars_ensure!(
+                !tolerance.negative() && tolerance.months() == 0,
+                InvalidOperation: "'clock_skew_tolerance' must be a positive duration without months, got {}", tolerance
+            );
+        } else if group_by.is_none() {
             // If by is given, the column must be sorted in the 'by' arg, which we can not check now
             // this will be checked when the groups are materialized.
             time.as_materialized_series()
                 .ensure_sorted_arg("group_by_dynamic")?;
         }
         let time_type = time.dtype();
+        let group_windows = match &group_by {
+            Some(groups)
+                if options.every_by_group.is_some() || options.period_by_group.is_some() =>
+            {
+                Some(windows_by_group(self.0, groups, options, time_type)?)
+            },
+            None => {
+                polars_ensure!(
+                    options.every_by_group.is_none() && options.period_by_group.is_none(),
+                    InvalidOperation: "'every_by_group' and 'period_by_group' require 'group_by'"
+                );
+                None
+            },
+            _ => None,
+        };
 
         polars_ensure!(time.null_count() == 0, ComputeError: "null values in dynamic group_by not supported, fill nulls.");
         ensure_duration_matches_dtype(options.every, time_type, "every")?;
         ensure_duration_matches_dtype(options.offset, time_type, "offset")?;
         ensure_duration_matches_dtype(options.period, time_type, "period")?;
+        if let Some(tolerance) = options.clock_skew_tolerance {
+            ensure_duration_matches_dtype(tolerance, time_type, "clock_skew_tolerance")?;
+        }
 
         use DataType::*;
         let (dt, tu) = match time_type {
@@ -221,6 +377,7 @@ impl Wrap<&DataFrame> {
                 let (out, mut keys, gt) = self.impl_group_by_dynamic(
                     dt,
                     group_by,
+                    group_windows,
                     options,
                     TimeUnit::Nanoseconds,
                     &time_type,
@@ -239,6 +396,7 @@ impl Wrap<&DataFrame> {
                 let (out, mut keys, gt) = self.impl_group_by_dynamic(
                     dt,
                     group_by,
+                    group_windows,
                     options,
                     TimeUnit::Nanoseconds,
                     &time_type,
@@ -257,13 +415,15 @@ impl Wrap<&DataFrame> {
                 dt
             ),
         };
-        self.impl_group_by_dynamic(dt, group_by, options, tu, time_type)
+        self.impl_group_by_dynamic(dt, group_by, group_windows, options, tu, time_type)
     }
 
+    /// `group_windows` overrides the window of every group in `group_by`.
     fn impl_group_by_dynamic(
         &self,
         mut dt: Column,
         group_by: Option<GroupsSlice>,
+        group_windows: Option<Vec<Window>>,
         options: &DynamicGroupOptions,
         tu: TimeUnit,
         time_type: &DataType,
@@ -273,6 +433,11 @@ impl Wrap<&DataFrame> {
             return dt.cast(time_type).map(|s| (s, vec![], Default::default()));
         }
 
+        let sort_idx = match options.clock_skew_tolerance {
+            Some(tolerance) => sort_skewed_index(&mut dt, tolerance, tu)?,
+            None => None,
+        };
+
         // A requirement for the index so we can set this such that downstream code has this info.
         dt.set_sorted_flag(IsSorted::Ascending);
 
@@ -309,16 +474,21 @@ impl Wrap<&DataFrame> {
                 _ => unreachable!(),
             };
 
-        let overlapping = match options.closed_window {
-            ClosedWindow::Both => options.period >= options.every,
-            _ => options.period > options.every,
+        let is_overlapping = |w: &Window| match options.closed_window {
+            ClosedWindow::Both => w.period >= w.every,
+            _ => w.period > w.every,
+        };
+        let overlapping = match &group_windows {
+            Some(windows) => windows.iter().any(is_overlapping),
+            None => is_overlapping(&w),
         };
 
         let groups = if let Some(groups) = group_by.as_ref() {
             let vals = dt.physical().downcast_iter().next().unwrap();
             let ts = vals.values().as_slice();
 
-            let iter = groups.par_iter().map(|[start, len]| {
+            let iter = groups.par_iter().enumerate().map(|(i, [start, len])| {
+                let w = group_windows.as_ref().map_or(w, |windows| windows[i]);
                 let group_offset = *start;
                 let start = *start as usize;
                 let end = start + *len as usize;
@@ -412,6 +582,12 @@ impl Wrap<&DataFrame> {
             bounds.push(upper.into_datetime(tu, tz.clone()).into_column());
         }
 
+        // The windows were computed on the sorted index, map them back to the original rows.
+        let groups = match sort_idx {
+            Some(sort_idx) => unsorted_groups(&groups, &sort_idx),
+            None => groups,
+        };
+
         dt.into_datetime(tu, None)
             .into_column()
             .cast(time_type)
@@ -926,6 +926,30 @@ impl Duration {
         )
     }
 
+    // Round the given ns timestamp to the closest window boundary, rounding ties up.
+    #[inline]
+    pub fn round_ns(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
+        let lower = self.truncate_ns(t, tz)?;
+        let upper = self.add_ns(lower, tz)?;
+        Ok(if t - lower < upper - t { lower } else { upper })
+    }
+
+    // Round the given us timestamp to the closest window boundary, rounding ties up.
+    #[inline]
+    pub fn round_us(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
+        let lower = self.truncate_us(t, tz)?;
+        let upper = self.add_us(lower, tz)?;
+        Ok(if t - lower < upper - t { lower } else { upper })
+    }
+
+    // Round the given ms timestamp to the closest window boundary, rounding ties up.
+    #[inline]
+    pub fn round_ms(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
+        let lower = self.truncate_ms(t, tz)?;
+        let upper = self.add_ms(lower, tz)?;
+        Ok(if t - lower < upper - t { lower } else { upper })
+    }
+
     fn add_impl_month_week_or_day<F, G, J>(
         &self,
         mut t: i64,
@@ -1182,6 +1206,31 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_round_ns() {
+        let t = datetime_to_timestamp_ns(new_datetime(2024, 1, 1, 0, 30, 0, 0).unwrap());
+        let one_hour = Duration::parse("1h");
+        let two_hours = Duration::parse("2h");
+
+        // Ties round up.
+        assert_eq!(
+            one_hour.round_ns(t, None).unwrap(),
+            datetime_to_timestamp_ns(new_datetime(2024, 1, 1, 1, 0, 0, 0).unwrap())
+        );
+        assert_eq!(
+            two_hours.round_ns(t, None).unwrap(),
+            datetime_to_timestamp_ns(new_datetime(2024, 1, 1, 0, 0, 0, 0).unwrap())
+        );
+
+        // Calendar durations pick the closer of the two boundaries.
+        let one_month = Duration::parse("1mo");
+        let t = datetime_to_timestamp_ns(new_datetime(2024, 2, 20, 0, 0, 0, 0).unwrap());
+        assert_eq!(
+            one_month.round_ns(t, None).unwrap(),
+            datetime_to_timestamp_ns(new_datetime(2024, 3, 1, 0, 0, 0, 0).unwrap())
+        );
+    }
+
     #[test]
     fn test_display() {
         let duration = Duration::parse("1h");
@@ -77,6 +77,43 @@ fn test_feb_date_range() {
     assert_eq!(dates, expected);
 }
 
+#[test]
+#[cfg(feature = "dtype-date")]
+fn test_business_date_range() {
+    let date = |d| NaiveDate::from_ymd_opt(2024, 1, d).unwrap();
+    let to_vec = |ca: DateChunked| ca.as_date_iter().map(Option::unwrap).collect::<Vec<_>>();
+
+    // Monday to the following Monday, both inclusive.
+    let out = business_date_range("".into(), date(1), date(8), BusinessDayRoll::Forward, &[]);
+    assert_eq!(
+        to_vec(out.unwrap()),
+        [date(1), date(2), date(3), date(4), date(5), date(8)]
+    );
+    let out = business_date_range(
+        "".into(),
+        date(1),
+        date(8),
+        BusinessDayRoll::Forward,
+        &[date(1)],
+    );
+    assert_eq!(to_vec(out.unwrap()).len(), 5);
+
+    // Monday to Saturday, rolling the end date.
+    let out = business_date_range("".into(), date(1), date(6), BusinessDayRoll::Backward, &[]);
+    assert_eq!(to_vec(out.unwrap()).last(), Some(&date(5)));
+    let out = business_date_range("".into(), date(1), date(6), BusinessDayRoll::Forward, &[]);
+    assert_eq!(to_vec(out.unwrap()).last(), Some(&date(8)));
+
+    // Saturday 2024-03-30: rolling forward would cross into April.
+    let start = NaiveDate::from_ymd_opt(2024, 3, 25).unwrap();
+    let end = NaiveDate::from_ymd_opt(2024, 3, 30).unwrap();
+    let out = business_date_range("".into(), start, end, BusinessDayRoll::Modified, &[]);
+    assert_eq!(
+        to_vec(out.unwrap()).last(),
+        NaiveDate::from_ymd_opt(2024, 3, 29).as_ref()
+    );
+}
+
 fn print_ns(ts: &[i64]) {
     for ts in ts {
         println!("{}", timestamp_ns_to_datetime(*ts));
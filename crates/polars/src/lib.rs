@@ -24,7 +24,7 @@
 //!             .last()
 //!             .alias("last_foo_ranked_by_ham"),
 //!         // every expression runs in parallel
-//!         col("foo").cum_min(false).alias("cumulative_min_per_group"),
+//!         col("foo").cum_min(false, true).alias("cumulative_min_per_group"),
 //!         // every expression runs in parallel
 //!         col("foo").reverse().implode().alias("reverse_group"),
 //!     ]);
@@ -1,3 +1,4 @@
+use polars_core::series::IsSorted;
 use polars_core::utils::{accumulate_dataframes_vertical, split_df};
 
 use super::*;
@@ -599,6 +600,52 @@ fn test_multi_joins_with_duplicates() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_inner_join_sorted_keys_with_nulls() -> PolarsResult<()> {
+    let mut key_a = Series::new("a".into(), &[None, Some(1i32), Some(2), Some(2), Some(3)]);
+    let mut key_b = Series::new("b".into(), &[Some(2i32), Some(3), Some(4), None, None]);
+    key_a.set_sorted_flag(IsSorted::Ascending);
+    key_b.set_sorted_flag(IsSorted::Ascending);
+    let df_a = DataFrame::new_infer_height(vec![
+        key_a.into_column(),
+        Column::new("x".into(), &[0i32, 1, 2, 3, 4]),
+    ])?;
+    let df_b = DataFrame::new_infer_height(vec![
+        key_b.into_column(),
+        Column::new("y".into(), &[10i32, 11, 12, 13, 14]),
+    ])?;
+
+    let out = df_a.join(&df_b, ["a"], ["b"], JoinType::Inner.into(), None)?;
+    assert_eq!(
+        Vec::from(out.column("x")?.i32()?),
+        &[Some(2), Some(3), Some(4)]
+    );
+    assert_eq!(
+        Vec::from(out.column("y")?.i32()?),
+        &[Some(10), Some(10), Some(11)]
+    );
+
+    let out = df_a.join(
+        &df_b,
+        ["a"],
+        ["b"],
+        JoinArgs {
+            nulls_equal: true,
+            ..JoinArgs::new(JoinType::Inner)
+        },
+        None,
+    )?;
+    assert_eq!(
+        Vec::from(out.column("x")?.i32()?),
+        &[Some(0), Some(0), Some(2), Some(3), Some(4)]
+    );
+    assert_eq!(
+        Vec::from(out.column("y")?.i32()?),
+        &[Some(13), Some(14), Some(10), Some(10), Some(11)]
+    );
+    Ok(())
+}
+
 #[test]
 #[cfg_attr(miri, ignore)]
 fn test_join_floats() -> PolarsResult<()> {
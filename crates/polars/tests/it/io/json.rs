@@ -102,3 +102,35 @@ fn read_ndjson_with_trailing_newline() {
     .unwrap();
     assert!(expected.equals(&df));
 }
+
+#[test]
+fn write_json_formats() -> PolarsResult<()> {
+    let mut df = df! {
+        "a" => [1, 2],
+        "b" => [Some("x"), None]
+    }?;
+
+    let write = |df: &mut DataFrame, format| -> PolarsResult<String> {
+        let mut buf = vec![];
+        JsonWriter::new(&mut buf)
+            .with_json_format(format)
+            .finish(df)?;
+        Ok(String::from_utf8(buf).unwrap())
+    };
+
+    assert_eq!(
+        write(&mut df, JsonFormat::Json)?,
+        r#"[{"a":1,"b":"x"},{"a":2,"b":null}]"#
+    );
+    assert_eq!(
+        write(&mut df, JsonFormat::JsonLines)?,
+        "{\"a\":1,\"b\":\"x\"}\n{\"a\":2,\"b\":null}\n"
+    );
+    assert_eq!(write(&mut df, JsonFormat::Values)?, r#"[[1,"x"],[2,null]]"#);
+
+    let err = JsonReader::new(Cursor::new(r#"[[1,"x"]]"#))
+        .with_json_format(JsonFormat::Values)
+        .finish();
+    assert!(err.is_err());
+    Ok(())
+}
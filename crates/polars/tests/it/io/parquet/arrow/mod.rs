@@ -673,9 +673,10 @@ fn integration_write(
         compression: CompressionOptions::Uncompressed,
         version: Version::V1,
         data_page_size: None,
+        max_footer_size: None,
     };
 
-    let encodings = get_encodings(schema);
+    let encodings = get_encodings(schema)?;
 
     let row_groups =
         RowGroupIterator::try_new(chunks.iter().cloned().map(Ok), schema, options, encodings)?;
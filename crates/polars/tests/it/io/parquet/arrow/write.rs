@@ -37,6 +37,7 @@ fn round_trip_opt_stats(
         compression,
         version,
         data_page_size: None,
+        max_footer_size: None,
     };
 
     let iter = vec![RecordBatchT::try_new(
@@ -469,3 +470,37 @@ fn nested_edge_list_struct_list_nullable() -> PolarsResult<()> {
         vec![Encoding::Plain],
     )
 }
+
+#[test]
+fn encoding_from_field_metadata() -> PolarsResult<()> {
+    use polars_parquet::parquet::read::{get_page_iterator, read_metadata};
+
+    let annotated = |dtype| {
+        let metadata = Metadata::from([(
+            PlSmallStr::from_static(ENCODING_METADATA_KEY),
+            PlSmallStr::from_static("delta"),
+        )]);
+        Field::new("a".into(), dtype, true).with_metadata(metadata)
+    };
+
+    let array = Int64Array::from_vec((0..100).collect());
+    let schema = ArrowSchema::from_iter([annotated(ArrowDataType::Int64)]);
+    let chunk = RecordBatchT::try_new(array.len(), Arc::new(schema.clone()), vec![array.boxed()])?;
+    let data = integration_write(&schema, &[chunk])?;
+
+    let mut reader = Cursor::new(Buffer::from_vec(data));
+    let metadata = read_metadata(&mut reader)?;
+    let column = &metadata.row_groups[0].parquet_columns()[0];
+    for page in get_page_iterator(column, reader, vec![], usize::MAX)? {
+        let CompressedPage::Data(page) = page? else {
+            panic!("expected only data pages");
+        };
+        let encoding = Encoding::try_from(page.header().encoding()).unwrap();
+        assert_eq!(encoding, Encoding::DeltaBinaryPacked);
+    }
+
+    // Delta encoding is not supported for floats.
+    let schema = ArrowSchema::from_iter([annotated(ArrowDataType::Float64)]);
+    assert!(get_encodings(&schema).is_err());
+    Ok(())
+}
@@ -15,12 +15,16 @@ mod utils;
 use std::fs::File;
 
 use dictionary::DecodedDictPage;
+use polars::io::parquet::write::ParquetWriter;
 use polars_buffer::Buffer;
 use polars_parquet::parquet::encoding::hybrid_rle::{HybridRleChunk, HybridRleDecoder};
 use polars_parquet::parquet::error::{ParquetError, ParquetResult};
 use polars_parquet::parquet::metadata::ColumnChunkMetadata;
 use polars_parquet::parquet::page::DataPage;
-use polars_parquet::parquet::read::{BasicDecompressor, get_column_iterator, read_metadata};
+use polars_parquet::parquet::read::{
+    BasicDecompressor, get_column_iterator, get_page_iterator, read_metadata,
+    read_metadata_from_bytes, read_offset_indexes,
+};
 use polars_parquet::parquet::schema::Repetition;
 use polars_parquet::parquet::schema::types::{GroupConvertedType, ParquetType};
 use polars_parquet::parquet::types::int96_to_i64_ns;
@@ -399,3 +403,68 @@ fn test_metadata() -> ParquetResult<()> {
     assert_eq!(expected, result);
     Ok(())
 }
+
+#[test]
+fn test_metadata_from_bytes() -> ParquetResult<()> {
+    let mut testdata = get_path();
+    testdata.push("alltypes_plain.parquet");
+    let bytes = std::fs::read(&testdata).unwrap();
+    let expected = read_metadata(&mut File::open(testdata).unwrap())?;
+
+    let metadata = read_metadata_from_bytes(Buffer::from_vec(bytes.clone()))?;
+    assert_eq!(metadata.num_rows, expected.num_rows);
+    assert_eq!(metadata.row_groups.len(), expected.row_groups.len());
+    assert_eq!(
+        metadata.schema_descr.columns().len(),
+        expected.schema_descr.columns().len()
+    );
+    for (column, expected) in metadata
+        .schema_descr
+        .columns()
+        .iter()
+        .zip(expected.schema_descr.columns())
+    {
+        assert_eq!(column.path_in_schema, expected.path_in_schema);
+        assert_eq!(column.descriptor, expected.descriptor);
+    }
+
+    let truncated = Buffer::from_vec(bytes[..bytes.len() - 1].to_vec());
+    assert!(read_metadata_from_bytes(truncated).is_err());
+    Ok(())
+}
+
+#[test]
+fn seek_to_row_with_offset_index() -> ParquetResult<()> {
+    let mut df = df!("col" => (0..100_000i64).collect::<Vec<_>>()).unwrap();
+    let mut buf = vec![];
+    ParquetWriter::new(&mut buf)
+        .with_data_page_size(Some(4 * 1024))
+        .finish(&mut df)
+        .unwrap();
+
+    let mut reader = Cursor::new(Buffer::from_vec(buf));
+    let mut metadata = read_metadata(&mut reader)?;
+    read_offset_indexes(&mut reader, &mut metadata)?;
+
+    let row_group = &metadata.row_groups[0];
+    let offset_index = row_group.column_offset_index(0).unwrap();
+    assert!(offset_index.page_locations.len() > 1);
+
+    let column = &row_group.parquet_columns()[0];
+    let pages = get_page_iterator(column, reader, vec![], usize::MAX)?;
+    let mut pages = BasicDecompressor::new(pages, vec![]);
+    let dict = pages
+        .read_dict_page()?
+        .map(|dict| dictionary::deserialize(&dict, column.physical_type()))
+        .transpose()?;
+
+    let first_row = pages.seek_to_row(offset_index, 50_000)?;
+    assert!(first_row > 0 && first_row <= 50_000);
+
+    let page = pages.next().unwrap()?.decompress(&mut pages)?;
+    let Array::Int64(values) = page_to_array(&page, dict.as_ref())? else {
+        unreachable!()
+    };
+    assert_eq!(values[50_000 - first_row], Some(50_000));
+    Ok(())
+}
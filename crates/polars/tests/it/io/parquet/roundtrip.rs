@@ -1,8 +1,10 @@
 use std::io::Cursor;
 use std::sync::Arc;
 
-use arrow::array::{ArrayRef, Utf8ViewArray};
-use arrow::datatypes::{ArrowSchema, Field};
+use arrow::array::{
+    ArrayRef, BooleanArray, DictionaryArray, FixedSizeBinaryArray, PrimitiveArray, Utf8ViewArray,
+};
+use arrow::datatypes::{ArrowDataType, ArrowSchema, Field};
 use arrow::record_batch::RecordBatchT;
 use polars_buffer::Buffer;
 use polars_error::PolarsResult;
@@ -28,6 +30,7 @@ fn round_trip(
         compression,
         version,
         data_page_size: None,
+        max_footer_size: None,
     };
 
     let iter = vec![RecordBatchT::try_new(
@@ -89,3 +92,81 @@ fn roundtrip_binview() -> PolarsResult<()> {
         vec![Encoding::Plain],
     )
 }
+
+#[test]
+fn all_null_statistics_have_no_min_max() -> PolarsResult<()> {
+    let len = 4;
+    let dict_keys = PrimitiveArray::<u32>::new_null(ArrowDataType::UInt32, len);
+    let dict_values = Utf8ViewArray::from_slice_values(["a", "b"]).boxed();
+    let cases: Vec<(ArrayRef, Encoding)> = vec![
+        (
+            PrimitiveArray::<i64>::new_null(ArrowDataType::Int64, len).boxed(),
+            Encoding::Plain,
+        ),
+        (
+            PrimitiveArray::<f64>::new_null(ArrowDataType::Float64, len).boxed(),
+            Encoding::Plain,
+        ),
+        (
+            BooleanArray::new_null(ArrowDataType::Boolean, len).boxed(),
+            Encoding::Plain,
+        ),
+        (
+            Utf8ViewArray::new_null(ArrowDataType::Utf8View, len).boxed(),
+            Encoding::Plain,
+        ),
+        (
+            FixedSizeBinaryArray::new_null(ArrowDataType::FixedSizeBinary(3), len).boxed(),
+            Encoding::Plain,
+        ),
+        (
+            DictionaryArray::try_from_keys(dict_keys, dict_values, false)?.boxed(),
+            Encoding::RleDictionary,
+        ),
+    ];
+
+    for (array, encoding) in cases {
+        let field = Field::new("a1".into(), array.dtype().clone(), true);
+        let schema = ArrowSchema::from_iter([field]);
+        let options = WriteOptions {
+            statistics: StatisticsOptions::full(),
+            compression: CompressionOptions::Uncompressed,
+            version: Version::V2,
+            data_page_size: None,
+            max_footer_size: None,
+        };
+
+        let iter = vec![RecordBatchT::try_new(
+            array.len(),
+            Arc::new(schema.clone()),
+            vec![array.clone()],
+        )];
+        let row_groups = RowGroupIterator::try_new(
+            iter.into_iter(),
+            &schema,
+            options,
+            Buffer::from_iter([vec![encoding]]),
+        )?;
+
+        let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+        for group in row_groups {
+            writer.write(u64::MAX, group?)?;
+        }
+        writer.end(None)?;
+
+        let mut reader = Cursor::new(writer.into_inner().into_inner());
+        let md = read_metadata(&mut reader)?;
+        let column = &md.row_groups[0].parquet_columns()[0];
+        let statistics = column.statistics(&md.footer_buf).unwrap()?.serialize();
+
+        assert_eq!(
+            statistics.null_count,
+            Some(len as i64),
+            "{:?}",
+            array.dtype()
+        );
+        assert_eq!(statistics.min_value, None, "{:?}", array.dtype());
+        assert_eq!(statistics.max_value, None, "{:?}", array.dtype());
+    }
+    Ok(())
+}
@@ -55,6 +55,7 @@ fn test_column(column: &str, compression: CompressionOptions) -> ParquetResult<(
     let options = WriteOptions {
         write_statistics: true,
         version: Version::V1,
+        max_footer_size: None,
     };
 
     // prepare schema
@@ -180,6 +181,7 @@ fn basic() -> ParquetResult<()> {
     let options = WriteOptions {
         write_statistics: false,
         version: Version::V1,
+        max_footer_size: None,
     };
 
     let schema = SchemaDescriptor::new(
@@ -299,3 +301,123 @@ fn test_read_parquet_with_columns() {
     assert_eq!(df_read.shape(), (3, 2));
     df_read.equals(&expected);
 }
+
+#[test]
+fn test_write_offset_index_page_boundaries() -> ParquetResult<()> {
+    use polars_parquet::parquet::page::CompressedPage;
+    use polars_parquet::parquet::read::{get_page_iterator, read_offset_indexes};
+
+    let num_rows = 100_000;
+    let values = (0..num_rows as i64)
+        .map(|v| (v % 7 != 0).then_some(v))
+        .collect::<Vec<_>>();
+    let mut df = df!("col" => values).unwrap();
+    let mut buf = vec![];
+    ParquetWriter::new(&mut buf)
+        .with_data_page_size(Some(4 * 1024))
+        .finish(&mut df)
+        .unwrap();
+
+    let mut reader = Cursor::new(Buffer::from_vec(buf));
+    let mut metadata = read_metadata(&mut reader)?;
+    read_offset_indexes(&mut reader, &mut metadata)?;
+
+    let row_group = &metadata.row_groups[0];
+    let column = &row_group.parquet_columns()[0];
+    let offset_index = row_group.column_offset_index(0).unwrap();
+    let locations = &offset_index.page_locations;
+    assert!(locations.len() > 1);
+    assert_eq!(locations[0].first_row_index, 0);
+
+    let mut pages = get_page_iterator(column, reader, vec![], usize::MAX)?;
+    for (i, location) in locations.iter().enumerate() {
+        let first_row = location.first_row_index as usize;
+        assert_eq!(pages.seek_to_row(offset_index, first_row)?, first_row);
+
+        let Some(CompressedPage::Data(page)) = pages.next().transpose()? else {
+            panic!("expected a data page at page location {i}");
+        };
+
+        let end_row = locations
+            .get(i + 1)
+            .map_or(num_rows, |next| next.first_row_index as usize);
+        assert_eq!(page.num_values(), end_row - first_row);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_estimate_footer_size() -> ParquetResult<()> {
+    let array = alltypes_plain("bigint_col");
+    let Array::Int64(values) = &array else {
+        unreachable!()
+    };
+    let num_rows = values.len() as u64;
+
+    let options = WriteOptions {
+        write_statistics: true,
+        version: Version::V1,
+        max_footer_size: None,
+    };
+    let schema = SchemaDescriptor::new(
+        "schema".into(),
+        vec![ParquetType::from_physical(
+            "col".into(),
+            PhysicalType::Int64,
+        )],
+    );
+    let descriptor = schema.columns()[0].descriptor.clone();
+    let mut writer = FileWriter::new(Cursor::new(vec![]), schema, options, None);
+
+    for _ in 0..100 {
+        let pages = DynStreamingIterator::new(Compressor::new_from_vec(
+            DynIter::new(std::iter::once(array_to_page(
+                &array,
+                &options,
+                &descriptor,
+            ))),
+            CompressionOptions::Snappy,
+            vec![],
+        ));
+        writer.write(num_rows, DynIter::new(std::iter::once(Ok(pages))))?;
+    }
+
+    let estimate = writer.estimate_footer_size(None)?;
+    writer.end(None)?;
+
+    let data = writer.into_inner().into_inner();
+    let metadata_len = i32::from_le_bytes(data[data.len() - 8..data.len() - 4].try_into().unwrap());
+    assert_eq!(estimate, metadata_len as u64 + 8);
+    Ok(())
+}
+
+#[test]
+fn test_max_footer_size() -> ParquetResult<()> {
+    let schema = SchemaDescriptor::new(
+        "schema".into(),
+        vec![ParquetType::from_physical(
+            "col".into(),
+            PhysicalType::Int64,
+        )],
+    );
+    let options = |max_footer_size| WriteOptions {
+        write_statistics: true,
+        version: Version::V1,
+        max_footer_size,
+    };
+
+    let mut writer = FileWriter::new(Cursor::new(vec![]), schema.clone(), options(Some(4)), None);
+    assert!(writer.end(None).is_err());
+
+    let mut writer = FileWriter::new(Cursor::new(vec![]), schema, options(None), None);
+    let file_size = writer.end(None)?;
+    let mut data = writer.into_inner();
+
+    let limit_error =
+        polars_parquet::parquet::read::read_metadata_with_limit(&mut data, file_size, 4);
+    assert!(limit_error.is_err());
+    let metadata =
+        polars_parquet::parquet::read::read_metadata_with_limit(&mut data, file_size, file_size)?;
+    assert_eq!(metadata.num_rows, 0);
+    Ok(())
+}
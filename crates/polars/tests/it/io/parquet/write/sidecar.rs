@@ -21,6 +21,7 @@ fn basic() -> Result<(), ParquetError> {
             WriteOptions {
                 write_statistics: true,
                 version: Version::V2,
+                max_footer_size: None,
             },
             None,
         );
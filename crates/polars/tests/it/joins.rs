@@ -56,3 +56,41 @@ fn join_empty_datasets() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(all(feature = "lazy", feature = "semi_anti_join"))]
+fn anti_join_composite_key() -> PolarsResult<()> {
+    let left = df! {
+        "a" => [Some(1), Some(1), Some(2), Some(2), None],
+        "b" => [Some("x"), Some("y"), Some("x"), None, Some("x")],
+        "v" => [0, 1, 2, 3, 4],
+    }?
+    .lazy();
+    let right = df! {
+        "a" => [Some(1), Some(2), None],
+        "b" => [Some("y"), None, Some("x")],
+    }?
+    .lazy();
+    let on = [col("a"), col("b")];
+
+    let out = left
+        .clone()
+        .join(right.clone(), on.clone(), on.clone(), JoinType::Anti.into())
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("v")?.i32()?),
+        &[Some(0), Some(2), Some(3), Some(4)]
+    );
+
+    let out = left
+        .join_builder()
+        .with(right)
+        .left_on(on.clone())
+        .right_on(on)
+        .how(JoinType::Anti)
+        .join_nulls(true)
+        .finish()
+        .collect()?;
+    assert_eq!(Vec::from(out.column("v")?.i32()?), &[Some(0), Some(2)]);
+    Ok(())
+}
@@ -113,3 +113,652 @@ fn fuzz_exprs() {
         }
     }
 }
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_struct_apply_fields() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1i32, 2],
+        "b" => [3i32, 4],
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([as_struct(vec![col("a"), col("b")])
+            .struct_()
+            .apply_fields(|_, e| e.cast(DataType::Float64))
+            .alias("s")])
+        .unnest(cols(["s"]), None)
+        .collect()?;
+
+    let expected = df![
+        "a" => [1.0f64, 2.0],
+        "b" => [3.0f64, 4.0],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "extract_groups")]
+fn test_str_extract_groups_named() -> PolarsResult<()> {
+    let pat = r"(?P<year>\d{4})-(?P<month>\d{2})-(?P<day>\d{2})";
+    let lf = df!["date" => [Some("2024-01-15"), Some("not a date"), None]]?
+        .lazy()
+        .select([col("date").str().extract_groups(pat)?]);
+
+    let schema = lf.clone().collect_schema()?;
+    let DataType::Struct(fields) = schema.get("date").unwrap() else {
+        panic!("expected a struct")
+    };
+    let names = fields.iter().map(|f| f.name().as_str()).collect::<Vec<_>>();
+    assert_eq!(names, ["year", "month", "day"]);
+
+    let out = lf.unnest(cols(["date"]), None).collect()?;
+    let expected = df![
+        "year" => [Some("2024"), None, None],
+        "month" => [Some("01"), None, None],
+        "day" => [Some("15"), None, None],
+    ]?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_str_starts_ends_with_per_row() -> PolarsResult<()> {
+    let df = df![
+        "fruit" => ["apple", "banana", "cherry"],
+        "prefix" => ["app", "ban", "x"],
+        "suffix" => ["le", "na", "rr"],
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("fruit")
+                .str()
+                .starts_with(col("prefix"))
+                .alias("starts"),
+            col("fruit").str().ends_with(col("suffix")).alias("ends"),
+        ])
+        .collect()?;
+
+    let expected = [Some(true), Some(true), Some(false)];
+    assert_eq!(Vec::from(out.column("starts")?.bool()?), &expected);
+    assert_eq!(Vec::from(out.column("ends")?.bool()?), &expected);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "business", feature = "dtype-date"))]
+fn test_add_business_days_skips_weekend_and_holidays() -> PolarsResult<()> {
+    // 2024-01-05 is a Friday, 2024-01-08 the following Monday.
+    let friday = 19727;
+    let monday = friday + 3;
+    let date = |days: Vec<i32>| {
+        Int32Chunked::new("".into(), &days)
+            .into_date()
+            .into_series()
+    };
+    let df = df!["dt" => date(vec![friday])]?;
+    let week_mask = [true, true, true, true, true, false, false];
+
+    let out = df
+        .lazy()
+        .select([
+            col("dt")
+                .dt()
+                .add_business_days(lit(1), week_mask, lit(date(vec![])), Roll::Raise)
+                .alias("plain"),
+            col("dt")
+                .dt()
+                .add_business_days(lit(1), week_mask, lit(date(vec![monday])), Roll::Raise)
+                .alias("holiday"),
+        ])
+        .collect()?;
+
+    assert_eq!(out.column("plain")?.date()?.physical().get(0), Some(monday));
+    assert_eq!(
+        out.column("holiday")?.date()?.physical().get(0),
+        Some(monday + 1)
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-array")]
+fn test_arr_get_strides_fixed_size_list() -> PolarsResult<()> {
+    let rows = (0..4)
+        .map(|i| Series::new("".into(), [1.0f32, 2.0, 3.0].map(|v| v + 3.0 * i as f32)))
+        .collect::<Vec<_>>();
+    let a = Series::new("a".into(), rows).cast(&DataType::Array(Box::new(DataType::Float32), 3))?;
+
+    // The slice gives the inner values a non-zero offset.
+    let out = df!["a" => a]?
+        .slice(1, 3)
+        .lazy()
+        .select([
+            col("a").arr().get(lit(1i64), true).alias("second"),
+            col("a").arr().get(lit(-1i64), true).alias("last"),
+            col("a").arr().get(lit(3i64), true).alias("oob"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("second")?.f32()?),
+        &[Some(5.0), Some(8.0), Some(11.0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("last")?.f32()?),
+        &[Some(6.0), Some(9.0), Some(12.0)]
+    );
+    assert_eq!(out.column("oob")?.null_count(), 3);
+    Ok(())
+}
+
+#[test]
+fn test_gather_every_by() -> PolarsResult<()> {
+    let df = df![
+        "a" => (0..9).collect::<Vec<i32>>(),
+        "n" => [3; 9],
+        "offset" => [1; 9],
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("a").gather_every(3, 1).alias("static"),
+            col("a")
+                .gather_every_by(col("n").first(), col("offset").first())
+                .alias("dynamic"),
+        ])
+        .collect()?;
+
+    for name in ["static", "dynamic"] {
+        assert_eq!(
+            Vec::from(out.column(name)?.i32()?),
+            &[Some(1), Some(4), Some(7)]
+        );
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "round_series")]
+fn test_clip_by() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 5, 10, 7],
+        "lower" => [Some(2), Some(6), None, Some(0)],
+        "upper" => [Some(3), Some(8), Some(4), None],
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([col("a").clip_by(col("lower"), col("upper"))])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("a")?.i32()?),
+        &[Some(2), Some(6), Some(4), Some(7)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_sample_systematic() -> PolarsResult<()> {
+    let df = df!["a" => (0..100).collect::<Vec<i32>>()]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("a").sample_systematic(lit(10), lit(3))])
+        .collect()?;
+
+    let expected = (0..10).map(|i| Some(10 * i + 3)).collect::<Vec<_>>();
+    assert_eq!(Vec::from(out.column("a")?.i32()?), expected);
+
+    // The same checks as `Series::sample_systematic` apply.
+    let sample_err = |n: i32, offset: i32| {
+        df.clone()
+            .lazy()
+            .select([col("a").sample_systematic(lit(n), lit(offset))])
+            .collect()
+            .unwrap_err()
+            .to_string()
+    };
+    assert!(sample_err(101, 0).contains("systematic sample of 101 elements"));
+    assert!(sample_err(10, 10).contains("sampling interval 10"));
+    assert!(sample_err(-1, 0).contains("conversion from `i32` to `u64` failed"));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "tdigest")]
+fn test_tdigest_group_by() -> PolarsResult<()> {
+    let df = df![
+        "g" => (0..10_000).map(|i| i % 2).collect::<Vec<i32>>(),
+        "a" => (0..10_000).map(|i| (i / 2 + (i % 2) * 5_000) as f64).collect::<Vec<_>>(),
+    ]?;
+
+    let sketches = df
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([col("a").tdigest(100.0)])
+        .collect()?;
+    assert_eq!(sketches.column("a")?.dtype(), &DataType::Binary);
+
+    // Every group is summarized by its own sketch.
+    let out = sketches
+        .clone()
+        .lazy()
+        .select([col("a").tdigest_quantile(0.5)])
+        .collect()?;
+    let medians = out.column("a")?.f64()?;
+    assert!((medians.get(0).unwrap() - 2_500.0).abs() < 50.0);
+    assert!((medians.get(1).unwrap() - 7_500.0).abs() < 50.0);
+
+    // The sketches of the groups merge into a sketch of all values.
+    let out = sketches
+        .lazy()
+        .select([col("a").tdigest_merge().tdigest_quantile(0.5)])
+        .collect()?;
+    assert_eq!(out.height(), 1);
+    assert!((out.column("a")?.f64()?.get(0).unwrap() - 5_000.0).abs() < 50.0);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "replace")]
+fn test_replace_many() -> PolarsResult<()> {
+    let df = df!["a" => [Some(1), Some(2), Some(3), None, Some(2)]]?;
+    let old = || lit(Series::new("".into(), [1, 2]));
+    let new = || lit(Series::new("".into(), [10, 20]));
+
+    let out = df
+        .lazy()
+        .select([
+            col("a").replace(old(), new()).alias("passthrough"),
+            col("a")
+                .replace_strict(old(), new(), Some(lit(-1)), None::<DataType>)
+                .alias("default"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("passthrough")?.i32()?),
+        &[Some(10), Some(20), Some(3), None, Some(20)]
+    );
+    assert_eq!(
+        Vec::from(out.column("default")?.i32()?),
+        &[Some(10), Some(20), Some(-1), Some(-1), Some(20)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "temporal", feature = "timezones"))]
+fn test_format_epoch_timestamps() -> PolarsResult<()> {
+    let df = df!["ts" => [0i64, 86_400_000]]?;
+    let tz = TimeZone::opt_try_new(Some("America/New_York"))?;
+
+    // Casting integers to a datetime reinterprets them as UTC timestamps without a copy.
+    let out = df
+        .lazy()
+        .select([
+            col("ts")
+                .cast(DataType::Datetime(TimeUnit::Milliseconds, None))
+                .dt()
+                .to_string("%Y-%m-%d")
+                .alias("utc"),
+            col("ts")
+                .cast(DataType::Datetime(TimeUnit::Milliseconds, tz))
+                .dt()
+                .to_string("%Y-%m-%d %H:%M")
+                .alias("local"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("utc")?.str()?),
+        &[Some("1970-01-01"), Some("1970-01-02")]
+    );
+    assert_eq!(
+        Vec::from(out.column("local")?.str()?),
+        &[Some("1969-12-31 19:00"), Some("1970-01-01 19:00")]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "ewma_by", feature = "dtype-datetime"))]
+fn test_ewm_mean_by_irregular_times() -> PolarsResult<()> {
+    const HOUR: i64 = 3_600_000;
+    // Gaps of 1h, 2h and 4h with a half-life of 2h give `1 - alpha` of 0.5^0.5, 0.5 and 0.25.
+    let df = df![
+        "t" => [0, HOUR, 3 * HOUR, 7 * HOUR],
+        "v" => [0.0f64, 4.0, 8.0, 16.0],
+    ]?;
+    let expected = [
+        0.0,
+        4.0 - 2.0 * 2f64.sqrt(),
+        6.0 - 2f64.sqrt(),
+        13.5 - 2f64.sqrt() / 4.0,
+    ];
+
+    // Rows are given out of order as well, the output keeps the input order.
+    for order in [[0 as IdxSize, 1, 2, 3], [2, 0, 3, 1]] {
+        let idx = IdxCa::from_vec("".into(), order.to_vec());
+        let out = df
+            .take(&idx)?
+            .lazy()
+            .select([col("v").ewm_mean_by(
+                col("t").cast(DataType::Datetime(TimeUnit::Milliseconds, None)),
+                Duration::parse("2h"),
+            )])
+            .collect()?;
+
+        let out = out.column("v")?.f64()?;
+        for (i, &j) in order.iter().enumerate() {
+            let got = out.get(i).unwrap();
+            assert!((got - expected[j as usize]).abs() < 1e-12);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "rolling_window_by",
+    feature = "temporal",
+    feature = "dtype-datetime"
+))]
+fn test_rolling_sum_by_irregular_ms_times() -> PolarsResult<()> {
+    let df = df![
+        "t" => [0i64, 200, 900, 1000, 1500, 2600],
+        "v" => [1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0],
+    ]?;
+    let window = |closed_window, min_periods| RollingOptionsDynamicWindow {
+        window_size: Duration::parse("1s"),
+        min_periods,
+        closed_window,
+        fn_params: None,
+    };
+    let t = col("t").cast(DataType::Datetime(TimeUnit::Milliseconds, None));
+
+    let out = df
+        .lazy()
+        .select([
+            col("v")
+                .rolling_sum_by(t.clone(), window(ClosedWindow::Right, 1))
+                .alias("right"),
+            col("v")
+                .rolling_sum_by(t.clone(), window(ClosedWindow::Both, 1))
+                .alias("both"),
+            col("v")
+                .rolling_sum_by(t, window(ClosedWindow::Right, 2))
+                .alias("min_periods"),
+        ])
+        .collect()?;
+
+    // The window of every row is (t - 1s, t], or [t - 1s, t] when closed on both sides.
+    assert_eq!(
+        Vec::from(out.column("right")?.f64()?),
+        &[
+            Some(1.0),
+            Some(3.0),
+            Some(6.0),
+            Some(9.0),
+            Some(12.0),
+            Some(6.0)
+        ]
+    );
+    assert_eq!(
+        Vec::from(out.column("both")?.f64()?),
+        &[
+            Some(1.0),
+            Some(3.0),
+            Some(6.0),
+            Some(10.0),
+            Some(12.0),
+            Some(6.0)
+        ]
+    );
+    assert_eq!(
+        Vec::from(out.column("min_periods")?.f64()?),
+        &[None, Some(3.0), Some(6.0), Some(9.0), Some(12.0), None]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "temporal", feature = "dtype-datetime"))]
+fn test_epoch_seconds_floors_subseconds() -> PolarsResult<()> {
+    // 2024-01-01 00:00:00.999999, 1970-01-01 00:00:00 and 1969-12-31 23:59:59.999999
+    let df = df!["t" => [1_704_067_200_999_999i64, 0, -1]]?;
+    let out = df
+        .lazy()
+        .select([col("t")
+            .cast(DataType::Datetime(TimeUnit::Microseconds, None))
+            .dt()
+            .epoch_seconds()])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("t")?.i64()?),
+        &[Some(1_704_067_200), Some(0), Some(-1)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "strings", feature = "regex"))]
+fn test_str_split_regex() -> PolarsResult<()> {
+    let df = df!["s" => [Some("one 1 two 2"), None, Some("three")]]?;
+    let out = df
+        .lazy()
+        .select([col("s").str().split_regex(lit(r"\s+\d+\s*"), true)])
+        .collect()?;
+
+    let out = out.column("s")?.list()?;
+    let row = |i| -> PolarsResult<Option<Vec<Option<String>>>> {
+        Ok(match out.get_as_series(i) {
+            Some(s) => Some(s.str()?.iter().map(|v| v.map(String::from)).collect()),
+            None => None,
+        })
+    };
+    assert_eq!(
+        row(0)?,
+        Some(vec![
+            Some("one".into()),
+            Some("two".into()),
+            Some("".into())
+        ])
+    );
+    assert_eq!(row(1)?, None);
+    assert_eq!(row(2)?, Some(vec![Some("three".into())]));
+    Ok(())
+}
+
+#[test]
+fn test_expr_pipe() -> PolarsResult<()> {
+    let piped = col("a").pipe(|e| e + lit(1)).pipe(|e| e * lit(2));
+    assert!(piped == (col("a") + lit(1)) * lit(2));
+    assert!(col("a").pipe_if(false, |e| e.alias("b")) == col("a"));
+
+    let df = df!["a" => [1, 2, 3]]?;
+    let out = df
+        .lazy()
+        .select([piped.pipe_if(true, |e| e.alias("b"))])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("b")?.i32()?),
+        &[Some(4), Some(6), Some(8)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_is_monotonic() -> PolarsResult<()> {
+    let df = df![
+        "sorted" => [Some(1), Some(2), Some(2), Some(5)],
+        "reversed" => [Some(5), Some(2), Some(2), Some(1)],
+        "unordered" => [Some(1), Some(3), Some(2), Some(5)],
+        "nulls" => [Some(1), None, Some(2), Some(5)]
+    ]?;
+    let out = df
+        .lazy()
+        .select(
+            ["sorted", "reversed", "unordered", "nulls"]
+                .into_iter()
+                .flat_map(|name| {
+                    [
+                        col(name)
+                            .is_monotonic_increasing(true)
+                            .alias(format!("{name}_inc")),
+                        col(name)
+                            .is_monotonic_decreasing(true)
+                            .alias(format!("{name}_dec")),
+                    ]
+                })
+                .chain([col("nulls")
+                    .is_monotonic_increasing(false)
+                    .alias("nulls_ignored")])
+                .collect::<Vec<_>>(),
+        )
+        .collect()?;
+
+    let get = |name: &str| -> PolarsResult<bool> { Ok(out.column(name)?.bool()?.get(0).unwrap()) };
+    assert!(get("sorted_inc")?);
+    assert!(!get("sorted_dec")?);
+    assert!(!get("reversed_inc")?);
+    assert!(get("reversed_dec")?);
+    assert!(!get("unordered_inc")?);
+    assert!(!get("unordered_dec")?);
+    assert!(!get("nulls_inc")?);
+    assert!(get("nulls_ignored")?);
+
+    let s = Series::new("a".into(), [3, 2, 2, 1]);
+    assert!(s.is_monotonic_decreasing(true)?);
+    assert!(!s.is_monotonic_increasing(true)?);
+
+    // Comparing nulls as the smallest value.
+    let nulls_first = Series::new("a".into(), [None, None, Some(1), Some(3)]);
+    assert!(nulls_first.is_monotonic_increasing_by(true)?);
+    assert!(!nulls_first.is_monotonic_increasing_by(false)?);
+    assert!(!nulls_first.is_monotonic_decreasing_by(true)?);
+    let nulls_last = Series::new("a".into(), [Some(3), Some(1), None, None]);
+    assert!(nulls_last.is_monotonic_decreasing_by(true)?);
+    assert!(!nulls_last.is_monotonic_increasing_by(true)?);
+    let out = df!["a" => [None, Some(1), None]]?
+        .lazy()
+        .select([
+            col("a").is_monotonic_increasing_by(true).alias("inc"),
+            col("a").is_monotonic_increasing(false).alias("lenient"),
+        ])
+        .collect()?;
+    assert_eq!(out.column("inc")?.bool()?.get(0), Some(false));
+    assert_eq!(out.column("lenient")?.bool()?.get(0), Some(true));
+    Ok(())
+}
+
+#[test]
+fn test_list_row_wise_equality() -> PolarsResult<()> {
+    let list = |rows: &[&[Option<i32>]]| {
+        let rows = rows
+            .iter()
+            .map(|row| Series::new("".into(), *row))
+            .collect::<Vec<_>>();
+        Series::new("".into(), rows)
+    };
+    let df = DataFrame::new_infer_height(vec![
+        list(&[
+            &[Some(1), Some(2)],
+            &[Some(3), None],
+            &[Some(3), None],
+            &[Some(1)],
+        ])
+        .with_name("a".into())
+        .into(),
+        list(&[
+            &[Some(1), Some(2)],
+            &[Some(3), None],
+            &[Some(3), Some(4)],
+            &[Some(1), Some(1)],
+        ])
+        .with_name("b".into())
+        .into(),
+    ])?;
+
+    // Lists are equal if they have the same length and all elements are equal, where null
+    // inner elements are equal to each other.
+    let out = df.lazy().select([col("a").eq(col("b"))]).collect()?;
+    assert_eq!(
+        Vec::from(out.column("a")?.bool()?),
+        &[Some(true), Some(true), Some(false), Some(false)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_list_chunk_every() -> PolarsResult<()> {
+    let df = df!["a" => [Series::new("".into(), [1, 2, 3, 4, 5])]]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("a").list().chunk_every(lit(2)).alias("chunks"),
+            col("a")
+                .list()
+                .chunk_every(lit(2))
+                .list()
+                .flatten_n(1)
+                .alias("roundtrip"),
+        ])
+        .collect()?;
+
+    let chunks = out.column("chunks")?.list()?.get_as_series(0).unwrap();
+    let chunks = chunks
+        .list()?
+        .into_no_null_iter()
+        .map(|s| Ok(s.i32()?.into_no_null_iter().collect::<Vec<_>>()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    assert_eq!(chunks, [vec![1, 2], vec![3, 4], vec![5]]);
+
+    let roundtrip = out.column("roundtrip")?.list()?.get_as_series(0).unwrap();
+    assert_eq!(
+        Vec::from(roundtrip.i32()?),
+        &[Some(1), Some(2), Some(3), Some(4), Some(5)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-i8")]
+fn test_cum_prod_with_overflow() -> PolarsResult<()> {
+    let df = df!["a" => [100i8, 100, 100]]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("a").cum_prod_with_overflow(false, OverflowPolicy::Checked)])
+        .collect();
+    assert!(out.is_err());
+
+    let out = df
+        .lazy()
+        .select([
+            col("a")
+                .cum_prod_with_overflow(false, OverflowPolicy::Saturating)
+                .alias("saturating"),
+            col("a").cum_prod(false).alias("wrapping"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("saturating")?.i8()?),
+        &[Some(100), Some(127), Some(127)]
+    );
+    assert_eq!(
+        Vec::from(out.column("wrapping")?.i64()?),
+        &[Some(100), Some(10_000), Some(1_000_000)]
+    );
+    Ok(())
+}
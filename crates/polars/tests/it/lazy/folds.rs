@@ -30,7 +30,10 @@ fn test_fold_wildcard() -> PolarsResult<()> {
     // test if we don't panic due to wildcard
     let _out = df1
         .lazy()
-        .select([polars_lazy::dsl::all_horizontal([col("*").is_not_null()])?])
+        .select([polars_lazy::dsl::all_horizontal(
+            [col("*").is_not_null()],
+            false,
+        )?])
         .collect()?;
     Ok(())
 }
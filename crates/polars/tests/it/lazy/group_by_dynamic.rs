@@ -61,3 +61,222 @@ fn test_group_by_dynamic_week_bounds() -> PolarsResult<()> {
     assert_eq!(a.get(1)?, AnyValue::Int32(6));
     Ok(())
 }
+
+#[test]
+#[cfg(all(feature = "temporal", feature = "dynamic_group_by"))]
+fn test_group_by_dynamic_clock_skew_tolerance() -> PolarsResult<()> {
+    // The rows at 00:00:40 and 00:00:55 arrive 30 and 25 seconds late.
+    let dt = Int64Chunked::new(
+        "dt".into(),
+        &[10_000, 50_000, 70_000, 40_000, 80_000, 55_000],
+    )
+    .into_datetime(TimeUnit::Milliseconds, None)
+    .into_series();
+    let df = df![
+        "dt" => dt,
+        "a" => [1, 2, 3, 4, 5, 6]
+    ]?;
+
+    let options = DynamicGroupOptions {
+        every: Duration::parse("1m"),
+        period: Duration::parse("1m"),
+        offset: Duration::parse("0m"),
+        closed_window: ClosedWindow::Left,
+        ..Default::default()
+    };
+    let q = |options: DynamicGroupOptions| {
+        df.clone()
+            .lazy()
+            .group_by_dynamic(col("dt"), [], options)
+            .agg([col("a").sum()])
+            .collect()
+    };
+
+    assert!(q(options.clone()).is_err());
+    assert!(
+        q(DynamicGroupOptions {
+            clock_skew_tolerance: Some(Duration::parse("20s")),
+            ..options.clone()
+        })
+        .is_err()
+    );
+
+    let out = q(DynamicGroupOptions {
+        clock_skew_tolerance: Some(Duration::parse("30s")),
+        ..options
+    })?;
+    assert_eq!(
+        Vec::from(out.column("dt")?.datetime()?.physical()),
+        &[Some(0), Some(60_000)]
+    );
+    assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(13), Some(8)]);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "temporal",
+    feature = "dtype-date",
+    feature = "dtype-time",
+    feature = "dynamic_group_by"
+))]
+fn test_group_by_dynamic_combined_date_time_index() -> PolarsResult<()> {
+    let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+    let df = df![
+        "date" => [date; 4],
+        "time" => [time(9, 10), time(9, 50), time(10, 5), time(11, 30)],
+        "a" => [1, 2, 3, 4]
+    ]?;
+
+    // The index is evaluated from the expression, no combined column has to exist upfront.
+    let out = df
+        .lazy()
+        .group_by_dynamic(
+            col("date")
+                .dt()
+                .combine(col("time"), TimeUnit::Microseconds)
+                .alias("ts"),
+            [],
+            DynamicGroupOptions {
+                every: Duration::parse("1h"),
+                period: Duration::parse("1h"),
+                offset: Duration::parse("0h"),
+                ..Default::default()
+            },
+        )
+        .agg([col("a").sum()])
+        .collect()?;
+
+    let hour = |h| {
+        date.and_hms_opt(h, 0, 0)
+            .unwrap()
+            .and_utc()
+            .timestamp_micros()
+    };
+    assert_eq!(
+        Vec::from(out.column("ts")?.datetime()?.physical()),
+        &[Some(hour(9)), Some(hour(10)), Some(hour(11))]
+    );
+    assert_eq!(
+        Vec::from(out.column("a")?.i32()?),
+        &[Some(3), Some(3), Some(4)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "temporal",
+    feature = "dtype-date",
+    feature = "dynamic_group_by"
+))]
+fn test_group_by_dynamic_label_right() -> PolarsResult<()> {
+    let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    let ts = |h, m| {
+        date.and_hms_opt(h, m, 0)
+            .unwrap()
+            .and_utc()
+            .timestamp_millis()
+    };
+    let dt = Int64Chunked::new("dt".into(), &[ts(9, 10), ts(9, 50), ts(10, 5)])
+        .into_datetime(TimeUnit::Milliseconds, None)
+        .into_series();
+    let df = df![
+        "dt" => dt,
+        "a" => [1, 2, 3]
+    ]?;
+
+    let out = df
+        .lazy()
+        .group_by_dynamic(
+            col("dt"),
+            [],
+            DynamicGroupOptions {
+                every: Duration::parse("1h"),
+                period: Duration::parse("1h"),
+                offset: Duration::parse("0h"),
+                label: Label::Right,
+                ..Default::default()
+            },
+        )
+        .agg([col("a").sum()])
+        .collect()?;
+
+    // The 09:00-10:00 window is labeled with its end.
+    assert_eq!(
+        Vec::from(out.column("dt")?.datetime()?.physical()),
+        &[Some(ts(10, 0)), Some(ts(11, 0))]
+    );
+    assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(3), Some(3)]);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "temporal", feature = "dynamic_group_by"))]
+fn test_group_by_dynamic_every_by_group() -> PolarsResult<()> {
+    let minutes = [0, 20, 50, 70];
+    let dt = Int64Chunked::from_iter_values(
+        "dt".into(),
+        minutes.iter().chain(&minutes).map(|m| m * 60_000),
+    )
+    .into_datetime(TimeUnit::Milliseconds, None)
+    .into_series();
+    // Instrument "a" is resampled hourly, instrument "b" every 30 minutes.
+    let df = df![
+        "instrument" => ["a", "a", "a", "a", "b", "b", "b", "b"],
+        "window" => ["1h", "1h", "1h", "1h", "30m", "30m", "30m", "30m"],
+        "dt" => dt,
+        "value" => [1, 2, 3, 4, 5, 6, 7, 8]
+    ]?;
+
+    let options = DynamicGroupOptions {
+        every: Duration::parse("1h"),
+        period: Duration::parse("1h"),
+        offset: Duration::parse("0h"),
+        every_by_group: Some("window".into()),
+        period_by_group: Some("window".into()),
+        ..Default::default()
+    };
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_dynamic(col("dt"), [col("instrument")], options.clone())
+        .agg([col("value").sum()])
+        .sort(["instrument", "dt"], Default::default())
+        .collect()?;
+
+    let instrument = out.column("instrument")?.str()?;
+    assert_eq!(
+        instrument.into_no_null_iter().collect::<Vec<_>>(),
+        ["a", "a", "b", "b", "b"]
+    );
+    assert_eq!(
+        Vec::from(out.column("dt")?.datetime()?.physical()),
+        &[
+            Some(0),
+            Some(3_600_000),
+            Some(0),
+            Some(1_800_000),
+            Some(3_600_000)
+        ]
+    );
+    assert_eq!(
+        Vec::from(out.column("value")?.i32()?),
+        &[Some(6), Some(4), Some(11), Some(7), Some(8)]
+    );
+
+    // The duration must be the same within a group.
+    let mut df = df;
+    df.with_column(Column::new(
+        "window".into(),
+        ["1h", "1h", "1h", "2h", "30m", "30m", "30m", "30m"],
+    ))?;
+    let out = df
+        .lazy()
+        .group_by_dynamic(col("dt"), [col("instrument")], options)
+        .agg([col("value").sum()])
+        .collect();
+    assert!(out.is_err());
+    Ok(())
+}
@@ -1003,6 +1003,7 @@ class PyLazyFrame:
         decimal_comma: bool,
         null_value: str | None,
         quote_style: QuoteStyle | None,
+        append: bool,
     ) -> PyLazyFrame: ...
     def sink_ndjson(
         self,
@@ -1364,8 +1365,8 @@ class PyExpr:
     def sqrt(self) -> PyExpr: ...
     def cbrt(self) -> PyExpr: ...
     def cum_sum(self, reverse: bool) -> PyExpr: ...
-    def cum_max(self, reverse: bool) -> PyExpr: ...
-    def cum_min(self, reverse: bool) -> PyExpr: ...
+    def cum_max(self, reverse: bool, skip_nulls: bool) -> PyExpr: ...
+    def cum_min(self, reverse: bool, skip_nulls: bool) -> PyExpr: ...
     def cum_prod(self, reverse: bool) -> PyExpr: ...
     def cum_count(self, reverse: bool) -> PyExpr: ...
     def cumulative_eval(self, expr: PyExpr, min_samples: int) -> PyExpr: ...
@@ -1594,6 +1595,9 @@ class PyExpr:
     def list_contains(self, other: PyExpr, nulls_equal: bool) -> PyExpr: ...
     def list_count_matches(self, expr: PyExpr) -> PyExpr: ...
     def list_diff(self, n: int, null_behavior: NullBehavior) -> PyExpr: ...
+    def list_cum_sum(self) -> PyExpr: ...
+    def list_cum_prod(self) -> PyExpr: ...
+    def list_flatten_n(self, n: int) -> PyExpr: ...
     def list_eval(self, expr: PyExpr, _parallel: bool) -> PyExpr: ...
     def list_agg(self, expr: PyExpr) -> PyExpr: ...
     def list_filter(self, predicate: PyExpr) -> PyExpr: ...
@@ -1866,7 +1870,7 @@ class PyExpr:
     def str_tail(self, n: PyExpr) -> PyExpr: ...
     def str_to_uppercase(self) -> PyExpr: ...
     def str_to_lowercase(self) -> PyExpr: ...
-    def str_to_titlecase(self) -> PyExpr: ...
+    def str_to_titlecase(self, unicode_words: bool) -> PyExpr: ...
     def str_len_bytes(self) -> PyExpr: ...
     def str_len_chars(self) -> PyExpr: ...
     def str_replace_n(
@@ -2224,8 +2228,8 @@ def spearman_rank_corr(a: PyExpr, b: PyExpr, propagate_nans: bool) -> PyExpr: ..
 def sql_expr(sql: str) -> PyExpr: ...
 
 # functions.aggregations
-def all_horizontal(exprs: Sequence[PyExpr]) -> PyExpr: ...
-def any_horizontal(exprs: Sequence[PyExpr]) -> PyExpr: ...
+def all_horizontal(exprs: Sequence[PyExpr], ignore_nulls: bool) -> PyExpr: ...
+def any_horizontal(exprs: Sequence[PyExpr], ignore_nulls: bool) -> PyExpr: ...
 def max_horizontal(exprs: Sequence[PyExpr]) -> PyExpr: ...
 def min_horizontal(exprs: Sequence[PyExpr]) -> PyExpr: ...
 def sum_horizontal(exprs: Sequence[PyExpr], ignore_nulls: bool) -> PyExpr: ...
@@ -1701,7 +1701,7 @@ class Expr:
         """
         return wrap_expr(self._pyexpr.cum_prod(reverse))
 
-    def cum_min(self, *, reverse: bool = False) -> Expr:
+    def cum_min(self, *, reverse: bool = False, skip_nulls: bool = True) -> Expr:
         """
         Get an array with the cumulative min computed at every element.
 
@@ -1709,6 +1709,9 @@ class Expr:
         ----------
         reverse
             Reverse the operation.
+        skip_nulls
+            Skip null values. If set to `False`, a null value makes all values after
+            it null as well.
 
         Examples
         --------
@@ -1728,9 +1731,9 @@ class Expr:
         │ 2   ┆ 1       ┆ 2               │
         └─────┴─────────┴─────────────────┘
         """
-        return wrap_expr(self._pyexpr.cum_min(reverse))
+        return wrap_expr(self._pyexpr.cum_min(reverse, skip_nulls))
 
-    def cum_max(self, *, reverse: bool = False) -> Expr:
+    def cum_max(self, *, reverse: bool = False, skip_nulls: bool = True) -> Expr:
         """
         Get an array with the cumulative max computed at every element.
 
@@ -1738,6 +1741,9 @@ class Expr:
         ----------
         reverse
             Reverse the operation.
+        skip_nulls
+            Skip null values. If set to `False`, a null value makes all values after
+            it null as well.
 
         Examples
         --------
@@ -1785,7 +1791,7 @@ class Expr:
         │ null   ┆ null    ┆ 16                 │
         └────────┴─────────┴────────────────────┘
         """
-        return wrap_expr(self._pyexpr.cum_max(reverse))
+        return wrap_expr(self._pyexpr.cum_max(reverse, skip_nulls))
 
     def cum_count(self, *, reverse: bool = False) -> Expr:
         """
@@ -971,6 +971,78 @@ class ExprListNameSpace:
         """
         return wrap_expr(self._pyexpr.list_diff(n, null_behavior))
 
+    def cum_sum(self) -> Expr:
+        """
+        Compute the cumulative sum of every sublist.
+
+        Null values are skipped and remain null in the output.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"n": [[1, 2, 3], [4, None, 5]]})
+        >>> df.with_columns(cum_sum=pl.col("n").list.cum_sum())
+        shape: (2, 2)
+        ┌──────────────┬──────────────┐
+        │ n            ┆ cum_sum      │
+        │ ---          ┆ ---          │
+        │ list[i64]    ┆ list[i64]    │
+        ╞══════════════╪══════════════╡
+        │ [1, 2, 3]    ┆ [1, 3, 6]    │
+        │ [4, null, 5] ┆ [4, null, 9] │
+        └──────────────┴──────────────┘
+        """
+        return wrap_expr(self._pyexpr.list_cum_sum())
+
+    def cum_prod(self) -> Expr:
+        """
+        Compute the cumulative product of every sublist.
+
+        Null values are skipped and remain null in the output.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"n": [[1, 2, 3], [4, None, 5]]})
+        >>> df.with_columns(cum_prod=pl.col("n").list.cum_prod())
+        shape: (2, 2)
+        ┌──────────────┬───────────────┐
+        │ n            ┆ cum_prod      │
+        │ ---          ┆ ---           │
+        │ list[i64]    ┆ list[i64]     │
+        ╞══════════════╪═══════════════╡
+        │ [1, 2, 3]    ┆ [1, 2, 6]     │
+        │ [4, null, 5] ┆ [4, null, 20] │
+        └──────────────┴───────────────┘
+        """
+        return wrap_expr(self._pyexpr.list_cum_prod())
+
+    def flatten_n(self, n: int) -> Expr:
+        """
+        Flatten up to `n` levels of nesting within every sublist.
+
+        Stops early once the inner type is no longer a list. Null sublists are
+        skipped.
+
+        Parameters
+        ----------
+        n
+            Number of nesting levels to flatten.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[[1, 2], [3]], [[4]]]})
+        >>> df.with_columns(flat=pl.col("a").list.flatten_n(1))
+        shape: (2, 2)
+        ┌─────────────────┬───────────┐
+        │ a               ┆ flat      │
+        │ ---             ┆ ---       │
+        │ list[list[i64]] ┆ list[i64] │
+        ╞═════════════════╪═══════════╡
+        │ [[1, 2], [3]]   ┆ [1, 2, 3] │
+        │ [[4]]           ┆ [4]       │
+        └─────────────────┴───────────┘
+        """
+        return wrap_expr(self._pyexpr.list_flatten_n(n))
+
     def shift(self, n: int | IntoExprColumn = 1) -> Expr:
         """
         Shift list values by the given number of indices.
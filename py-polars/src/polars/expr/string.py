@@ -503,10 +503,16 @@ class ExprStringNameSpace:
         """
         return wrap_expr(self._pyexpr.str_to_lowercase())
 
-    def to_titlecase(self) -> Expr:
+    def to_titlecase(self, *, unicode_words: bool = False) -> Expr:
         """
         Modify strings to their titlecase equivalent.
 
+        Parameters
+        ----------
+        unicode_words
+            Delimit the words by the Unicode word boundaries of UAX #29 instead of by
+            non-alphabetic characters, so "it's" and "2nd" are single words.
+
         Notes
         -----
         This is a form of case transform where the first letter of each word is
@@ -538,7 +544,7 @@ class ExprStringNameSpace:
         │ to infinity,and BEYOND! ┆ To Infinity,And Beyond! │
         └─────────────────────────┴─────────────────────────┘
         """
-        return wrap_expr(self._pyexpr.str_to_titlecase())
+        return wrap_expr(self._pyexpr.str_to_titlecase(unicode_words))
 
     def strip_chars(self, characters: IntoExpr = None) -> Expr:
         r"""
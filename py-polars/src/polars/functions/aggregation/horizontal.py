@@ -17,7 +17,9 @@ if TYPE_CHECKING:
     from polars._typing import IntoExpr
 
 
-def all_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
+def all_horizontal(
+    *exprs: IntoExpr | Iterable[IntoExpr], ignore_nulls: bool = False
+) -> Expr:
     """
     Compute the logical AND horizontally across columns.
 
@@ -26,11 +28,13 @@ def all_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
     *exprs
         Column(s) to use in the aggregation. Accepts expression input. Strings are
         parsed as column names, other non-expression inputs are parsed as literals.
+    ignore_nulls
+        Treat null values as `True`, so that they never affect the result.
 
     Notes
     -----
-    `Kleene logic`_ is used to deal with nulls: if the column contains any null values
-    and no `False` values, the output is null.
+    `Kleene logic`_ is used by default to deal with nulls: if the column contains any
+    null values and no `False` values, the output is null.
 
     .. _Kleene logic: https://en.wikipedia.org/wiki/Three-valued_logic
 
@@ -59,10 +63,12 @@ def all_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
     └───────┴───────┴─────┴───────┘
     """
     pyexprs = parse_into_list_of_expressions(*exprs)
-    return wrap_expr(plr.all_horizontal(pyexprs))
+    return wrap_expr(plr.all_horizontal(pyexprs, ignore_nulls))
 
 
-def any_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
+def any_horizontal(
+    *exprs: IntoExpr | Iterable[IntoExpr], ignore_nulls: bool = False
+) -> Expr:
     """
     Compute the logical OR horizontally across columns.
 
@@ -71,11 +77,13 @@ def any_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
     *exprs
         Column(s) to use in the aggregation. Accepts expression input. Strings are
         parsed as column names, other non-expression inputs are parsed as literals.
+    ignore_nulls
+        Treat null values as `False`, so that they never affect the result.
 
     Notes
     -----
-    `Kleene logic`_ is used to deal with nulls: if the column contains any null values
-    and no `True` values, the output is null.
+    `Kleene logic`_ is used by default to deal with nulls: if the column contains any
+    null values and no `True` values, the output is null.
 
     .. _Kleene logic: https://en.wikipedia.org/wiki/Three-valued_logic
 
@@ -104,7 +112,7 @@ def any_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
     └───────┴───────┴─────┴───────┘
     """
     pyexprs = parse_into_list_of_expressions(*exprs)
-    return wrap_expr(plr.any_horizontal(pyexprs))
+    return wrap_expr(plr.any_horizontal(pyexprs, ignore_nulls))
 
 
 def max_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
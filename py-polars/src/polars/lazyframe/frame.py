@@ -3768,6 +3768,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         decimal_comma: bool = False,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        append: bool = False,
         maintain_order: bool = True,
         storage_options: StorageOptionsDict | None = None,
         credential_provider: CredentialProviderFunction
@@ -3803,6 +3804,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         decimal_comma: bool = False,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        append: bool = False,
         maintain_order: bool = True,
         storage_options: StorageOptionsDict | None = None,
         credential_provider: CredentialProviderFunction
@@ -3837,6 +3839,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         decimal_comma: bool = False,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        append: bool = False,
         maintain_order: bool = True,
         storage_options: StorageOptionsDict | None = None,
         credential_provider: CredentialProviderFunction
@@ -3935,6 +3938,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
               Namely, when writing a field that does not parse as a valid float
               or integer, then quotes will be used even if they aren`t strictly
               necessary.
+        append
+            Append to the file if it exists instead of overwriting it. The BOM and
+            header are only written if the file is empty. Only supported when
+            sinking to a local file.
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will be slightly faster.
@@ -4100,6 +4107,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             decimal_comma=decimal_comma,
             null_value=null_value,
             quote_style=quote_style,
+            append=append,
         )
 
         if not lazy:
@@ -727,6 +727,66 @@ class ListNameSpace:
         ]
         """
 
+    def cum_sum(self) -> Series:
+        """
+        Compute the cumulative sum of every sublist.
+
+        Null values are skipped and remain null in the output.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2, 3], [4, None, 5]])
+        >>> s.list.cum_sum()
+        shape: (2,)
+        Series: 'a' [list[i64]]
+        [
+            [1, 3, 6]
+            [4, null, 9]
+        ]
+        """
+
+    def cum_prod(self) -> Series:
+        """
+        Compute the cumulative product of every sublist.
+
+        Null values are skipped and remain null in the output.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2, 3], [4, None, 5]])
+        >>> s.list.cum_prod()
+        shape: (2,)
+        Series: 'a' [list[i64]]
+        [
+            [1, 2, 6]
+            [4, null, 20]
+        ]
+        """
+
+    def flatten_n(self, n: int) -> Series:
+        """
+        Flatten up to `n` levels of nesting within every sublist.
+
+        Stops early once the inner type is no longer a list. Null sublists are
+        skipped.
+
+        Parameters
+        ----------
+        n
+            Number of nesting levels to flatten.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[[1, 2], [3]], [[4]]])
+        >>> s.list.flatten_n(1)
+        shape: (2,)
+        Series: 'a' [list[i64]]
+        [
+            [1, 2, 3]
+            [4]
+        ]
+        """
+
     def shift(self, n: int | IntoExprColumn = 1) -> Series:
         """
         Shift list values by the given number of indices.
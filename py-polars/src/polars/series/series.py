@@ -3123,7 +3123,7 @@ class Series:
         """
         return self._s.n_chunks()
 
-    def cum_max(self, *, reverse: bool = False) -> Series:
+    def cum_max(self, *, reverse: bool = False, skip_nulls: bool = True) -> Series:
         """
         Get an array with the cumulative max computed at every element.
 
@@ -3131,6 +3131,9 @@ class Series:
         ----------
         reverse
             reverse the operation.
+        skip_nulls
+            Skip null values. If set to `False`, a null value makes all values after
+            it null as well.
 
         Examples
         --------
@@ -3145,7 +3148,7 @@ class Series:
         ]
         """
 
-    def cum_min(self, *, reverse: bool = False) -> Series:
+    def cum_min(self, *, reverse: bool = False, skip_nulls: bool = True) -> Series:
         """
         Get an array with the cumulative min computed at every element.
 
@@ -3153,6 +3156,9 @@ class Series:
         ----------
         reverse
             reverse the operation.
+        skip_nulls
+            Skip null values. If set to `False`, a null value makes all values after
+            it null as well.
 
         Examples
         --------
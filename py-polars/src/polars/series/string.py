@@ -1672,10 +1672,16 @@ class StringNameSpace:
         ]
         """
 
-    def to_titlecase(self) -> Series:
+    def to_titlecase(self, *, unicode_words: bool = False) -> Series:
         """
         Modify strings to their titlecase equivalent.
 
+        Parameters
+        ----------
+        unicode_words
+            Delimit the words by the Unicode word boundaries of UAX #29 instead of by
+            non-alphabetic characters, so "it's" and "2nd" are single words.
+
         Notes
         -----
         This is a form of case transform where the first letter of each word is
@@ -0,0 +1,69 @@
+"""Benchmark tests for equi-joins on pre-sorted integer keys."""
+
+from __future__ import annotations
+
+import numpy as np
+import pytest
+
+import polars as pl
+
+pytestmark = pytest.mark.benchmark()
+
+
+def test_inner_join_sorted_keys(
+    sorted_frames: tuple[pl.DataFrame, pl.DataFrame],
+) -> None:
+    left, right = sorted_frames
+    result = (
+        left.lazy()
+        .join(right.lazy(), on="key", how="inner")
+        .collect(engine="in-memory")
+    )
+
+    assert len(result) > 0
+
+
+def test_inner_join_unflagged_keys(
+    presorted_frames: tuple[pl.DataFrame, pl.DataFrame],
+) -> None:
+    # Same data, but without the sorted flag the join has to hash the keys.
+    left, right = presorted_frames
+    result = (
+        left.lazy()
+        .join(right.lazy(), on="key", how="inner")
+        .collect(engine="in-memory")
+    )
+
+    assert len(result) > 0
+
+
+@pytest.fixture(scope="module")
+def presorted_frames() -> tuple[pl.DataFrame, pl.DataFrame]:
+    num_rows = 10_000_000
+    rng = np.random.default_rng(42)
+
+    left = pl.DataFrame(
+        {
+            "key": np.sort(rng.integers(0, 2 * num_rows, num_rows)),
+            "a": rng.integers(0, 100, num_rows),
+        }
+    )
+    right = pl.DataFrame(
+        {
+            "key": np.sort(rng.integers(0, 2 * num_rows, num_rows)),
+            "b": rng.integers(0, 100, num_rows),
+        }
+    )
+
+    return left, right
+
+
+@pytest.fixture(scope="module")
+def sorted_frames(
+    presorted_frames: tuple[pl.DataFrame, pl.DataFrame],
+) -> tuple[pl.DataFrame, pl.DataFrame]:
+    left, right = presorted_frames
+    return (
+        left.with_columns(pl.col("key").set_sorted()),
+        right.with_columns(pl.col("key").set_sorted()),
+    )
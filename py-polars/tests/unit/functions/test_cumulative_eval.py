@@ -65,3 +65,32 @@ def test_cumulative_eval_length_preserving_streaming_25293() -> None:
     expected = pl.DataFrame({"a": [1, 1, 1]})
     assert_frame_equal(q.collect(engine="in-memory"), expected)
     assert_frame_equal(q.collect(engine="streaming"), expected)
+
+
+@pytest.mark.parametrize(
+    ("agg", "expected"),
+    [
+        (pl.element().sum(), [None, 2, 2, 3, 3, 7]),
+        (pl.element().mean(), [None, 2.0, 2.0, 1.5, 1.5, 7 / 3]),
+        (pl.element().min(), [None, 2, 2, 1, 1, 1]),
+        (pl.element().max(), [None, 2, 2, 2, 2, 4]),
+        (pl.element().count(), [None, 1, 1, 2, 2, 3]),
+        (pl.element().len(), [None, 2, 3, 4, 5, 6]),
+    ],
+)
+def test_cumulative_eval_running_aggregations(
+    agg: pl.Expr, expected: list[float | None]
+) -> None:
+    s = pl.Series("a", [None, 2, None, 1, None, 4], pl.Int64)
+    assert_series_equal(
+        s.cumulative_eval(agg), pl.Series("a", expected), check_dtypes=False
+    )
+
+
+def test_cumulative_eval_running_aggregation_large() -> None:
+    s = pl.Series("a", range(1_000_000), pl.Int64)
+    assert_series_equal(s.cumulative_eval(pl.element().sum()), s.cum_sum())
+    assert_series_equal(
+        s.cumulative_eval(pl.element().mean()),
+        s.cum_sum() / pl.Series("a", range(1, 1_000_001)),
+    )
@@ -135,6 +135,30 @@ def test_sink_to_file(tmp_path: Path, sink: Any, scan: Any) -> None:
         )
 
 
+@pytest.mark.write_disk
+def test_sink_csv_append(tmp_path: Path) -> None:
+    path = tmp_path / "out.csv"
+    df1 = pl.DataFrame({"a": [1, 2], "b": ["x", "y"]})
+    df2 = pl.DataFrame({"a": [3], "b": ["z"]})
+
+    df1.lazy().sink_csv(path, include_bom=True, append=True)
+    df2.lazy().sink_csv(path, include_bom=True, append=True)
+
+    assert path.read_bytes().count(b"a,b") == 1
+    assert_frame_equal(pl.read_csv(path), pl.concat([df1, df2]))
+
+    # Without `append` the file is overwritten.
+    df2.lazy().sink_csv(path)
+    assert_frame_equal(pl.read_csv(path), df2)
+
+
+def test_sink_csv_append_partitioned_raises(tmp_path: Path) -> None:
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="append"):
+        pl.LazyFrame({"a": [1]}).sink_csv(
+            pl.PartitionBy(tmp_path, key="a"), append=True
+        )
+
+
 @pytest.mark.parametrize(("scan", "sink"), SINKS)
 def test_sink_empty(sink: Any, scan: Any) -> None:
     df = pl.LazyFrame(data={"col1": ["a"]})
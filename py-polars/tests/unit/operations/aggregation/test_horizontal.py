@@ -54,6 +54,26 @@ def test_all_any_horizontally() -> None:
     assert "horizontal" not in dfltr.explain().lower()
 
 
+def test_all_any_horizontally_ignore_nulls() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [True, True, False, None, None],
+            "b": [True, None, None, None, False],
+        }
+    )
+    result = df.select(
+        any=pl.any_horizontal("a", "b", ignore_nulls=True),
+        all=pl.all_horizontal("a", "b", ignore_nulls=True),
+    )
+    expected = pl.DataFrame(
+        {
+            "any": [True, True, False, False, False],
+            "all": [True, True, False, True, False],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
 def test_empty_all_any_horizontally() -> None:
     # any/all_horizontal don't allow empty input, but we can still trigger this
     # by selecting an empty set of columns with pl.selectors.
@@ -448,6 +448,42 @@ def test_list_diff() -> None:
     assert s.list.diff().to_list() == expected.to_list()
 
 
+def test_list_flatten_n() -> None:
+    s = pl.Series("a", [[[[1, 2], [3]], [[4, 5, 6]]], [[[4, 5, 6]]], None])
+    assert s.list.flatten_n(0).equals(s)
+
+    out = s.list.flatten_n(2)
+    assert out.dtype == pl.List(pl.Int64)
+    assert out.to_list() == [[1, 2, 3, 4, 5, 6], [4, 5, 6], None]
+    assert s.list.flatten_n(2**32).equals(out)
+
+
+def test_list_cum_sum_cum_prod() -> None:
+    s = pl.Series(
+        "a",
+        [[1, 2, 3], [4, None, 5], [], None, [None, 2]],
+        dtype=pl.List(pl.Int8),
+    )
+
+    expected = pl.Series(
+        "a", [[1, 3, 6], [4, None, 9], [], None, [None, 2]], dtype=pl.List(pl.Int64)
+    )
+    assert_series_equal(s.list.cum_sum(), expected)
+
+    expected = pl.Series(
+        "a", [[1, 2, 6], [4, None, 20], [], None, [None, 2]], dtype=pl.List(pl.Int64)
+    )
+    assert_series_equal(s.list.cum_prod(), expected)
+
+    # Sliced lists must restart from the start of every sublist.
+    assert s.slice(1).list.cum_sum().to_list() == [[4, None, 9], [], None, [None, 2]]
+
+    lf = pl.LazyFrame({"a": [[True, False, True]]})
+    assert lf.select(pl.col("a").list.cum_sum()).collect_schema() == {
+        "a": pl.List(pl.UInt32)
+    }
+
+
 def test_slice() -> None:
     vals = [[1, 2, 3, 4], [10, 2, 1]]
     s = pl.Series("a", vals)
@@ -885,6 +921,16 @@ def test_list_arithmetic() -> None:
     assert_series_equal(s.list.min(), pl.Series("a", [1, 1]))
 
 
+def test_list_median_mean_nulls() -> None:
+    s = pl.Series("a", [[1, 3, 5], [2, 4]])
+    assert_series_equal(s.list.median(), pl.Series("a", [3.0, 3.0]))
+
+    # Like `Series.mean`, inner nulls are skipped and an all-null list has a null mean.
+    s = pl.Series("a", [[1, None, 3], [None], [], None])
+    assert_series_equal(s.list.mean(), pl.Series("a", [2.0, None, None, None]))
+    assert_series_equal(s.list.median(), pl.Series("a", [2.0, None, None, None]))
+
+
 def test_list_ordering() -> None:
     s = pl.Series("a", [[2, 1], [1, 3, 2]])
     assert_series_equal(s.list.sort(), pl.Series("a", [[1, 2], [1, 2, 3]]))
@@ -1958,6 +1958,34 @@ def test_titlecase() -> None:
         )
 
 
+def test_titlecase_unicode_words() -> None:
+    s = pl.Series(
+        [
+            "hello WORLD über",
+            "you talkin' to me?",
+            "it's o'neil's",
+            "by jean-paul sartre",
+            "SOMETIMES/life/gives/you/a/2nd/chance",
+            None,
+        ]
+    )
+    expected = pl.Series(
+        [
+            "Hello World Über",
+            "You Talkin' To Me?",
+            "It's O'neil's",
+            "By Jean-Paul Sartre",
+            "Sometimes/Life/Gives/You/A/2nd/Chance",
+            None,
+        ]
+    )
+    assert_series_equal(s.str.to_titlecase(unicode_words=True), expected)
+    assert_series_equal(
+        pl.select(pl.lit(s).str.to_titlecase(unicode_words=True)).to_series(),
+        expected,
+    )
+
+
 def test_string_replace_with_nulls_10124() -> None:
     df = pl.DataFrame({"col1": ["S", "S", "S", None, "S", "S", "S", "S"]})
 
@@ -2010,6 +2038,26 @@ def test_string_reverse() -> None:
     assert_frame_equal(result, expected)
 
 
+def test_string_reverse_grapheme_clusters() -> None:
+    s = pl.Series(
+        [
+            "caf\u00e9",
+            "cafe\u0301",
+            "\U0001f1f3\U0001f1f1\U0001f1e9\U0001f1ea",
+            "a\U0001f468\u200d\U0001f469\u200d\U0001f467b",
+        ]
+    )
+    expected = pl.Series(
+        [
+            "\u00e9fac",
+            "e\u0301fac",
+            "\U0001f1e9\U0001f1ea\U0001f1f3\U0001f1f1",
+            "b\U0001f468\u200d\U0001f469\u200d\U0001f467a",
+        ]
+    )
+    assert_series_equal(s.str.reverse(), expected)
+
+
 @pytest.mark.parametrize(
     ("data", "expected_data"),
     [
@@ -3154,3 +3154,29 @@ def test_group_by_when_then_with_mutated_idxs() -> None:
             }
         ),
     )
+
+
+def test_group_by_fill_null_does_not_cross_groups() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 1, 2, 2, 2],
+            "x": [1, None, None, None, None, 5],
+        }
+    )
+
+    result = df.group_by("g", maintain_order=True).agg(
+        ffill=pl.col("x").forward_fill(),
+        ffill_1=pl.col("x").forward_fill(limit=1),
+        bfill=pl.col("x").backward_fill(),
+        bfill_1=pl.col("x").backward_fill(limit=1),
+    )
+    expected = pl.DataFrame(
+        {
+            "g": [1, 2],
+            "ffill": [[1, 1, 1], [None, None, 5]],
+            "ffill_1": [[1, 1, None], [None, None, 5]],
+            "bfill": [[1, None, None], [5, 5, 5]],
+            "bfill_1": [[1, None, None], [None, 5, 5]],
+        }
+    )
+    assert_frame_equal(result, expected)
@@ -689,6 +689,36 @@ def test_window_order_by_8662() -> None:
     }
 
 
+def test_window_shared_partition_multiple_aggs() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 2, 1, 2, 1, 3],
+            "t": [3, 1, 1, 2, 2, 1],
+            "x": [10, 20, 30, 40, 50, 60],
+        }
+    )
+
+    # All windows share the same partition and order keys, so their groups are
+    # computed once and reused.
+    out = df.select(
+        s=pl.col("x").sum().over("g", order_by="t"),
+        mn=pl.col("x").min().over("g", order_by="t"),
+        mx=pl.col("x").max().over("g", order_by="t"),
+        first=pl.col("x").first().over("g", order_by="t"),
+        cs=pl.col("x").cum_sum().over("g", order_by="t"),
+    )
+    expected = pl.DataFrame(
+        {
+            "s": [90, 60, 90, 60, 90, 60],
+            "mn": [10, 20, 10, 20, 10, 60],
+            "mx": [50, 40, 50, 40, 50, 60],
+            "first": [30, 20, 30, 20, 30, 60],
+            "cs": [90, 20, 30, 60, 80, 60],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
 def test_window_chunked_std_17102() -> None:
     c1 = pl.DataFrame({"A": [1, 1], "B": [1.0, 2.0]})
     c2 = pl.DataFrame({"A": [2, 2], "B": [1.0, 2.0]})
@@ -80,6 +80,25 @@ def test_cum_min_max_bool() -> None:
     )
 
 
+def test_cum_min_max_no_skip_nulls() -> None:
+    s = pl.Series("a", [1, None, 3])
+    expected = pl.Series("a", [1, None, None])
+    assert_series_equal(s.cum_max(skip_nulls=False), expected)
+    assert_series_equal(s.cum_min(skip_nulls=False), expected)
+
+    expected = pl.Series("a", [None, None, 3])
+    assert_series_equal(s.cum_max(reverse=True, skip_nulls=False), expected)
+    assert_series_equal(s.cum_min(reverse=True, skip_nulls=False), expected)
+
+    s = pl.Series("a", [3, 1, 2])
+    assert_series_equal(s.cum_min(skip_nulls=False), pl.Series("a", [3, 1, 1]))
+
+    df = pl.DataFrame({"a": [1, None, 3]})
+    assert df.lazy().select(pl.col("a").cum_max(skip_nulls=False)).collect(
+        engine="streaming"
+    ).to_series().to_list() == [1, None, None]
+
+
 def test_init_inputs(plmonkeypatch: PlMonkeyPatch) -> None:
     nan = float("nan")
     # Good inputs
@@ -509,6 +509,48 @@ def test_streaming_group_by_all_null_21593() -> None:
     assert_frame_equal(df, out, check_row_order=False)
 
 
+@pytest.mark.slow
+def test_streaming_group_by_spill_above_memory_limit(
+    tmp_path: Path,
+    plmonkeypatch: PlMonkeyPatch,
+    capfd: pytest.CaptureFixture[str],
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    plmonkeypatch.setenv("POLARS_OOC_SPILL_DIR", str(tmp_path))
+    plmonkeypatch.setenv("POLARS_STREAMING_GROUP_BY_MAX_MEMORY_MB", "1")
+    plmonkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    n = 10_000_000
+    lf = pl.LazyFrame(
+        {
+            "a": pl.int_range(n, eager=True) % 1_000_003,
+            "b": pl.int_range(n, eager=True),
+        }
+    )
+    q = lf.group_by("a").agg(
+        pl.col("b").sum().alias("sum"),
+        pl.col("b").min().alias("min"),
+        pl.col("b").first().alias("first"),
+        pl.col("b").last().alias("last"),
+        pl.len(),
+    )
+
+    capfd.readouterr()
+    result = q.collect(engine="streaming")
+    spill_lines = [
+        line
+        for line in capfd.readouterr().err.splitlines()
+        if line.startswith("[ooc] spill_file ")
+    ]
+    assert spill_lines
+    assert all(str(tmp_path) in line for line in spill_lines)
+    # Spill files are removed once their partition has been merged.
+    assert not list(tmp_path.rglob("spill_file_*.parquet"))
+
+    expected = q.collect(engine="in-memory")
+    assert_frame_equal(result, expected, check_row_order=False)
+
+
 def test_streaming_group_by_nested_agg_fallback() -> None:
     n = 1001
     df = pl.DataFrame(